@@ -1,16 +1,20 @@
-use crate::lldpdu::Lldpdu;
+pub mod dcbx;
+
+use crate::lldpdu::{Lldpdu, ParsePolicy};
 use crate::tlv::chassisid_tlv::*;
 use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
+use crate::tlv::organizationallyspecific_tlv::registry::OrgTlvRegistry;
 use crate::tlv::portid_tlv::*;
 use crate::tlv::ttl_tlv::TtlTLV;
 use crate::tlv::Tlv;
+use dcbx::{DcbxConfig, DcbxEvent, DcbxState};
 use std::time::Instant;
 
 extern crate pnet;
 use pnet::datalink::Channel::Ethernet;
 use pnet::datalink::{self, DataLinkReceiver, DataLinkSender, MacAddr, NetworkInterface};
 use pnet::packet::ethernet::EtherTypes;
-use pnet::packet::ethernet::{EtherType, EthernetPacket, MutableEthernetPacket};
+use pnet::packet::ethernet::{EthernetPacket, MutableEthernetPacket};
 use pnet::packet::Packet;
 
 /// Logger trait
@@ -27,6 +31,21 @@ impl Logger for StdoutLogger {
     }
 }
 
+/// Receive statistics of the LLDP agent
+///
+/// The counters follow the statistics defined by IEEE 802.1AB for the receive state machine.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AgentStatistics {
+    /// Number of valid LLDP frames received
+    pub frames_in_total: u64,
+    /// Number of LLDP frames discarded for any reason
+    pub frames_discarded_total: u64,
+    /// Number of LLDP frames received with one or more detectable errors
+    pub frames_in_errors_total: u64,
+    /// Number of TLVs of a reserved type received
+    pub tlvs_unrecognized_total: u64,
+}
+
 /// LLDP agent
 ///
 /// This is the top-level component. It provides two functions.
@@ -41,6 +60,13 @@ pub struct LLDPAgent {
     interval: f32,
     channel: (Box<dyn DataLinkSender>, Box<dyn DataLinkReceiver>),
     logger: Box<dyn Logger>,
+    statistics: AgentStatistics,
+    parse_policy: ParsePolicy,
+    org_registry: OrgTlvRegistry,
+    /// Transmit buffer, reused for every announcement
+    frame: Vec<u8>,
+    dcbx: Option<DcbxState>,
+    dcbx_events: Vec<DcbxEvent>,
 }
 
 impl LLDPAgent {
@@ -63,11 +89,7 @@ impl LLDPAgent {
 
                 // Find the network interface with the provided name
                 let interfaces = datalink::interfaces();
-                let interface = interfaces
-                    .into_iter()
-                    .filter(interface_names_match)
-                    .next()
-                    .unwrap();
+                let interface = interfaces.into_iter().find(interface_names_match).unwrap();
 
                 // Create a new channel, dealing with layer 2 packets
                 let (tx, rx) = match datalink::channel(&interface, Default::default()) {
@@ -89,6 +111,79 @@ impl LLDPAgent {
             interval,
             channel: (tx, rx),
             logger,
+            statistics: AgentStatistics::default(),
+            parse_policy: ParsePolicy::default(),
+            org_registry: OrgTlvRegistry::with_builtin(),
+            frame: Vec::new(),
+            dcbx: None,
+            dcbx_events: Vec::new(),
+        }
+    }
+
+    /// Get the receive statistics of the agent
+    pub fn statistics(&self) -> &AgentStatistics {
+        &self.statistics
+    }
+
+    /// Get the policy used to parse received LLDPDUs
+    pub fn parse_policy(&self) -> ParsePolicy {
+        self.parse_policy
+    }
+
+    /// Set the policy used to parse received LLDPDUs
+    ///
+    /// By default the agent uses [ParsePolicy::Strict] and discards every non-conformant LLDPDU. With
+    /// [ParsePolicy::Lenient] it logs as much of such LLDPDUs as possible, followed by the problems found.
+    pub fn set_parse_policy(&mut self, policy: ParsePolicy) {
+        self.parse_policy = policy;
+    }
+
+    /// Get the registry used to decode received Organizationally Specific TLVs
+    pub fn org_registry(&self) -> &OrgTlvRegistry {
+        &self.org_registry
+    }
+
+    /// Set the registry used to decode received Organizationally Specific TLVs
+    ///
+    /// Every Organizationally Specific TLV known to the registry is logged in its decoded form after the LLDPDU.
+    pub fn set_org_registry(&mut self, registry: OrgTlvRegistry) {
+        self.org_registry = registry;
+    }
+
+    /// Enable DCBX or change the local DCBX configuration
+    ///
+    /// The agent advertises the configuration in its announcements and negotiates the operational configuration with
+    /// the peer, see [dcbx]. Enabling DCBX emits the initial operational configuration as event.
+    pub fn set_dcbx(&mut self, config: DcbxConfig) {
+        let events = match &mut self.dcbx {
+            Some(state) => state.set_local(config),
+            None => {
+                let state = DcbxState::new(self.mac_address.into(), config);
+                let events = vec![DcbxEvent::OperationalChanged(state.operational().clone())];
+                self.dcbx = Some(state);
+                events
+            }
+        };
+        self.dcbx_event(events);
+    }
+
+    /// Get the DCBX state, including the operational configuration and the mismatch state
+    ///
+    /// Returns `None` if DCBX is not enabled.
+    pub fn dcbx(&self) -> Option<&DcbxState> {
+        self.dcbx.as_ref()
+    }
+
+    /// Take the DCBX events that occurred since the last call
+    pub fn take_dcbx_events(&mut self) -> Vec<DcbxEvent> {
+        std::mem::take(&mut self.dcbx_events)
+    }
+
+    /// Log and queue DCBX events
+    fn dcbx_event(&mut self, events: Vec<DcbxEvent>) {
+        for event in events {
+            self.logger.log(&format!("{}", event));
+            self.dcbx_events.push(event);
         }
     }
 
@@ -100,6 +195,9 @@ impl LLDPAgent {
     /// has been received, it will check if the frame is a valid LLDP frame and, if so, log its contents for the
     /// administrator. All other frames will be ignored.
     ///
+    /// LLDP frames that do not contain an LLDPDU acceptable under the agent's [ParsePolicy] are discarded and counted
+    /// in the agent's [AgentStatistics].
+    ///
     /// Valid LLDP frames have an ethertype of 0x88CC, are directed to one of the LLDP multicast addresses
     /// (01:80:c2:00:00:00, 01:80:c2:00:00:03 and 01:80:c2:00:00:0e) and have not been sent by the local agent.
     ///
@@ -110,7 +208,7 @@ impl LLDPAgent {
     pub fn run(&mut self, run_once: bool) {
         let mut t_previous = Instant::now();
 
-        let valid_destination = vec![
+        let valid_destination = [
             MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x00),
             MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x03),
             MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e),
@@ -142,10 +240,41 @@ impl LLDPAgent {
                     }
 
                     // Instantiate Lldpdu struct from raw bytes
-                    let lldpdu: Lldpdu = Lldpdu::from_bytes(ether_frame.payload());
+                    let lldpdu: Lldpdu =
+                        match Lldpdu::parse(ether_frame.payload(), self.parse_policy) {
+                            Ok(lldpdu) => lldpdu,
+                            Err(_) => {
+                                self.statistics.frames_discarded_total += 1;
+                                self.statistics.frames_in_errors_total += 1;
+                                continue;
+                            }
+                        };
+                    self.statistics.frames_in_total += 1;
+                    self.statistics.tlvs_unrecognized_total += lldpdu
+                        .tlvs()
+                        .filter(|tlv| matches!(tlv, Tlv::Unknown { .. }))
+                        .count()
+                        as u64;
 
                     // Log contents
                     self.logger.log(&format!("{}", lldpdu));
+                    for warning in lldpdu.warnings() {
+                        self.logger.log(&format!("warning: {}", warning));
+                    }
+                    for tlv in lldpdu.tlvs() {
+                        if let Tlv::OrganizationallySpecific(tlv) = tlv {
+                            match self.org_registry.decode(tlv) {
+                                Some(Ok(value)) => self.logger.log(&format!("{}", value)),
+                                Some(Err(e)) => self.logger.log(&format!("warning: {}", e)),
+                                None => {}
+                            }
+                        }
+                    }
+
+                    if let Some(state) = &mut self.dcbx {
+                        let events = state.receive(source_mac.into(), &lldpdu, Instant::now());
+                        self.dcbx_event(events);
+                    }
 
                     if run_once {
                         break;
@@ -156,8 +285,14 @@ impl LLDPAgent {
                     panic!("An error occurred while reading: {}", e);
                 }
             }
-            // Announce if the time is right
+            // Age out the DCBX peer
             let t_now = Instant::now();
+            if let Some(state) = &mut self.dcbx {
+                let events = state.expire(t_now);
+                self.dcbx_event(events);
+            }
+
+            // Announce if the time is right
             if (t_now - t_previous).as_secs_f32() > self.interval {
                 self.announce();
                 t_previous = t_now;
@@ -173,26 +308,35 @@ impl LLDPAgent {
     /// * the agent's MAC address as its chassis id
     /// * the agent's interface name as port id
     /// * a TTL of 60 seconds
+    /// * the local DCBX configuration, if DCBX is enabled
+    /// * an End Of LLDPDU TLV
     pub fn announce(&mut self) {
         // Construct LLDPDU
-        let init_tlvs: Vec<Tlv> = vec![
+        let mut init_tlvs: Vec<Tlv> = vec![
             Tlv::ChassisId(ChassisIdTLV::new(
                 ChassisIdSubType::MacAddress,
-                ChassisIdValue::Mac(self.mac_address.octets().to_vec()),
+                ChassisIdValue::Mac(self.mac_address.into()),
             )),
             Tlv::PortId(PortIdTLV::new(
                 PortIdSubtype::InterfaceName,
                 PortIdValue::Other(self.interface_name.clone()),
             )),
             Tlv::Ttl(TtlTLV::new(60)),
-            // Tlv::EndOfLldpdu(EndOfLLDPDUTLV::new()),
         ];
+        if let Some(state) = &self.dcbx {
+            init_tlvs.extend(state.local().tlvs());
+        }
+        init_tlvs.push(Tlv::EndOfLldpdu(EndOfLLDPDUTLV::new()));
 
         let lldpdu: Lldpdu = Lldpdu::new(init_tlvs);
 
-        // Construct Ethernet Frame
-        let mut header = [0u8; 14];
-        let mut ethernet_header = MutableEthernetPacket::new(&mut header[..]).unwrap();
+        // Construct Ethernet Frame in the transmit buffer
+        let header_len = EthernetPacket::minimum_packet_size();
+        self.frame.clear();
+        self.frame.reserve(header_len + lldpdu.encoded_len());
+        self.frame.resize(header_len, 0);
+
+        let mut ethernet_header = MutableEthernetPacket::new(&mut self.frame[..]).unwrap();
 
         let source = self.mac_address;
         ethernet_header.set_source(source);
@@ -202,11 +346,10 @@ impl LLDPAgent {
 
         ethernet_header.set_ethertype(EtherTypes::Lldp);
 
-        let mut frame = header.to_vec();
-        frame.extend_from_slice(&lldpdu.bytes());
+        lldpdu.encode(&mut self.frame);
 
         // Send frame
-        match self.channel.0.send_to(&frame, None) {
+        match self.channel.0.send_to(&self.frame, None) {
             Some(Ok(_)) => (),
             Some(Err(err)) => panic!("ERROR: Announce failed: {:?}", err),
             None => (),
@@ -219,7 +362,11 @@ mod tests {
     use pnet::datalink::dummy::{self, dummy_interface, Config};
 
     use super::*;
-    use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
+    use crate::tlv::mac_address::MacAddress;
+    use crate::tlv::organizationallyspecific_tlv::dcbx::{
+        EtsConfigurationTLV, EtsTables, PfcConfigurationTLV,
+    };
+    use crate::tlv::organizationallyspecific_tlv::registry::OrgTlvValue;
     use std::cell::RefCell;
     use std::rc::Rc;
     use std::sync::mpsc;
@@ -255,7 +402,7 @@ mod tests {
 
         assert_eq!(
             received.as_ref(),
-            b"\x01\x80\xc2\x00\x00\x0e\x66\x6F\x6F\x62\x61\x72\x88\xcc\x02\x07\x04foobar\x04\x03\x05lo\x06\x02\x00\x3c"
+            b"\x01\x80\xc2\x00\x00\x0e\x66\x6F\x6F\x62\x61\x72\x88\xcc\x02\x07\x04foobar\x04\x03\x05lo\x06\x02\x00\x3c\x00\x00"
         );
     }
 
@@ -288,8 +435,247 @@ mod tests {
 
         assert_eq!(
             received.as_ref(),
-            b"\x01\x80\xc2\x00\x00\x0e\x28\x5E\x5F\x5E\x27\x29\x88\xcc\x02\x07\x04(^_^')\x04\x07\x05enp4s0\x06\x02\x00\x3c"
+            b"\x01\x80\xc2\x00\x00\x0e\x28\x5E\x5F\x5E\x27\x29\x88\xcc\x02\x07\x04(^_^')\x04\x07\x05enp4s0\x06\x02\x00\x3c\x00\x00"
+        );
+    }
+
+    #[test]
+    fn test_announce_reuses_buffer() {
+        let (tx_sender, tx_receiver) = mpsc::channel();
+        let (_, rx_receiver) = mpsc::channel();
+        let dummy_loopback = dummy_interface(42);
+        let dummy_config = Config::new(rx_receiver, tx_sender);
+
+        let (tx, rx) = {
+            match dummy::channel(&dummy_loopback, dummy_config) {
+                Ok(Ethernet(tx, rx)) => (tx, rx),
+                _ => unreachable!("pnet is broken"),
+            }
+        };
+
+        let mut a = LLDPAgent::new(
+            MacAddr::new(102, 111, 111, 98, 97, 114),
+            String::from("lo"),
+            1.0,
+            Some((tx, rx)),
+            None,
+        );
+        a.announce();
+        a.announce();
+
+        let first = tx_receiver
+            .try_recv()
+            .expect("No packet received from agent");
+        let second = tx_receiver
+            .try_recv()
+            .expect("No packet received from agent");
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 14 + 20);
+    }
+
+    #[test]
+    fn test_announce_parse() {
+        let (tx_sender, tx_receiver) = mpsc::channel();
+        let (_, rx_receiver) = mpsc::channel();
+        let dummy_loopback = dummy_interface(42);
+        let dummy_config = Config::new(rx_receiver, tx_sender);
+
+        let (tx, rx) = {
+            match dummy::channel(&dummy_loopback, dummy_config) {
+                Ok(Ethernet(tx, rx)) => (tx, rx),
+                _ => unreachable!("pnet is broken"),
+            }
+        };
+
+        let mut a = LLDPAgent::new(
+            MacAddr::new(102, 111, 111, 98, 97, 114),
+            String::from("lo"),
+            1.0,
+            Some((tx, rx)),
+            None,
+        );
+        let tables = EtsTables::new([0; 8], [100, 0, 0, 0, 0, 0, 0, 0], [2, 0, 0, 0, 0, 0, 0, 0]);
+        a.set_dcbx(DcbxConfig::new(
+            EtsConfigurationTLV::new(true, 8, tables),
+            PfcConfigurationTLV::new(true, 8, 0x08),
+        ));
+        a.announce();
+
+        let received = tx_receiver
+            .try_recv()
+            .expect("No packet received from agent");
+
+        // Another agent accepts the announcement with its default policy
+        let frame = EthernetPacket::new(&received).unwrap();
+        let lldpdu = Lldpdu::parse(frame.payload(), a.parse_policy()).unwrap();
+        assert!(lldpdu.complete());
+        assert_eq!(lldpdu.len(), 6);
+    }
+
+    #[test]
+    fn test_run_lenient() {
+        let full_log = Rc::new(RefCell::new(String::new()));
+        let logger = Box::new(MockLogger {
+            inner: full_log.clone(),
+        });
+
+        let (tx_sender, _tx_receiver) = mpsc::channel();
+        let (rx_sender, rx_receiver) = mpsc::channel();
+        let dummy_loopback = dummy_interface(42);
+        let dummy_config = Config::new(rx_receiver, tx_sender);
+
+        let (tx, rx) = {
+            match dummy::channel(&dummy_loopback, dummy_config) {
+                Ok(Ethernet(tx, rx)) => (tx, rx),
+                _ => unreachable!("pnet is broken"),
+            }
+        };
+
+        // The System Capabilities TLV enables capabilities that are not supported
+        let frame = b"\x01\x80\xc2\x00\x00\x0e\xff\xee\xdd\xcc\xbb\xaa\x88\xcc\x02\x07\x04\xff\xee\xdd\xcc\xbb\xaa\x04\x07\x03\xff\xee\xdd\xcc\xbb\xaa\x06\x02\x00x\x0e\x04\x00\x04\x00\x14\x00\x00";
+        rx_sender
+            .send(Ok(frame.to_vec().into_boxed_slice()))
+            .unwrap();
+
+        let mut a = LLDPAgent::new(
+            MacAddr::new(170, 187, 204, 221, 238, 255),
+            String::from("lo"),
+            1.0,
+            Some((tx, rx)),
+            Some(logger),
         );
+        assert_eq!(a.parse_policy(), ParsePolicy::Strict);
+        a.set_parse_policy(ParsePolicy::Lenient);
+        a.run(true);
+
+        assert_eq!(full_log.borrow().as_str(), "LLDPDU(ChassisIdTLV(4, \"ff:ee:dd:cc:bb:aa\"), PortIdTLV(3, \"ff:ee:dd:cc:bb:aa\"), TtlTLV(120), EndOfLLDPDUTLV)warning: enabled capabilities 0x0014 are not supported (0x0004)");
+        assert_eq!(a.statistics().frames_in_total, 1);
+    }
+
+    #[test]
+    fn test_run_org_registry() {
+        #[derive(Debug)]
+        struct RackTLV(u8);
+
+        impl std::fmt::Display for RackTLV {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "RackTLV({})", self.0)
+            }
+        }
+
+        impl OrgTlvValue for RackTLV {
+            fn write_info(&self, buf: &mut Vec<u8>) {
+                buf.push(self.0);
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let full_log = Rc::new(RefCell::new(String::new()));
+        let logger = Box::new(MockLogger {
+            inner: full_log.clone(),
+        });
+
+        let (tx_sender, _tx_receiver) = mpsc::channel();
+        let (rx_sender, rx_receiver) = mpsc::channel();
+        let dummy_loopback = dummy_interface(42);
+        let dummy_config = Config::new(rx_receiver, tx_sender);
+
+        let (tx, rx) = {
+            match dummy::channel(&dummy_loopback, dummy_config) {
+                Ok(Ethernet(tx, rx)) => (tx, rx),
+                _ => unreachable!("pnet is broken"),
+            }
+        };
+
+        // A proprietary TLV followed by a Port VLAN ID TLV
+        let frame = b"\x01\x80\xc2\x00\x00\x0e\xff\xee\xdd\xcc\xbb\xaa\x88\xcc\x02\x07\x04\xff\xee\xdd\xcc\xbb\xaa\x04\x07\x03\xff\xee\xdd\xcc\xbb\xaa\x06\x02\x00x\xfe\x05\x02\x00\x00\x01\x2a\xfe\x06\x00\x80\xc2\x01\x00\x64\x00\x00";
+        rx_sender
+            .send(Ok(frame.to_vec().into_boxed_slice()))
+            .unwrap();
+
+        let mut a = LLDPAgent::new(
+            MacAddr::new(170, 187, 204, 221, 238, 255),
+            String::from("lo"),
+            1.0,
+            Some((tx, rx)),
+            Some(logger),
+        );
+        let mut registry = OrgTlvRegistry::with_builtin();
+        registry.register([0x02, 0x00, 0x00], 1, |info: &[u8]| {
+            Ok(Box::new(RackTLV(info[0])) as Box<dyn OrgTlvValue>)
+        });
+        a.set_org_registry(registry);
+        assert!(a.org_registry().contains([0x02, 0x00, 0x00], 1));
+        a.run(true);
+
+        assert!(full_log
+            .borrow()
+            .ends_with("EndOfLLDPDUTLV)RackTLV(42)PortVlanIdTLV(100)"));
+    }
+
+    #[test]
+    fn test_run_dcbx() {
+        let full_log = Rc::new(RefCell::new(String::new()));
+        let logger = Box::new(MockLogger {
+            inner: full_log.clone(),
+        });
+
+        let (tx_sender, tx_receiver) = mpsc::channel();
+        let (rx_sender, rx_receiver) = mpsc::channel();
+        let dummy_loopback = dummy_interface(42);
+        let dummy_config = Config::new(rx_receiver, tx_sender);
+
+        let (tx, rx) = {
+            match dummy::channel(&dummy_loopback, dummy_config) {
+                Ok(Ethernet(tx, rx)) => (tx, rx),
+                _ => unreachable!("pnet is broken"),
+            }
+        };
+
+        // The peer is not willing and enables PFC for priorities 3 and 4
+        let frame = b"\x01\x80\xc2\x00\x00\x0e\xff\xee\xdd\xcc\xbb\xaa\x88\xcc\x02\x07\x04\xff\xee\xdd\xcc\xbb\xaa\x04\x07\x03\xff\xee\xdd\xcc\xbb\xaa\x06\x02\x00x\xfe\x06\x00\x80\xc2\x0b\x08\x18\x00\x00";
+        rx_sender
+            .send(Ok(frame.to_vec().into_boxed_slice()))
+            .unwrap();
+
+        let mut a = LLDPAgent::new(
+            MacAddr::new(170, 187, 204, 221, 238, 255),
+            String::from("lo"),
+            1.0,
+            Some((tx, rx)),
+            Some(logger),
+        );
+        let tables = EtsTables::new([0; 8], [100, 0, 0, 0, 0, 0, 0, 0], [2, 0, 0, 0, 0, 0, 0, 0]);
+        a.set_dcbx(DcbxConfig::new(
+            EtsConfigurationTLV::new(true, 8, tables),
+            PfcConfigurationTLV::new(true, 8, 0x08),
+        ));
+        let events = a.take_dcbx_events();
+        assert!(matches!(
+            events.as_slice(),
+            [DcbxEvent::OperationalChanged(operational)] if operational.pfc == 0x08
+        ));
+        a.run(true);
+
+        assert_eq!(a.dcbx().unwrap().operational().pfc, 0x18);
+        let events = a.take_dcbx_events();
+        assert!(matches!(
+            events.as_slice(),
+            [DcbxEvent::OperationalChanged(_)]
+        ));
+        assert!(a.take_dcbx_events().is_empty());
+        assert!(full_log.borrow().ends_with(
+            "dcbx: DcbxOperational(ETS(TC0: ETS 100% [0 1 2 3 4 5 6 7]), PFC on [3 4], App())"
+        ));
+
+        a.announce();
+        let received = tx_receiver
+            .try_recv()
+            .expect("No packet received from agent");
+        assert!(received.ends_with(b"\xfe\x06\x00\x80\xc2\x0b\x88\x08\x00\x00"));
     }
 
     #[test]
@@ -352,15 +738,15 @@ mod tests {
         let mut lldpdu = Lldpdu::new(vec![]);
         lldpdu.append(Tlv::ChassisId(ChassisIdTLV::new(
             ChassisIdSubType::MacAddress,
-            ChassisIdValue::Mac(b"\xff\xee\xdd\xcc\xbb\xaa".to_vec()),
+            ChassisIdValue::Mac(MacAddress::new([0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa])),
         )));
         lldpdu.append(Tlv::PortId(PortIdTLV::new(
             PortIdSubtype::MacAddress,
-            PortIdValue::Mac(b"\xff\xee\xdd\xcc\xbb\xaa".to_vec()),
+            PortIdValue::Mac(MacAddress::new([0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa])),
         )));
         lldpdu.append(Tlv::Ttl(TtlTLV::new(120)));
         lldpdu.append(Tlv::EndOfLldpdu(EndOfLLDPDUTLV::new()));
 
-        assert_eq!(full_log.borrow().as_str(), "LLDPDU(ChassisIdTLV(4, \"FF:EE:DD:CC:BB:AA\"), PortIdTLV(3, \"FF:EE:DD:CC:BB:AA\"), TtlTLV(120), EndOfLLDPDUTLV)");
+        assert_eq!(full_log.borrow().as_str(), "LLDPDU(ChassisIdTLV(4, \"ff:ee:dd:cc:bb:aa\"), PortIdTLV(3, \"ff:ee:dd:cc:bb:aa\"), TtlTLV(120), EndOfLLDPDUTLV)");
     }
 }
@@ -0,0 +1,552 @@
+//! DCBX negotiation
+//!
+//! Implements the IEEE 802.1Qaz exchange of an end station. The agent advertises its local ETS, PFC and Application
+//! Priority settings and derives the operational configuration from the settings of the peer:
+//!
+//! * ETS is asymmetric: a willing station uses the ETS Recommendation of its peer.
+//! * PFC is symmetric: a willing station uses the PFC configuration of its peer. If both stations are willing, the
+//!   station with the numerically lower MAC address keeps its local configuration.
+//! * The Application Priority TLV has no willing bit, the table of the peer is used if
+//!   [DcbxConfig::app_willing] is set.
+//!
+//! A feature is in mismatch if the peer advertises a configuration that differs from the operational configuration. For
+//! ETS the recommendation of the peer is compared, as its ETS configuration only describes the peer itself.
+//!
+//! Only one peer is tracked, identified by its source MAC address. It is lost when it stops advertising DCBX TLVs,
+//! shuts down or its TTL expires, see [DcbxState::expire].
+
+use crate::lldpdu::Lldpdu;
+use crate::tlv::mac_address::MacAddress;
+use crate::tlv::organizationallyspecific_tlv::dcbx::{
+    AppPriority, ApplicationPriorityTLV, EtsConfigurationTLV, EtsRecommendationTLV, EtsTables,
+    PfcConfigurationTLV,
+};
+use crate::tlv::organizationallyspecific_tlv::OrgTlvCodec;
+use crate::tlv::Tlv;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// Local DCBX settings of the agent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DcbxConfig {
+    /// The local ETS configuration, including the willing bit
+    pub ets: EtsConfigurationTLV,
+    /// The ETS configuration recommended to the peer
+    pub ets_recommendation: Option<EtsRecommendationTLV>,
+    /// The local PFC configuration, including the willing bit
+    pub pfc: PfcConfigurationTLV,
+    /// The local application priority table, not advertised if empty
+    pub app: ApplicationPriorityTLV,
+    /// Use the application priority table of the peer
+    pub app_willing: bool,
+}
+
+impl DcbxConfig {
+    /// Create a configuration without ETS recommendation and application priorities
+    pub fn new(ets: EtsConfigurationTLV, pfc: PfcConfigurationTLV) -> DcbxConfig {
+        DcbxConfig {
+            ets,
+            ets_recommendation: None,
+            pfc,
+            app: ApplicationPriorityTLV::new(vec![]),
+            app_willing: false,
+        }
+    }
+
+    /// Recommend an ETS configuration to the peer
+    pub fn with_recommendation(mut self, recommendation: EtsRecommendationTLV) -> DcbxConfig {
+        self.ets_recommendation = Some(recommendation);
+        self
+    }
+
+    /// Set the application priority table and whether the table of the peer is used instead
+    pub fn with_app(mut self, app: ApplicationPriorityTLV, willing: bool) -> DcbxConfig {
+        self.app = app;
+        self.app_willing = willing;
+        self
+    }
+
+    /// Get the TLVs advertising the configuration
+    pub fn tlvs(&self) -> Vec<Tlv> {
+        let mut tlvs = vec![Tlv::OrganizationallySpecific(self.ets.to_tlv())];
+        if let Some(recommendation) = &self.ets_recommendation {
+            tlvs.push(Tlv::OrganizationallySpecific(recommendation.to_tlv()));
+        }
+        tlvs.push(Tlv::OrganizationallySpecific(self.pfc.to_tlv()));
+        if !self.app.entries.is_empty() {
+            tlvs.push(Tlv::OrganizationallySpecific(self.app.to_tlv()));
+        }
+
+        tlvs
+    }
+
+    fn operational(&self) -> DcbxOperational {
+        DcbxOperational {
+            ets: self.ets.tables.clone(),
+            pfc: self.pfc.enabled,
+            app: self.app.entries.clone(),
+        }
+    }
+}
+
+/// DCBX settings advertised by the peer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DcbxPeer {
+    /// The source MAC address of the peer
+    pub mac_address: MacAddress,
+    /// The ETS configuration of the peer
+    pub ets: Option<EtsConfigurationTLV>,
+    /// The ETS configuration recommended by the peer
+    pub ets_recommendation: Option<EtsRecommendationTLV>,
+    /// The PFC configuration of the peer
+    pub pfc: Option<PfcConfigurationTLV>,
+    /// The application priority table of the peer
+    pub app: Option<ApplicationPriorityTLV>,
+}
+
+impl DcbxPeer {
+    /// Get the DCBX TLVs of an LLDPDU, returns `None` if it does not contain any valid DCBX TLV.
+    fn from_lldpdu(mac_address: MacAddress, lldpdu: &Lldpdu) -> Option<DcbxPeer> {
+        fn find<T: OrgTlvCodec>(lldpdu: &Lldpdu) -> Option<T> {
+            lldpdu
+                .org_specific(T::OUI, T::SUBTYPE)
+                .find_map(|tlv| T::try_from_tlv(tlv).ok())
+        }
+
+        let peer = DcbxPeer {
+            mac_address,
+            ets: find(lldpdu),
+            ets_recommendation: find(lldpdu),
+            pfc: find(lldpdu),
+            app: find(lldpdu),
+        };
+
+        if peer.ets.is_none()
+            && peer.ets_recommendation.is_none()
+            && peer.pfc.is_none()
+            && peer.app.is_none()
+        {
+            return None;
+        }
+
+        Some(peer)
+    }
+}
+
+/// Operational (negotiated) DCBX configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DcbxOperational {
+    /// The ETS tables in use
+    pub ets: EtsTables,
+    /// Bitmap of the priorities with PFC enabled, bit n corresponds to priority n
+    pub pfc: u8,
+    /// The application priority table in use
+    pub app: Vec<AppPriority>,
+}
+
+impl Display for DcbxOperational {
+    /// Write the operational configuration, e.g. `DcbxOperational(ETS(TC0: ETS 100% [0 1 2 3 4 5 6 7]), PFC on [3],
+    /// App())`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let priorities: Vec<String> = (0..8)
+            .filter(|priority| self.pfc & (1 << priority) != 0)
+            .map(|priority| priority.to_string())
+            .collect();
+        let app: Vec<String> = self.app.iter().map(|entry| entry.to_string()).collect();
+
+        write!(
+            f,
+            "DcbxOperational(ETS({}), PFC on [{}], App({}))",
+            self.ets,
+            priorities.join(" "),
+            app.join(", ")
+        )
+    }
+}
+
+/// Features whose operational configuration differs from the configuration advertised by the peer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DcbxMismatch {
+    /// The ETS configuration recommended by the peer differs from the operational ETS tables
+    pub ets: bool,
+    /// The PFC configuration of the peer differs from the operational PFC priorities
+    pub pfc: bool,
+    /// The application priority table of the peer differs from the operational table
+    pub app: bool,
+}
+
+impl DcbxMismatch {
+    /// Check if any feature is in mismatch
+    pub fn any(&self) -> bool {
+        self.ets || self.pfc || self.app
+    }
+}
+
+impl Display for DcbxMismatch {
+    /// Write the features in mismatch, e.g. `DcbxMismatch(PFC, App)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let features: Vec<&str> = [(self.ets, "ETS"), (self.pfc, "PFC"), (self.app, "App")]
+            .iter()
+            .filter(|(mismatch, _)| *mismatch)
+            .map(|(_, name)| *name)
+            .collect();
+
+        match features.is_empty() {
+            true => write!(f, "DcbxMismatch(none)"),
+            false => write!(f, "DcbxMismatch({})", features.join(", ")),
+        }
+    }
+}
+
+/// Change of the DCBX state, see [DcbxState::receive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DcbxEvent {
+    /// The operational configuration changed
+    OperationalChanged(DcbxOperational),
+    /// The mismatch state changed
+    MismatchChanged(DcbxMismatch),
+    /// The peer stopped advertising DCBX TLVs, shut down or its TTL expired
+    PeerLost,
+}
+
+impl Display for DcbxEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DcbxEvent::OperationalChanged(operational) => write!(f, "dcbx: {}", operational),
+            DcbxEvent::MismatchChanged(mismatch) => write!(f, "dcbx: {}", mismatch),
+            DcbxEvent::PeerLost => write!(f, "dcbx: peer lost"),
+        }
+    }
+}
+
+/// DCBX state of the agent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DcbxState {
+    mac_address: MacAddress,
+    local: DcbxConfig,
+    peer: Option<DcbxPeer>,
+    /// The time the information of the peer expires
+    peer_expires: Option<Instant>,
+    operational: DcbxOperational,
+    mismatch: DcbxMismatch,
+}
+
+impl DcbxState {
+    /// Create the state of a station with the given MAC address, which has not received DCBX TLVs yet.
+    pub fn new(mac_address: MacAddress, local: DcbxConfig) -> DcbxState {
+        DcbxState {
+            mac_address,
+            operational: local.operational(),
+            local,
+            peer: None,
+            peer_expires: None,
+            mismatch: DcbxMismatch::default(),
+        }
+    }
+
+    /// Get the local configuration
+    pub fn local(&self) -> &DcbxConfig {
+        &self.local
+    }
+
+    /// Get the settings of the peer
+    pub fn peer(&self) -> Option<&DcbxPeer> {
+        self.peer.as_ref()
+    }
+
+    /// Get the operational configuration
+    pub fn operational(&self) -> &DcbxOperational {
+        &self.operational
+    }
+
+    /// Get the mismatch state
+    pub fn mismatch(&self) -> DcbxMismatch {
+        self.mismatch
+    }
+
+    /// Change the local configuration and renegotiate.
+    pub fn set_local(&mut self, local: DcbxConfig) -> Vec<DcbxEvent> {
+        self.local = local;
+        self.negotiate()
+    }
+
+    /// Process an LLDPDU received from `source` at `now` and renegotiate.
+    ///
+    /// A shutdown LLDPDU or an LLDPDU without DCBX TLVs removes the peer, the operational configuration falls back to
+    /// the local configuration. LLDPDUs of other stations are ignored while a peer is known. The peer expires after
+    /// the TTL of the LLDPDU, see [DcbxState::expire].
+    pub fn receive(&mut self, source: MacAddress, lldpdu: &Lldpdu, now: Instant) -> Vec<DcbxEvent> {
+        if matches!(&self.peer, Some(peer) if peer.mac_address != source) {
+            return vec![];
+        }
+
+        let peer = match lldpdu.ttl() {
+            Some(0) => None,
+            _ => DcbxPeer::from_lldpdu(source, lldpdu),
+        };
+        self.peer_expires = lldpdu
+            .ttl()
+            .map(|ttl| now + Duration::from_secs(ttl.into()));
+
+        self.update_peer(peer)
+    }
+
+    /// Remove the peer if its TTL expired at `now` and renegotiate.
+    ///
+    /// Must be called regularly, as a peer that disappears without shutdown LLDPDU is not noticed otherwise.
+    pub fn expire(&mut self, now: Instant) -> Vec<DcbxEvent> {
+        match self.peer_expires {
+            Some(expires) if now >= expires => self.update_peer(None),
+            _ => vec![],
+        }
+    }
+
+    fn update_peer(&mut self, peer: Option<DcbxPeer>) -> Vec<DcbxEvent> {
+        let lost = self.peer.is_some() && peer.is_none();
+        self.peer = peer;
+        if self.peer.is_none() {
+            self.peer_expires = None;
+        }
+
+        let mut events = vec![];
+        if lost {
+            events.push(DcbxEvent::PeerLost);
+        }
+        events.extend(self.negotiate());
+        events
+    }
+
+    fn negotiate(&mut self) -> Vec<DcbxEvent> {
+        let mut operational = self.local.operational();
+        let mut mismatch = DcbxMismatch::default();
+
+        if let Some(peer) = &self.peer {
+            if let (true, Some(recommendation)) = (self.local.ets.willing, &peer.ets_recommendation)
+            {
+                operational.ets = recommendation.tables.clone();
+            }
+
+            if let Some(pfc) = &peer.pfc {
+                // If both are willing, the station with the lower MAC address keeps its configuration
+                if self.local.pfc.willing
+                    && (!pfc.willing || self.mac_address.octets() > peer.mac_address.octets())
+                {
+                    operational.pfc = pfc.enabled;
+                }
+                mismatch.pfc = pfc.enabled != operational.pfc;
+            }
+
+            if let Some(app) = &peer.app {
+                if self.local.app_willing {
+                    operational.app = app.entries.clone();
+                }
+                mismatch.app = app.entries != operational.app;
+            }
+
+            if let Some(recommendation) = &peer.ets_recommendation {
+                mismatch.ets = recommendation.tables != operational.ets;
+            }
+        }
+
+        let mut events = vec![];
+        if operational != self.operational {
+            self.operational = operational;
+            events.push(DcbxEvent::OperationalChanged(self.operational.clone()));
+        }
+        if mismatch != self.mismatch {
+            self.mismatch = mismatch;
+            events.push(DcbxEvent::MismatchChanged(mismatch));
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lldpdu::builder::LldpduBuilder;
+
+    const LOCAL: MacAddress = MacAddress([0x02, 0x00, 0x00, 0x00, 0x00, 0x02]);
+    const LOWER: MacAddress = MacAddress([0x02, 0x00, 0x00, 0x00, 0x00, 0x01]);
+    const HIGHER: MacAddress = MacAddress([0x02, 0x00, 0x00, 0x00, 0x00, 0x03]);
+
+    fn tables(bandwidth: u8) -> EtsTables {
+        EtsTables::new(
+            [0, 0, 0, 1, 0, 0, 0, 0],
+            [bandwidth, 100 - bandwidth, 0, 0, 0, 0, 0, 0],
+            [2, 2, 0, 0, 0, 0, 0, 0],
+        )
+    }
+
+    fn config(willing: bool) -> DcbxConfig {
+        DcbxConfig::new(
+            EtsConfigurationTLV::new(willing, 8, tables(50)),
+            PfcConfigurationTLV::new(willing, 8, 0x08),
+        )
+    }
+
+    fn lldpdu(ttl: u16, config: &DcbxConfig) -> Lldpdu {
+        config
+            .tlvs()
+            .into_iter()
+            .fold(
+                LldpduBuilder::new()
+                    .chassis_mac(HIGHER)
+                    .port_ifname("eth0")
+                    .ttl(ttl),
+                |builder, tlv| builder.tlv(tlv),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_not_willing() {
+        let mut state = DcbxState::new(LOCAL, config(false));
+        let peer = config(true)
+            .with_recommendation(EtsRecommendationTLV::new(tables(80)))
+            .with_app(
+                ApplicationPriorityTLV::new(vec![AppPriority::new(3, 1, 0x8906)]),
+                false,
+            );
+
+        let events = state.receive(HIGHER, &lldpdu(120, &peer), Instant::now());
+        assert_eq!(
+            events,
+            vec![DcbxEvent::MismatchChanged(DcbxMismatch {
+                ets: true,
+                pfc: false,
+                app: true
+            })]
+        );
+        assert_eq!(state.operational(), &config(false).operational());
+        assert_eq!(state.peer().unwrap().mac_address, HIGHER);
+    }
+
+    #[test]
+    fn test_willing() {
+        let mut state = DcbxState::new(LOCAL, config(true));
+        let mut peer = config(false).with_recommendation(EtsRecommendationTLV::new(tables(80)));
+        peer.pfc.enabled = 0x18;
+
+        let events = state.receive(LOWER, &lldpdu(120, &peer), Instant::now());
+        let operational = DcbxOperational {
+            ets: tables(80),
+            pfc: 0x18,
+            app: vec![],
+        };
+        assert_eq!(
+            events,
+            vec![DcbxEvent::OperationalChanged(operational.clone())]
+        );
+        assert_eq!(state.operational(), &operational);
+        assert_eq!(
+            events[0].to_string(),
+            "dcbx: DcbxOperational(ETS(TC0: ETS 80% [0 1 2 4 5 6 7]; TC1: ETS 20% [3]), PFC on [3 4], App())"
+        );
+
+        // Nothing changed
+        assert!(state
+            .receive(LOWER, &lldpdu(120, &peer), Instant::now())
+            .is_empty());
+
+        let shutdown = LldpduBuilder::new()
+            .chassis_mac(LOWER)
+            .port_ifname("eth0")
+            .ttl(0)
+            .build()
+            .unwrap();
+        let events = state.receive(LOWER, &shutdown, Instant::now());
+        assert_eq!(events[0], DcbxEvent::PeerLost);
+        assert_eq!(state.operational(), &config(true).operational());
+        assert!(!state.mismatch().any());
+    }
+
+    #[test]
+    fn test_both_willing() {
+        let mut peer = config(true);
+        peer.pfc.enabled = 0x18;
+
+        // The local station has the lower MAC address and keeps its configuration
+        let mut state = DcbxState::new(LOCAL, config(true));
+        state.receive(HIGHER, &lldpdu(120, &peer), Instant::now());
+        assert_eq!(state.operational().pfc, 0x08);
+        assert!(state.mismatch().pfc);
+        assert_eq!(state.mismatch().to_string(), "DcbxMismatch(PFC)");
+
+        let mut state = DcbxState::new(LOCAL, config(true));
+        state.receive(LOWER, &lldpdu(120, &peer), Instant::now());
+        assert_eq!(state.operational().pfc, 0x18);
+        assert!(!state.mismatch().pfc);
+    }
+
+    #[test]
+    fn test_set_local() {
+        let mut state = DcbxState::new(LOCAL, config(false));
+        state.receive(HIGHER, &lldpdu(120, &config(false)), Instant::now());
+        assert!(!state.mismatch().any());
+
+        let mut local = config(false);
+        local.pfc.enabled = 0x00;
+        let events = state.set_local(local);
+        assert_eq!(events.len(), 2);
+        assert!(state.mismatch().pfc);
+    }
+
+    #[test]
+    fn test_other_source() {
+        let mut state = DcbxState::new(LOCAL, config(true));
+        let mut peer = config(false);
+        peer.pfc.enabled = 0x18;
+        state.receive(HIGHER, &lldpdu(120, &peer), Instant::now());
+        assert_eq!(state.operational().pfc, 0x18);
+
+        // Neither the configuration nor the shutdown of another station affects the peer
+        peer.pfc.enabled = 0x28;
+        assert!(state
+            .receive(LOWER, &lldpdu(120, &peer), Instant::now())
+            .is_empty());
+        let shutdown = LldpduBuilder::new()
+            .chassis_mac(LOWER)
+            .port_ifname("eth0")
+            .ttl(0)
+            .build()
+            .unwrap();
+        assert!(state.receive(LOWER, &shutdown, Instant::now()).is_empty());
+        assert_eq!(state.peer().unwrap().mac_address, HIGHER);
+        assert_eq!(state.operational().pfc, 0x18);
+    }
+
+    #[test]
+    fn test_expire() {
+        let mut state = DcbxState::new(LOCAL, config(true));
+        let mut peer = config(false);
+        peer.pfc.enabled = 0x18;
+        let now = Instant::now();
+        state.receive(HIGHER, &lldpdu(120, &peer), now);
+
+        assert!(state.expire(now + Duration::from_secs(119)).is_empty());
+        assert!(state.peer().is_some());
+
+        // Every LLDPDU of the peer restarts its TTL
+        let now = now + Duration::from_secs(100);
+        state.receive(HIGHER, &lldpdu(120, &peer), now);
+        assert!(state.expire(now + Duration::from_secs(119)).is_empty());
+
+        let events = state.expire(now + Duration::from_secs(120));
+        assert_eq!(
+            events,
+            vec![
+                DcbxEvent::PeerLost,
+                DcbxEvent::OperationalChanged(config(true).operational()),
+            ]
+        );
+        assert!(state.peer().is_none());
+        assert!(state.expire(now + Duration::from_secs(122)).is_empty());
+
+        // Another station becomes the peer
+        state.receive(LOWER, &lldpdu(120, &peer), now);
+        assert_eq!(state.peer().unwrap().mac_address, LOWER);
+    }
+}
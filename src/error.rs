@@ -0,0 +1,132 @@
+use crate::tlv::TlvType;
+use std::fmt::Display;
+
+/// LLDP Error
+///
+/// Describes why a sequence of bytes could not be decoded into a TLV or LLDPDU, or why a TLV could not be added to an
+/// LLDPDU.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LldpError {
+    /// The input ended before the TLV was complete.
+    Truncated { needed: usize, available: usize },
+    /// The TLV type field does not match the TLV being decoded.
+    WrongType { expected: TlvType, found: u8 },
+    /// The length field (or an embedded length) is not valid for the TLV.
+    BadLength { tlv_type: TlvType, length: usize },
+    /// The subtype field holds a value that is not defined for the TLV.
+    UnknownSubtype { tlv_type: TlvType, subtype: u8 },
+    /// The ID of a Chassis ID or Port ID TLV is of a different kind than its subtype requires.
+    SubtypeMismatch { tlv_type: TlvType, subtype: u8 },
+    /// A network address uses an address family that is not supported.
+    UnknownAddressFamily(u8),
+    /// A string value is not valid UTF-8.
+    InvalidUtf8(TlvType),
+    /// Capabilities are enabled that are not supported.
+    CapabilityMismatch { supported: u16, enabled: u16 },
+    /// The Station Only capability is combined with other capabilities.
+    StationOnlyConflict(u16),
+    /// A TLV appears at a position of the LLDPDU where it is not allowed.
+    OrderingViolation { position: usize, tlv_type: TlvType },
+    /// A TLV follows the End Of LLDPDU TLV.
+    TlvAfterEnd,
+    /// Non-padding data of the given length follows the End Of LLDPDU TLV.
+    DataAfterEnd(usize),
+    /// A mandatory TLV is missing.
+    MissingTlv(TlvType),
+    /// A TLV that may appear only once appears multiple times.
+    DuplicateTlv(TlvType),
+    /// An optional TLV appears in a shutdown LLDPDU, i.e. an LLDPDU with a TTL of 0.
+    TlvInShutdown(TlvType),
+    /// The LLDPDU exceeds the maximum size of an LLDPDU.
+    TooLarge { size: usize, max: usize },
+    /// The printable representation of a TLV or LLDPDU is invalid at the given byte offset.
+    InvalidSyntax(usize),
+    /// An object identifier is not valid.
+    InvalidOid,
+    /// A MAC address or EUI-64 is not valid.
+    InvalidMacAddress,
+    /// An OUI does not have a length of 3 bytes.
+    InvalidOui(usize),
+    /// An Organizationally Specific TLV has an OUI or subtype that is not known or not expected.
+    UnknownOrgSubtype { oui: Vec<u8>, subtype: u8 },
+    /// A capability name is not known.
+    UnknownCapability(String),
+}
+
+impl Display for LldpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LldpError::Truncated { needed, available } => write!(
+                f,
+                "truncated TLV: needed {} bytes, {} available",
+                needed, available
+            ),
+            LldpError::WrongType { expected, found } => {
+                write!(
+                    f,
+                    "wrong TLV type: expected {:?}, found {}",
+                    expected, found
+                )
+            }
+            LldpError::BadLength { tlv_type, length } => {
+                write!(f, "invalid length {} for {:?} TLV", length, tlv_type)
+            }
+            LldpError::UnknownSubtype { tlv_type, subtype } => {
+                write!(f, "unknown subtype {} for {:?} TLV", subtype, tlv_type)
+            }
+            LldpError::SubtypeMismatch { tlv_type, subtype } => {
+                write!(
+                    f,
+                    "ID does not match subtype {} of {:?} TLV",
+                    subtype, tlv_type
+                )
+            }
+            LldpError::UnknownAddressFamily(family) => {
+                write!(f, "unsupported address family {}", family)
+            }
+            LldpError::InvalidUtf8(tlv_type) => {
+                write!(f, "invalid UTF-8 string in {:?} TLV", tlv_type)
+            }
+            LldpError::CapabilityMismatch { supported, enabled } => write!(
+                f,
+                "enabled capabilities {:#06x} are not supported ({:#06x})",
+                enabled, supported
+            ),
+            LldpError::StationOnlyConflict(capabilities) => write!(
+                f,
+                "Station Only capability combined with other capabilities ({:#06x})",
+                capabilities
+            ),
+            LldpError::OrderingViolation { position, tlv_type } => {
+                write!(f, "{:?} TLV not allowed at position {}", tlv_type, position)
+            }
+            LldpError::TlvAfterEnd => write!(f, "TLV after End Of LLDPDU TLV"),
+            LldpError::DataAfterEnd(length) => {
+                write!(f, "{} bytes of data after End Of LLDPDU TLV", length)
+            }
+            LldpError::MissingTlv(tlv_type) => write!(f, "mandatory {:?} TLV is missing", tlv_type),
+            LldpError::DuplicateTlv(tlv_type) => {
+                write!(f, "{:?} TLV appears more than once", tlv_type)
+            }
+            LldpError::TlvInShutdown(tlv_type) => {
+                write!(f, "{:?} TLV not allowed in shutdown LLDPDU", tlv_type)
+            }
+            LldpError::TooLarge { size, max } => {
+                write!(f, "LLDPDU size {} exceeds maximum of {} bytes", size, max)
+            }
+            LldpError::InvalidSyntax(position) => {
+                write!(f, "invalid syntax at offset {}", position)
+            }
+            LldpError::InvalidOid => write!(f, "invalid object identifier"),
+            LldpError::InvalidMacAddress => write!(f, "invalid MAC address"),
+            LldpError::InvalidOui(length) => write!(f, "invalid OUI length {}", length),
+            LldpError::UnknownOrgSubtype { oui, subtype } => {
+                let oui: Vec<String> = oui.iter().map(|b| format!("{:02X}", b)).collect();
+                write!(f, "unknown subtype {} for OUI {}", subtype, oui.join("-"))
+            }
+            LldpError::UnknownCapability(name) => write!(f, "unknown capability \"{}\"", name),
+        }
+    }
+}
+
+impl std::error::Error for LldpError {}
@@ -0,0 +1,6 @@
+pub mod agent;
+pub mod error;
+pub mod lldpdu;
+#[cfg(feature = "serde")]
+mod serialization;
+pub mod tlv;
@@ -1,12 +1,48 @@
-use crate::tlv::{
-    chassisid_tlv::ChassisIdTLV, eolldpdu_tlv::EndOfLLDPDUTLV,
-    managementaddress_tlv::ManagementAddressTLV,
-    organizationallyspecific_tlv::OrganizationallySpecificTLV,
-    portdescription_tlv::PortDescriptionTLV, portid_tlv::PortIdTLV,
-    systemcapabilities_tlv::SystemCapabilitiesTLV, systemdescription_tlv::SystemDescriptionTLV,
-    systemname_tlv::SystemNameTLV, ttl_tlv::TtlTLV, Tlv, TlvType,
-};
-use std::{convert::TryFrom, fmt::Display};
+pub mod builder;
+pub mod lldpdu_ref;
+
+use crate::error::LldpError;
+use crate::lldpdu::builder::LldpduBuilder;
+use crate::tlv::chassisid_tlv::ChassisIdTLV;
+use crate::tlv::managementaddress_tlv::ManagementAddressTLV;
+use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+use crate::tlv::parser::Parser;
+use crate::tlv::portid_tlv::PortIdTLV;
+use crate::tlv::systemcapabilities_tlv::{SystemCapabilitiesTLV, SystemCapability};
+use crate::tlv::{self, Tlv, TlvCodec, TlvHeader, TlvType, MAX_STRING_LEN, TLV_MAX_VALUE_LEN};
+use bytes::BufMut;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Maximum size of an LLDPDU in bytes, i.e. the payload of a single Ethernet frame.
+const MAX_SIZE: usize = 1500;
+
+/// TLVs that may appear at most once in an LLDPDU.
+const SINGLETON_TLVS: [TlvType; 8] = [
+    TlvType::ChassisId,
+    TlvType::PortId,
+    TlvType::Ttl,
+    TlvType::PortDescription,
+    TlvType::SystemName,
+    TlvType::SystemDescription,
+    TlvType::SystemCapabilities,
+    TlvType::EndOfLLDPDU,
+];
+
+/// Parse policy for received LLDPDUs
+///
+/// Determines how [Lldpdu::parse] treats LLDPDUs that do not conform to IEEE 802.1AB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParsePolicy {
+    /// Discard any LLDPDU that is malformed or has a violation reported by [Lldpdu::validate].
+    #[default]
+    Strict,
+    /// Keep the mandatory TLVs and drop only malformed or offending optional TLVs. Every problem is recorded in
+    /// [Lldpdu::warnings].
+    ///
+    /// LLDPDUs whose mandatory TLVs are missing or malformed are still discarded.
+    Lenient,
+}
 
 /// LLDP Data Unit
 ///
@@ -19,16 +55,22 @@ use std::{convert::TryFrom, fmt::Display};
 ///
 /// LLDPDU Format:
 ///
+/// ```text
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+
 ///     |                 |                 |                 |                                 |
 ///     | Chassis ID TLV  |   Port ID TLV   |     TTL TLV     |         (Optional TLVs)         |
 ///     |                 |                 |                 |                                 |
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+
+/// ```
 #[derive(Debug, Clone)]
 pub struct Lldpdu {
     has_end: bool,
     tlvs: Vec<Tlv>,
     size: usize,
+    /// Number of bytes following the End Of LLDPDU TLV of a received LLDPDU, unless they are all zero padding
+    data_after_end: usize,
+    /// Problems found while parsing the LLDPDU leniently
+    warnings: Vec<LldpError>,
 }
 
 impl Display for Lldpdu {
@@ -39,74 +81,179 @@ impl Display for Lldpdu {
         for (index, tlv) in self.tlvs.iter().enumerate() {
             result.push_str(&format!("{}", tlv));
             if index != self.tlvs.len() - 1 {
-                result.push_str(&", ")
+                result.push_str(", ")
             }
         }
-        result.push_str(&")");
+        result.push(')');
 
         write!(f, "{}", result)
     }
 }
 
+impl FromStr for Lldpdu {
+    type Err = LldpError;
+
+    /// Parse the printable representation of an LLDPDU, e.g. `LLDPDU(ChassisIdTLV(7, "chair"), ...)`.
+    ///
+    /// Returns an error if the string is not a valid representation, a TLV contains errors or the TLVs could not be
+    /// appended in this order, see [Lldpdu::try_append].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let mut lldpdu = Lldpdu::new(vec![]);
+
+        parser.expect("LLDPDU(")?;
+        if !parser.accept(")") {
+            loop {
+                lldpdu.try_append(parser.tlv()?)?;
+                if parser.accept(")") {
+                    break;
+                }
+                parser.expect(",")?;
+            }
+        }
+        parser.end()?;
+
+        Ok(lldpdu)
+    }
+}
+
 impl Lldpdu {
     /// Create an LLDPDU instance from raw bytes.
     ///
-    /// Panics if a parsed TLV is of unknown type.
-    /// Further validity checks are left to the subclass.
+    /// Panics if a parsed TLV is of unknown type or contains errors.
+    /// Use [Lldpdu::try_from_bytes] to handle malformed input.
     pub fn from_bytes(data: &[u8]) -> Self {
-        let mut lldpdu = Lldpdu {
-            tlvs: vec![],
-            has_end: false,
-            size: 0,
-        };
+        Lldpdu::try_from_bytes(data).unwrap_or_else(|e| panic!("{}", e))
+    }
 
+    /// Create an LLDPDU instance from raw bytes.
+    ///
+    /// Returns an error if a TLV is truncated, of unknown type, contains errors or appears at a position where it
+    /// is not allowed.
+    ///
+    /// Parsing stops at the End Of LLDPDU TLV. Any data following it is ignored, but reported by
+    /// [Lldpdu::validate] unless it is zero padding.
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, LldpError> {
+        let mut lldpdu = Lldpdu::new(vec![]);
         let mut index = 0;
 
         while index < data.len() {
-            let mut type_field = data[index] & 0b11111110;
-            type_field = type_field >> 1;
+            let bytes = next_tlv(&data[index..])?;
+            lldpdu.try_append(Tlv::try_from_bytes(bytes)?)?;
 
-            let type_field = match TlvType::try_from(type_field) {
-                Ok(value) => value,
-                Err(_) => panic!("Tlv Type invalid"),
-            };
+            index += bytes.len();
 
-            let mut length = data[index + 1] as usize;
-            if data[index] & 1 == 1 {
-                length += 1 << 9;
+            if lldpdu.has_end {
+                lldpdu.set_trailing(&data[index..]);
+                break;
             }
+        }
 
-            let bytes = &data[index..index + 2 + length];
+        Ok(lldpdu)
+    }
 
-            let tlv = match type_field {
-                TlvType::ChassisId => Tlv::ChassisId(ChassisIdTLV::new_from_bytes(bytes)),
-                TlvType::EndOfLLDPDU => Tlv::EndOfLldpdu(EndOfLLDPDUTLV::new_from_bytes(bytes)),
-                TlvType::PortId => Tlv::PortId(PortIdTLV::new_from_bytes(bytes)),
-                TlvType::Ttl => Tlv::Ttl(TtlTLV::new_from_bytes(bytes)),
-                TlvType::PortDescription => {
-                    Tlv::PortDescription(PortDescriptionTLV::new_from_bytes(bytes))
-                }
-                TlvType::SystemName => Tlv::SystemName(SystemNameTLV::new_from_bytes(bytes)),
-                TlvType::SystemDescription => {
-                    Tlv::SystemDescription(SystemDescriptionTLV::new_from_bytes(bytes))
-                }
-                TlvType::SystemCapabilities => {
-                    Tlv::SystemCapabilities(SystemCapabilitiesTLV::new_from_bytes(bytes))
+    /// Create an LLDPDU instance from raw bytes received from the network, following `policy`.
+    ///
+    /// In [ParsePolicy::Strict] mode an error is returned if the LLDPDU cannot be parsed or [Lldpdu::validate] reports
+    /// a violation. The End Of LLDPDU TLV is optional since IEEE 802.1AB-2009.
+    ///
+    /// In [ParsePolicy::Lenient] mode an error is only returned if one of the mandatory TLVs is missing or cannot be
+    /// parsed. Malformed and offending optional TLVs are dropped. Everything that would have made strict parsing fail
+    /// is recorded in [Lldpdu::warnings].
+    pub fn parse(data: &[u8], policy: ParsePolicy) -> Result<Self, LldpError> {
+        match policy {
+            ParsePolicy::Strict => {
+                let mut lldpdu = Lldpdu::try_from_bytes(data)?;
+
+                if let Some(violation) = lldpdu.validate().into_iter().next() {
+                    return Err(violation);
                 }
-                TlvType::ManagementAddress => {
-                    Tlv::ManagementAddress(ManagementAddressTLV::new_from_bytes(bytes))
+                lldpdu.warnings = lldpdu.validation_warnings();
+
+                Ok(lldpdu)
+            }
+            ParsePolicy::Lenient => Lldpdu::parse_lenient(data),
+        }
+    }
+
+    /// Implementation of [ParsePolicy::Lenient], see [Lldpdu::parse]
+    fn parse_lenient(data: &[u8]) -> Result<Self, LldpError> {
+        let mut lldpdu = Lldpdu::new(vec![]);
+        let mut warnings = vec![];
+        let mut index = 0;
+
+        while index < data.len() {
+            let mandatory = lldpdu.len() < 3;
+
+            let bytes = match next_tlv(&data[index..]) {
+                Ok(bytes) => bytes,
+                Err(e) if mandatory => return Err(e),
+                Err(e) => {
+                    // Without a valid length the remaining TLVs cannot be located
+                    warnings.push(e);
+                    break;
                 }
-                TlvType::OrganizationallySpecific => Tlv::OrganizationallySpecific(
-                    OrganizationallySpecificTLV::new_from_bytes(bytes),
-                ),
             };
+            index += bytes.len();
 
-            lldpdu.append(tlv);
+            let result = Tlv::try_from_bytes(bytes).and_then(|tlv| {
+                if !mandatory {
+                    lldpdu.check_optional(&tlv)?;
+                }
+                lldpdu.try_append(tlv)
+            });
+
+            match result {
+                Ok(()) => (),
+                Err(e) if mandatory => return Err(e),
+                Err(e) => warnings.push(e),
+            }
+
+            if lldpdu.has_end {
+                lldpdu.set_trailing(&data[index..]);
+                break;
+            }
+        }
 
-            index += 2 + length;
+        if let Some(&tlv_type) =
+            [TlvType::ChassisId, TlvType::PortId, TlvType::Ttl].get(lldpdu.len())
+        {
+            return Err(LldpError::MissingTlv(tlv_type));
         }
+        warnings.extend(lldpdu.validate());
+        warnings.extend(lldpdu.validation_warnings());
+        lldpdu.warnings = warnings;
 
-        lldpdu
+        Ok(lldpdu)
+    }
+
+    /// Check if the optional TLV `tlv` can be added without introducing a violation.
+    fn check_optional(&self, tlv: &Tlv) -> Result<(), LldpError> {
+        let tlv_type = tlv.get_type();
+
+        if self.ttl() == Some(0) && tlv_type != TlvType::EndOfLLDPDU {
+            return Err(LldpError::TlvInShutdown(tlv_type));
+        }
+
+        if SINGLETON_TLVS.contains(&tlv_type) && self.tlvs.iter().any(|t| t.get_type() == tlv_type)
+        {
+            return Err(LldpError::DuplicateTlv(tlv_type));
+        }
+
+        let mut violations = vec![];
+        validate_tlv(tlv, &mut violations);
+
+        match violations.into_iter().next() {
+            Some(violation) => Err(violation),
+            None => Ok(()),
+        }
+    }
+
+    /// Record the data following the End Of LLDPDU TLV, unless it is zero padding.
+    fn set_trailing(&mut self, trailing: &[u8]) {
+        if trailing.iter().any(|&byte| byte != 0) {
+            self.data_after_end = trailing.len();
+        }
     }
 
     /// Constructor
@@ -117,6 +264,8 @@ impl Lldpdu {
             tlvs: vec![],
             has_end: false,
             size: 0,
+            data_after_end: 0,
+            warnings: vec![],
         };
 
         for tlv in init_tlvs {
@@ -126,54 +275,82 @@ impl Lldpdu {
         lldpdu
     }
 
+    /// Create an [LldpduBuilder] to construct an LLDPDU.
+    pub fn builder() -> LldpduBuilder {
+        LldpduBuilder::new()
+    }
+
     /// Append `tlv` to the LLDPDU.
     ///
     /// This method adds the given [Tlv] to the LLDPDU.
     ///
     /// If adding the TLV makes the LLDPDU invalid (e.g. by adding a TLV after an EndOfLLDPDU TLV) it should panic.
     /// Conditions for specific TLVs are detailed in each TLV's class description.
+    /// Use [Lldpdu::try_append] to handle invalid TLVs.
     pub fn append(&mut self, tlv: Tlv) {
-        let tlv_size = tlv.bytes().len();
-
-        if self.size + tlv_size > 1500 {
-            panic!("tlv size overflow");
-        }
+        self.try_append(tlv).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        if self.has_end {
-            panic!("Cannot add a tlv after endoflldpdu_tlv");
+    /// Append `tlv` to the LLDPDU.
+    ///
+    /// Returns an error and leaves the LLDPDU unchanged if adding the TLV would make the LLDPDU invalid.
+    pub fn try_append(&mut self, tlv: Tlv) -> Result<(), LldpError> {
+        match &tlv {
+            Tlv::Unknown { tlv_type, value } => tlv::check_unknown(*tlv_type, value)?,
+            // The lengths of the address and the OID are encoded in one byte each
+            Tlv::ManagementAddress(tlv) => tlv.check_lengths()?,
+            _ => (),
         }
 
-        let type_field = tlv.get_type();
+        let tlv_size = tlv.encoded_len();
 
-        if self.len() == 0 && type_field != TlvType::ChassisId {
-            panic!("first tlv should be a chassisid_tlv");
+        // The value would not fit into the length field of the header
+        if tlv_size - TlvHeader::LEN > TLV_MAX_VALUE_LEN {
+            return Err(LldpError::BadLength {
+                tlv_type: tlv.get_type(),
+                length: tlv_size - TlvHeader::LEN,
+            });
         }
 
-        if self.len() == 1 && type_field != TlvType::PortId {
-            panic!("second tlv should be a portid_tlv");
+        if self.size + tlv_size > MAX_SIZE {
+            return Err(LldpError::TooLarge {
+                size: self.size + tlv_size,
+                max: MAX_SIZE,
+            });
         }
 
-        if self.len() == 2 && type_field != TlvType::Ttl {
-            panic!("third tlv should be a ttl_tlv");
+        if self.has_end {
+            return Err(LldpError::TlvAfterEnd);
         }
 
-        if self.len() >= 3
-            && (type_field == TlvType::ChassisId
-                || type_field == TlvType::PortId
-                || type_field == TlvType::Ttl)
-        {
-            panic!("trying to add duplicate mandatory fields");
+        let type_field = tlv.get_type();
+        let position = self.len();
+
+        let allowed = match position {
+            0 => type_field == TlvType::ChassisId,
+            1 => type_field == TlvType::PortId,
+            2 => type_field == TlvType::Ttl,
+            _ => !matches!(
+                type_field,
+                TlvType::ChassisId | TlvType::PortId | TlvType::Ttl
+            ),
+        };
+
+        if !allowed {
+            return Err(LldpError::OrderingViolation {
+                position,
+                tlv_type: type_field,
+            });
         }
 
         if type_field == TlvType::EndOfLLDPDU {
-            if self.len() < 3 {
-                panic!("There should atleast be three mandatory tlvs");
-            }
             self.has_end = true;
         }
 
         self.tlvs.push(tlv);
         self.size += tlv_size;
+
+        Ok(())
     }
 
     /// Determine if the LLDPDU is complete
@@ -183,9 +360,115 @@ impl Lldpdu {
         self.has_end
     }
 
+    /// Get the problems found while parsing the LLDPDU with [Lldpdu::parse].
+    ///
+    /// With [ParsePolicy::Lenient] this includes the optional TLVs that have been dropped. Both policies record the
+    /// [Lldpdu::validation_warnings]. LLDPDUs created in any other way have no warnings.
+    pub fn warnings(&self) -> &[LldpError] {
+        &self.warnings
+    }
+
     /// Determine if the LLDPDU is valid
+    ///
+    /// An LLDPDU is valid if [Lldpdu::validate] does not find any violations.
     pub fn is_valid(&self) -> bool {
-        true
+        self.validate().is_empty()
+    }
+
+    /// Check the LLDPDU against the rules of IEEE 802.1AB.
+    ///
+    /// Returns every violation found, in the order of the TLVs they were found in:
+    /// * mandatory TLVs (Chassis ID, Port ID, TTL) that are missing or not at their required position
+    /// * TLVs that may only appear once but appear multiple times (e.g. System Name)
+    /// * unknown TLVs that are not of a reserved type
+    /// * ID and string fields exceeding 255 bytes, empty IDs and values too large for the TLV length field
+    /// * enabled system capabilities that are not supported
+    /// * optional TLVs in a shutdown LLDPDU (TTL of 0)
+    /// * data following the End Of LLDPDU TLV
+    /// * LLDPDUs exceeding the maximum size
+    pub fn validate(&self) -> Vec<LldpError> {
+        let mut violations = vec![];
+
+        for (position, &tlv_type) in [TlvType::ChassisId, TlvType::PortId, TlvType::Ttl]
+            .iter()
+            .enumerate()
+        {
+            match self.tlvs.iter().position(|tlv| tlv.get_type() == tlv_type) {
+                None => violations.push(LldpError::MissingTlv(tlv_type)),
+                Some(index) if index != position => violations.push(LldpError::OrderingViolation {
+                    position: index,
+                    tlv_type,
+                }),
+                Some(_) => (),
+            }
+        }
+
+        let shutdown = self
+            .tlvs
+            .iter()
+            .any(|tlv| matches!(tlv, Tlv::Ttl(ttl) if ttl.value == 0));
+        let mut seen: Vec<TlvType> = vec![];
+
+        for (position, tlv) in self.tlvs.iter().enumerate() {
+            let tlv_type = tlv.get_type();
+
+            if SINGLETON_TLVS.contains(&tlv_type) {
+                if seen.contains(&tlv_type) {
+                    violations.push(LldpError::DuplicateTlv(tlv_type));
+                } else {
+                    seen.push(tlv_type);
+                }
+            }
+
+            if position > 0 && self.tlvs[position - 1].get_type() == TlvType::EndOfLLDPDU {
+                violations.push(LldpError::TlvAfterEnd);
+            }
+
+            if shutdown
+                && !matches!(
+                    tlv_type,
+                    TlvType::ChassisId | TlvType::PortId | TlvType::Ttl | TlvType::EndOfLLDPDU
+                )
+            {
+                violations.push(LldpError::TlvInShutdown(tlv_type));
+            }
+
+            validate_tlv(tlv, &mut violations);
+        }
+
+        if self.data_after_end > 0 {
+            violations.push(LldpError::DataAfterEnd(self.data_after_end));
+        }
+
+        if self.size > MAX_SIZE {
+            violations.push(LldpError::TooLarge {
+                size: self.size,
+                max: MAX_SIZE,
+            });
+        }
+
+        violations
+    }
+
+    /// Check the LLDPDU for conditions that IEEE 802.1AB discourages, but that do not make it invalid:
+    /// * Station Only combined with other capabilities
+    ///
+    /// Unlike the violations reported by [Lldpdu::validate] they make neither [Lldpdu::parse] nor
+    /// [LldpduBuilder::build] fail. [Lldpdu::parse] records them in [Lldpdu::warnings].
+    pub fn validation_warnings(&self) -> Vec<LldpError> {
+        let mut warnings = vec![];
+
+        if let Some(tlv) = self.system_capabilities() {
+            // Station Only should not be set in conjunction with any other capability
+            let supported = tlv.supported();
+            if supported.contains(SystemCapability::StationOnly)
+                && supported != SystemCapability::StationOnly.into()
+            {
+                warnings.push(LldpError::StationOnlyConflict(supported.bits()));
+            }
+        }
+
+        warnings
     }
 
     /// Get the number of TLVs in the LLDPDU
@@ -198,13 +481,22 @@ impl Lldpdu {
         self.len() == 0
     }
 
-    /// Get the byte representation of the LLDPDU
-    pub fn bytes(&self) -> Vec<u8> {
-        let mut result: Vec<u8> = Vec::new();
+    /// Get the size of the encoded LLDPDU in bytes
+    pub fn encoded_len(&self) -> usize {
+        self.size
+    }
 
+    /// Write the byte representation of the LLDPDU to `buf`.
+    pub fn encode(&self, buf: &mut impl BufMut) {
         for tlv in &self.tlvs {
-            result.extend_from_slice(&tlv.bytes());
+            tlv.encode(buf);
         }
+    }
+
+    /// Get the byte representation of the LLDPDU
+    pub fn bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.encoded_len());
+        self.encode(&mut result);
 
         result
     }
@@ -216,6 +508,194 @@ impl Lldpdu {
             None => panic!("index out of bound"),
         }
     }
+
+    /// Iterate over the TLVs of the LLDPDU
+    pub fn tlvs(&self) -> std::slice::Iter<'_, Tlv> {
+        self.tlvs.iter()
+    }
+
+    /// Get the Chassis ID TLV
+    pub fn chassis_id(&self) -> Option<&ChassisIdTLV> {
+        self.tlvs().find_map(|tlv| match tlv {
+            Tlv::ChassisId(tlv) => Some(tlv),
+            _ => None,
+        })
+    }
+
+    /// Get the Port ID TLV
+    pub fn port_id(&self) -> Option<&PortIdTLV> {
+        self.tlvs().find_map(|tlv| match tlv {
+            Tlv::PortId(tlv) => Some(tlv),
+            _ => None,
+        })
+    }
+
+    /// Get the TTL in seconds
+    pub fn ttl(&self) -> Option<u16> {
+        self.tlvs().find_map(|tlv| match tlv {
+            Tlv::Ttl(tlv) => Some(tlv.value),
+            _ => None,
+        })
+    }
+
+    /// Get the port description
+    pub fn port_description(&self) -> Option<&str> {
+        self.tlvs().find_map(|tlv| match tlv {
+            Tlv::PortDescription(tlv) => Some(tlv.value.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Get the system name
+    pub fn system_name(&self) -> Option<&str> {
+        self.tlvs().find_map(|tlv| match tlv {
+            Tlv::SystemName(tlv) => Some(tlv.value.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Get the system description
+    pub fn system_description(&self) -> Option<&str> {
+        self.tlvs().find_map(|tlv| match tlv {
+            Tlv::SystemDescription(tlv) => Some(tlv.value.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Get the System Capabilities TLV
+    pub fn system_capabilities(&self) -> Option<&SystemCapabilitiesTLV> {
+        self.tlvs().find_map(|tlv| match tlv {
+            Tlv::SystemCapabilities(tlv) => Some(tlv),
+            _ => None,
+        })
+    }
+
+    /// Iterate over the Management Address TLVs
+    pub fn management_addresses(&self) -> impl Iterator<Item = &ManagementAddressTLV> {
+        self.tlvs().filter_map(|tlv| match tlv {
+            Tlv::ManagementAddress(tlv) => Some(tlv),
+            _ => None,
+        })
+    }
+
+    /// Iterate over the Organizationally Specific TLVs with the given OUI and subtype
+    pub fn org_specific(
+        &self,
+        oui: [u8; 3],
+        subtype: u8,
+    ) -> impl Iterator<Item = &OrganizationallySpecificTLV> {
+        self.tlvs().filter_map(move |tlv| match tlv {
+            Tlv::OrganizationallySpecific(tlv) if tlv.oui == oui && tlv.subtype == subtype => {
+                Some(tlv)
+            }
+            _ => None,
+        })
+    }
+}
+
+impl std::ops::Index<usize> for Lldpdu {
+    type Output = Tlv;
+
+    /// Get the TLV at position `index`
+    ///
+    /// Panics if `index` is out of bounds.
+    fn index(&self, index: usize) -> &Tlv {
+        &self.tlvs[index]
+    }
+}
+
+impl IntoIterator for Lldpdu {
+    type Item = Tlv;
+    type IntoIter = std::vec::IntoIter<Tlv>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tlvs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Lldpdu {
+    type Item = &'a Tlv;
+    type IntoIter = std::slice::Iter<'a, Tlv>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tlvs()
+    }
+}
+
+/// Split off the packed TLV at the start of `data`.
+fn next_tlv(data: &[u8]) -> Result<&[u8], LldpError> {
+    let size = TlvHeader::parse(data)?.size();
+    if data.len() < size {
+        return Err(LldpError::Truncated {
+            needed: size,
+            available: data.len(),
+        });
+    }
+
+    Ok(&data[..size])
+}
+
+/// Check the fields of a single TLV, see [Lldpdu::validate].
+fn validate_tlv(tlv: &Tlv, violations: &mut Vec<LldpError>) {
+    if let Tlv::Unknown { tlv_type, value } = tlv {
+        if let Err(violation) = tlv::check_unknown(*tlv_type, value) {
+            violations.push(violation);
+            return;
+        }
+    }
+
+    let tlv_type = tlv.get_type();
+    let value_length = tlv.encoded_len() - TlvHeader::LEN;
+
+    if value_length > TLV_MAX_VALUE_LEN {
+        violations.push(LldpError::BadLength {
+            tlv_type,
+            length: value_length,
+        });
+        return;
+    }
+
+    let string_length = match tlv {
+        // The ID follows the one byte subtype
+        Tlv::ChassisId(tlv) => Some(tlv.len() - 1),
+        Tlv::PortId(tlv) => Some(tlv.len() - 1),
+        Tlv::PortDescription(tlv) => Some(tlv.len()),
+        Tlv::SystemName(tlv) => Some(tlv.len()),
+        Tlv::SystemDescription(tlv) => Some(tlv.len()),
+        _ => None,
+    };
+
+    match string_length {
+        Some(0) if matches!(tlv_type, TlvType::ChassisId | TlvType::PortId) => {
+            violations.push(LldpError::BadLength {
+                tlv_type,
+                length: value_length,
+            })
+        }
+        Some(length) if length > MAX_STRING_LEN => violations.push(LldpError::BadLength {
+            tlv_type,
+            length: value_length,
+        }),
+        _ => (),
+    }
+
+    if let Tlv::SystemCapabilities(tlv) = tlv {
+        let supported = tlv.supported();
+        let enabled = tlv.enabled();
+
+        if !supported.contains(enabled) {
+            violations.push(LldpError::CapabilityMismatch {
+                supported: supported.bits(),
+                enabled: enabled.bits(),
+            });
+        }
+    }
+
+    if let Tlv::ManagementAddress(tlv) = tlv {
+        if let Err(violation) = tlv.check_lengths() {
+            violations.push(violation);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -223,12 +703,15 @@ mod tests {
     use super::*;
     use crate::tlv::chassisid_tlv::{ChassisIdSubType, ChassisIdTLV, ChassisIdValue};
     use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
+    use crate::tlv::mac_address::MacAddress;
     use crate::tlv::managementaddress_tlv::{IFNumberingSubtype, ManagementAddressTLV};
     use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
     use crate::tlv::portid_tlv::{PortIdSubtype, PortIdTLV, PortIdValue};
+    use crate::tlv::systemcapabilities_tlv::SystemCapabilitiesTLV;
     use crate::tlv::systemdescription_tlv::SystemDescriptionTLV;
     use crate::tlv::systemname_tlv::SystemNameTLV;
     use crate::tlv::ttl_tlv::TtlTLV;
+    use std::net::IpAddr;
     use std::panic;
     use std::panic::AssertUnwindSafe;
 
@@ -324,13 +807,13 @@ mod tests {
         )));
         lldpdu.append(Tlv::Ttl(TtlTLV::new(120)));
         lldpdu.append(Tlv::ManagementAddress(ManagementAddressTLV::new(
-            "192.2.0.1".parse().unwrap(),
+            "192.2.0.1".parse::<IpAddr>().unwrap(),
             1,
             IFNumberingSubtype::Unknown,
             vec![],
         )));
         lldpdu.append(Tlv::ManagementAddress(ManagementAddressTLV::new(
-            "2001:db::c0a8:1".parse().unwrap(),
+            "2001:db::c0a8:1".parse::<IpAddr>().unwrap(),
             1,
             IFNumberingSubtype::Unknown,
             vec![],
@@ -487,6 +970,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode() {
+        let bytes =
+            b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x08\x0bEngineering\x00\x00";
+        let lldpdu = Lldpdu::from_bytes(bytes);
+        assert_eq!(lldpdu.encoded_len(), bytes.len());
+
+        // Encoding appends to the buffer
+        let mut buf = vec![0xAA, 0xBB];
+        lldpdu.encode(&mut buf);
+        assert_eq!(&buf[..2], b"\xAA\xBB");
+        assert_eq!(&buf[2..], bytes.as_ref());
+    }
+
     #[test]
     fn test_load() {
         let lldpdu = Lldpdu::from_bytes(
@@ -496,6 +993,542 @@ mod tests {
         assert_eq!(lldpdu.len(), 5);
     }
 
+    #[test]
+    fn test_try_load_invalid() {
+        // TLV value cut off
+        assert_eq!(
+            Lldpdu::try_from_bytes(b"\x02\x08\x07Voy").unwrap_err(),
+            LldpError::Truncated {
+                needed: 10,
+                available: 6
+            }
+        );
+        // Port ID before Chassis ID
+        assert_eq!(
+            Lldpdu::try_from_bytes(b"\x04\x06\x0710743\x02\x08\x07Voyager").unwrap_err(),
+            LldpError::OrderingViolation {
+                position: 0,
+                tlv_type: TlvType::PortId
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_data_after_end() {
+        // Zero padding is ignored
+        let lldpdu = Lldpdu::from_bytes(
+            b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x00\x00\x00\x00\x00\x00",
+        );
+        assert_eq!(lldpdu.len(), 4);
+        assert!(lldpdu.is_valid());
+
+        // Anything else is reported by validate()
+        let lldpdu = Lldpdu::from_bytes(
+            b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x00\x00\x08\x02ok",
+        );
+        assert_eq!(lldpdu.len(), 4);
+        assert_eq!(lldpdu.validate(), vec![LldpError::DataAfterEnd(4)]);
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let valid = b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x00\x00";
+        assert_eq!(
+            Lldpdu::parse(valid, ParsePolicy::Strict).unwrap().bytes(),
+            valid
+        );
+
+        // The End Of LLDPDU TLV is optional
+        let lldpdu = Lldpdu::parse(&valid[..valid.len() - 2], ParsePolicy::Strict).unwrap();
+        assert_eq!(lldpdu.len(), 3);
+        assert!(!lldpdu.complete());
+        // Trailing junk
+        assert_eq!(
+            Lldpdu::parse(
+                b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x00\x00junk",
+                ParsePolicy::Strict
+            )
+            .unwrap_err(),
+            LldpError::DataAfterEnd(4)
+        );
+        // Duplicate System Name
+        assert_eq!(
+            Lldpdu::parse(
+                b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x0A\x01a\x0A\x01b\x00\x00",
+                ParsePolicy::Strict
+            )
+            .unwrap_err(),
+            LldpError::DuplicateTlv(TlvType::SystemName)
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        // Duplicate System Name, capabilities enabled but not supported, no End Of LLDPDU TLV and a truncated TLV
+        let lldpdu = Lldpdu::parse(
+            b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x0A\x01a\x0A\x01b\x0e\x04\x00\x04\x00\x14\x08\x0bEngin",
+            ParsePolicy::Lenient,
+        )
+        .unwrap();
+
+        assert_eq!(
+            format!("{}", lldpdu),
+            "LLDPDU(ChassisIdTLV(7, \"Voyager\"), PortIdTLV(7, \"10743\"), TtlTLV(255), SystemNameTLV(\"a\"))"
+        );
+        assert_eq!(
+            lldpdu.warnings(),
+            [
+                LldpError::DuplicateTlv(TlvType::SystemName),
+                LldpError::CapabilityMismatch {
+                    supported: 0x0004,
+                    enabled: 0x0014
+                },
+                LldpError::Truncated {
+                    needed: 13,
+                    available: 7
+                },
+            ]
+        );
+        // Dropped TLVs are not part of the LLDPDU
+        assert!(lldpdu.is_valid());
+    }
+
+    #[test]
+    fn test_parse_lenient_shutdown() {
+        let bytes = b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\x00\x0a\x07HAL9000\x00\x00";
+
+        assert_eq!(
+            Lldpdu::parse(bytes, ParsePolicy::Strict).unwrap_err(),
+            LldpError::TlvInShutdown(TlvType::SystemName)
+        );
+
+        let lldpdu = Lldpdu::parse(bytes, ParsePolicy::Lenient).unwrap();
+        assert_eq!(
+            lldpdu.warnings(),
+            &[LldpError::TlvInShutdown(TlvType::SystemName)]
+        );
+        // The System Name TLV is dropped, the End Of LLDPDU TLV is kept
+        assert_eq!(lldpdu.len(), 4);
+        assert_eq!(lldpdu.system_name(), None);
+        assert!(lldpdu.is_valid());
+    }
+
+    #[test]
+    fn test_parse_lenient_mandatory() {
+        // Malformed TTL TLV
+        assert_eq!(
+            Lldpdu::parse(
+                b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x01\x00\x00\x00",
+                ParsePolicy::Lenient
+            )
+            .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::Ttl,
+                length: 1
+            }
+        );
+        // Missing TTL TLV
+        assert_eq!(
+            Lldpdu::parse(
+                b"\x02\x08\x07Voyager\x04\x06\x0710743",
+                ParsePolicy::Lenient
+            )
+            .unwrap_err(),
+            LldpError::MissingTlv(TlvType::Ttl)
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut lldpdu = Lldpdu::new(vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("unittest")),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::Local,
+                PortIdValue::Other(String::new()),
+            )),
+            Tlv::Ttl(TtlTLV::new(120)),
+        ]);
+        lldpdu.append(Tlv::SystemName(SystemNameTLV::new(String::from("HAL9000"))));
+        lldpdu.append(Tlv::SystemName(SystemNameTLV::new(String::from("HAL9001"))));
+        lldpdu.append(Tlv::SystemDescription(SystemDescriptionTLV::new(
+            "x".repeat(256),
+        )));
+        lldpdu.append(Tlv::SystemCapabilities(SystemCapabilitiesTLV {
+            tlv_type: TlvType::SystemCapabilities,
+            supported: SystemCapability::Bridge.into(),
+            enabled: SystemCapability::Bridge | SystemCapability::Router,
+        }));
+
+        assert!(!lldpdu.is_valid());
+        assert_eq!(
+            lldpdu.validate(),
+            vec![
+                LldpError::BadLength {
+                    tlv_type: TlvType::PortId,
+                    length: 1
+                },
+                LldpError::DuplicateTlv(TlvType::SystemName),
+                LldpError::BadLength {
+                    tlv_type: TlvType::SystemDescription,
+                    length: 256
+                },
+                LldpError::CapabilityMismatch {
+                    supported: 0x0004,
+                    enabled: 0x0014
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_station_only() {
+        let mut lldpdu = Lldpdu::new(vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("unittest")),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::Local,
+                PortIdValue::Other(String::from("eth0")),
+            )),
+            Tlv::Ttl(TtlTLV::new(120)),
+        ]);
+        lldpdu.append(Tlv::SystemCapabilities(SystemCapabilitiesTLV::new(
+            (SystemCapability::StationOnly | SystemCapability::Telephone).bits(),
+            SystemCapability::StationOnly as u16,
+        )));
+
+        assert!(lldpdu.is_valid());
+        assert_eq!(
+            lldpdu.validation_warnings(),
+            vec![LldpError::StationOnlyConflict(0x00a0)]
+        );
+
+        // Both parse policies keep the TLV and record the warning
+        for policy in [ParsePolicy::Strict, ParsePolicy::Lenient] {
+            let lldpdu = Lldpdu::parse(&lldpdu.bytes(), policy).unwrap();
+            assert!(lldpdu.system_capabilities().is_some());
+            assert_eq!(lldpdu.warnings(), [LldpError::StationOnlyConflict(0x00a0)]);
+        }
+    }
+
+    #[test]
+    fn test_validate_mandatory() {
+        let lldpdu = Lldpdu::new(vec![Tlv::ChassisId(ChassisIdTLV::new(
+            ChassisIdSubType::Local,
+            ChassisIdValue::Other(String::from("unittest")),
+        ))]);
+        assert_eq!(
+            lldpdu.validate(),
+            vec![
+                LldpError::MissingTlv(TlvType::PortId),
+                LldpError::MissingTlv(TlvType::Ttl)
+            ]
+        );
+
+        // Not possible with append(), but e.g. with LLDPDUs from other sources
+        let lldpdu = Lldpdu {
+            has_end: false,
+            tlvs: vec![
+                Tlv::ChassisId(ChassisIdTLV::new(
+                    ChassisIdSubType::Local,
+                    ChassisIdValue::Other(String::from("unittest")),
+                )),
+                Tlv::Ttl(TtlTLV::new(120)),
+                Tlv::PortId(PortIdTLV::new(
+                    PortIdSubtype::Local,
+                    PortIdValue::Other(String::from("port(1)")),
+                )),
+                Tlv::EndOfLldpdu(EndOfLLDPDUTLV::new()),
+                Tlv::Ttl(TtlTLV::new(120)),
+            ],
+            size: 0,
+            data_after_end: 0,
+            warnings: vec![],
+        };
+        assert_eq!(
+            lldpdu.validate(),
+            vec![
+                LldpError::OrderingViolation {
+                    position: 2,
+                    tlv_type: TlvType::PortId
+                },
+                LldpError::OrderingViolation {
+                    position: 1,
+                    tlv_type: TlvType::Ttl
+                },
+                LldpError::DuplicateTlv(TlvType::Ttl),
+                LldpError::TlvAfterEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_shutdown() {
+        let mut lldpdu = Lldpdu::new(vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("unittest")),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::Local,
+                PortIdValue::Other(String::from("port(1)")),
+            )),
+            Tlv::Ttl(TtlTLV::new(0)),
+        ]);
+        assert!(lldpdu.is_valid());
+
+        lldpdu.append(Tlv::SystemName(SystemNameTLV::new(String::from("HAL9000"))));
+        assert_eq!(
+            lldpdu.validate(),
+            vec![LldpError::TlvInShutdown(TlvType::SystemName)]
+        );
+    }
+
+    #[test]
+    fn test_load_unknown_tlv() {
+        // TLV type 42 is reserved
+        let bytes = b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x54\x02\xbe\xef\x00\x00";
+        let lldpdu = Lldpdu::from_bytes(bytes);
+        assert_eq!(lldpdu.len(), 5);
+        assert_eq!(lldpdu.getitem(3).type_code(), 42);
+        assert_eq!(
+            format!("{}", lldpdu),
+            "LLDPDU(ChassisIdTLV(7, \"Voyager\"), PortIdTLV(7, \"10743\"), TtlTLV(255), UnknownTLV(42, \"BEEF\"), EndOfLLDPDUTLV)"
+        );
+        assert_eq!(lldpdu.bytes(), bytes.to_vec());
+    }
+
+    #[test]
+    fn test_try_append_keeps_lldpdu_unchanged() {
+        let mut lldpdu = Lldpdu::new(vec![Tlv::ChassisId(ChassisIdTLV::new(
+            ChassisIdSubType::Local,
+            ChassisIdValue::Other(String::from("unittest")),
+        ))]);
+        assert_eq!(
+            lldpdu.try_append(Tlv::Ttl(TtlTLV::new(120))),
+            Err(LldpError::OrderingViolation {
+                position: 1,
+                tlv_type: TlvType::Ttl
+            })
+        );
+        assert_eq!(lldpdu.len(), 1);
+    }
+
+    #[test]
+    fn test_append_oversized_tlv() {
+        let mut lldpdu = Lldpdu::builder()
+            .chassis_mac([0x02, 0x00, 0x00, 0x00, 0x00, 0x01])
+            .port_ifname("eth0")
+            .ttl(120)
+            .build()
+            .unwrap();
+        let bytes = lldpdu.bytes();
+
+        assert_eq!(
+            lldpdu.try_append(Tlv::SystemDescription(SystemDescriptionTLV::new(
+                "x".repeat(600)
+            ))),
+            Err(LldpError::BadLength {
+                tlv_type: TlvType::SystemDescription,
+                length: 600
+            })
+        );
+        assert_eq!(lldpdu.bytes(), bytes);
+
+        assert_eq!(
+            Lldpdu::builder()
+                .chassis_mac([0x02, 0x00, 0x00, 0x00, 0x00, 0x01])
+                .port_ifname("eth0")
+                .ttl(120)
+                .tlv(Tlv::SystemDescription(SystemDescriptionTLV::new(
+                    "x".repeat(600)
+                )))
+                .build()
+                .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::SystemDescription,
+                length: 600
+            }
+        );
+    }
+
+    #[test]
+    fn test_append_management_address_too_long() {
+        let mut lldpdu = Lldpdu::new(vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("unittest")),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::Local,
+                PortIdValue::Other(String::from("port(1)")),
+            )),
+            Tlv::Ttl(TtlTLV::new(120)),
+        ]);
+
+        // The OID length would wrap around in its one byte length field
+        let tlv = ManagementAddressTLV::new(
+            "192.0.2.1".parse::<IpAddr>().unwrap(),
+            1,
+            IFNumberingSubtype::IfIndex,
+            vec![0x2b; 300],
+        );
+        assert_eq!(
+            lldpdu.try_append(Tlv::ManagementAddress(tlv)),
+            Err(LldpError::BadLength {
+                tlv_type: TlvType::ManagementAddress,
+                length: 312
+            })
+        );
+        assert_eq!(lldpdu.len(), 3);
+    }
+
+    #[test]
+    fn test_append_unknown_tlv_type() {
+        let mut lldpdu = Lldpdu::new(vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("unittest")),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::Local,
+                PortIdValue::Other(String::from("port(1)")),
+            )),
+            Tlv::Ttl(TtlTLV::new(120)),
+        ]);
+
+        // A defined type disguised as unknown TLV would bypass the ordering checks
+        assert_eq!(
+            lldpdu.try_append(Tlv::Unknown {
+                tlv_type: 1,
+                value: vec![0x07, 0x41],
+            }),
+            Err(LldpError::WrongType {
+                expected: TlvType::Unknown,
+                found: 1
+            })
+        );
+        // Types above 127 do not fit the TLV header
+        assert!(lldpdu
+            .try_append(Tlv::Unknown {
+                tlv_type: 200,
+                value: vec![],
+            })
+            .is_err());
+        assert_eq!(lldpdu.len(), 3);
+
+        lldpdu.tlvs.push(Tlv::Unknown {
+            tlv_type: 2,
+            value: vec![],
+        });
+        assert_eq!(
+            lldpdu.validate(),
+            vec![LldpError::WrongType {
+                expected: TlvType::Unknown,
+                found: 2
+            }]
+        );
+
+        lldpdu.tlvs.pop();
+        lldpdu.append(Tlv::try_unknown(42, vec![0xBE, 0xEF]).unwrap());
+        assert!(lldpdu.is_valid());
+    }
+
+    #[test]
+    fn test_accessors() {
+        let lldpdu = Lldpdu::builder()
+            .chassis_mac([0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa])
+            .port_ifname("lo")
+            .ttl(120)
+            .system_name("HAL9000")
+            .capabilities(0x0014, 0x0004)
+            .management_address(
+                "192.0.2.1".parse::<IpAddr>().unwrap(),
+                1,
+                IFNumberingSubtype::IfIndex,
+                vec![],
+            )
+            .management_address(
+                "2001:db8::1".parse::<IpAddr>().unwrap(),
+                1,
+                IFNumberingSubtype::IfIndex,
+                vec![],
+            )
+            .org_specific([0x00, 0x80, 0xc2], 1, vec![0x00, 0x2a])
+            .org_specific([0x00, 0x12, 0x0f], 4, vec![0x05, 0xee])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            lldpdu.chassis_id().unwrap().value,
+            ChassisIdValue::Mac(MacAddress::new([0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa]))
+        );
+        assert_eq!(
+            lldpdu.port_id().unwrap().value,
+            PortIdValue::Other(String::from("lo"))
+        );
+        assert_eq!(lldpdu.ttl(), Some(120));
+        assert_eq!(lldpdu.system_name(), Some("HAL9000"));
+        assert_eq!(lldpdu.port_description(), None);
+        assert_eq!(lldpdu.system_description(), None);
+        assert!(lldpdu
+            .system_capabilities()
+            .unwrap()
+            .enabled()
+            .contains(SystemCapability::Bridge));
+
+        let addresses: Vec<_> = lldpdu
+            .management_addresses()
+            .map(|tlv| tlv.value.to_string())
+            .collect();
+        assert_eq!(addresses, vec!["192.0.2.1", "2001:db8::1"]);
+
+        let vlan: Vec<_> = lldpdu.org_specific([0x00, 0x80, 0xc2], 1).collect();
+        assert_eq!(vlan.len(), 1);
+        assert_eq!(vlan[0].value, vec![0x00, 0x2a]);
+        assert_eq!(lldpdu.org_specific([0x00, 0x80, 0xc2], 2).count(), 0);
+    }
+
+    #[test]
+    fn test_iterate() {
+        let lldpdu = Lldpdu::from_bytes(
+            b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x08\x0bEngineering\x00\x00",
+        );
+
+        assert_eq!(lldpdu[1].get_type(), TlvType::PortId);
+        assert_eq!(lldpdu.tlvs().count(), 5);
+
+        let types: Vec<TlvType> = (&lldpdu).into_iter().map(Tlv::get_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                TlvType::ChassisId,
+                TlvType::PortId,
+                TlvType::Ttl,
+                TlvType::PortDescription,
+                TlvType::EndOfLLDPDU
+            ]
+        );
+
+        let mut count = 0;
+        for tlv in lldpdu {
+            assert_eq!(tlv.get_type(), types[count]);
+            count += 1;
+        }
+        assert_eq!(count, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let lldpdu = Lldpdu::new(vec![]);
+        let _ = &lldpdu[0];
+    }
+
     #[test]
     fn test_display() {
         let lldpdu = Lldpdu::new(vec![
@@ -515,4 +1548,30 @@ mod tests {
             "LLDPDU(ChassisIdTLV(7, \"chair\"), PortIdTLV(7, \"Mathekeller\"), TtlTLV(1234))"
         );
     }
+
+    #[test]
+    fn test_from_str() {
+        let lldpdu = Lldpdu::try_from_bytes(b"\x02\x07\x04\x02\x04\xdf\x88\xa2\xb4\x04\x05\x05eth0\x06\x02\x00\x78\x0A\x07HAL9000\x00\x00").unwrap();
+        let parsed: Lldpdu = lldpdu.to_string().parse().unwrap();
+        assert_eq!(parsed.bytes(), lldpdu.bytes());
+
+        let lldpdu: Lldpdu = "LLDPDU()".parse().unwrap();
+        assert!(lldpdu.is_empty());
+
+        assert_eq!(
+            "LLDPDU(TtlTLV(120), ChassisIdTLV(7, \"chair\"))"
+                .parse::<Lldpdu>()
+                .unwrap_err(),
+            LldpError::OrderingViolation {
+                position: 0,
+                tlv_type: TlvType::Ttl
+            }
+        );
+        assert_eq!(
+            "LLDPDU(ChassisIdTLV(7, \"chair\") PortIdTLV(7, \"Mathekeller\"))"
+                .parse::<Lldpdu>()
+                .unwrap_err(),
+            LldpError::InvalidSyntax(32)
+        );
+    }
 }
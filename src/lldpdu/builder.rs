@@ -0,0 +1,349 @@
+use crate::error::LldpError;
+use crate::lldpdu::Lldpdu;
+use crate::tlv::chassisid_tlv::{ChassisIdSubType, ChassisIdTLV, ChassisIdValue};
+use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
+use crate::tlv::mac_address::MacAddress;
+use crate::tlv::managementaddress_tlv::{
+    IFNumberingSubtype, ManagementAddressTLV, ManagementAddressValue,
+};
+use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+use crate::tlv::portdescription_tlv::PortDescriptionTLV;
+use crate::tlv::portid_tlv::{PortIdSubtype, PortIdTLV, PortIdValue};
+use crate::tlv::systemcapabilities_tlv::{CapabilitySet, SystemCapabilitiesTLV};
+use crate::tlv::systemdescription_tlv::SystemDescriptionTLV;
+use crate::tlv::systemname_tlv::SystemNameTLV;
+use crate::tlv::ttl_tlv::TtlTLV;
+use crate::tlv::{Tlv, TlvType};
+
+/// LLDPDU Builder
+///
+/// Collects the TLVs of an LLDPDU in any order. [LldpduBuilder::build] puts the mandatory TLVs first, followed by the
+/// optional TLVs in the order they were added and an End Of LLDPDU TLV.
+///
+/// ```
+/// use lldp_rs::lldpdu::builder::LldpduBuilder;
+///
+/// let lldpdu = LldpduBuilder::new()
+///     .system_name("HAL9000")
+///     .ttl(120)
+///     .chassis_mac([0x02, 0x04, 0xdf, 0x88, 0xa2, 0xb4])
+///     .port_ifname("eth0")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(lldpdu.len(), 5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LldpduBuilder {
+    chassis_id: Option<ChassisIdTLV>,
+    port_id: Option<PortIdTLV>,
+    ttl: Option<TtlTLV>,
+    optional: Vec<Tlv>,
+    /// First error of a TLV that could not be created, returned by [LldpduBuilder::build]
+    error: Option<LldpError>,
+}
+
+impl LldpduBuilder {
+    /// Constructor
+    pub fn new() -> LldpduBuilder {
+        LldpduBuilder::default()
+    }
+
+    /// Set the chassis ID
+    pub fn chassis_id(mut self, subtype: ChassisIdSubType, id: ChassisIdValue) -> Self {
+        self.chassis_id = Some(ChassisIdTLV::new(subtype, id));
+        self
+    }
+
+    /// Set the chassis ID to a MAC address
+    pub fn chassis_mac(self, mac: impl Into<MacAddress>) -> Self {
+        self.chassis_id(
+            ChassisIdSubType::MacAddress,
+            ChassisIdValue::Mac(mac.into()),
+        )
+    }
+
+    /// Set the port ID
+    pub fn port_id(mut self, subtype: PortIdSubtype, id: PortIdValue) -> Self {
+        self.port_id = Some(PortIdTLV::new(subtype, id));
+        self
+    }
+
+    /// Set the port ID to an interface name
+    pub fn port_ifname(self, name: &str) -> Self {
+        self.port_id(
+            PortIdSubtype::InterfaceName,
+            PortIdValue::Other(name.to_string()),
+        )
+    }
+
+    /// Set the TTL in seconds
+    pub fn ttl(mut self, ttl: u16) -> Self {
+        self.ttl = Some(TtlTLV::new(ttl));
+        self
+    }
+
+    /// Add a Port Description TLV
+    pub fn port_description(self, description: &str) -> Self {
+        self.tlv(PortDescriptionTLV::new(description.to_string()).into())
+    }
+
+    /// Add a System Name TLV
+    pub fn system_name(self, name: &str) -> Self {
+        self.tlv(SystemNameTLV::new(name.to_string()).into())
+    }
+
+    /// Add a System Description TLV
+    pub fn system_description(self, description: &str) -> Self {
+        self.tlv(SystemDescriptionTLV::new(description.to_string()).into())
+    }
+
+    /// Add a System Capabilities TLV
+    ///
+    /// Enabling capabilities that are not supported makes [LldpduBuilder::build] fail.
+    pub fn capabilities(
+        self,
+        supported: impl Into<CapabilitySet>,
+        enabled: impl Into<CapabilitySet>,
+    ) -> Self {
+        self.try_tlv(SystemCapabilitiesTLV::try_new(supported, enabled))
+    }
+
+    /// Add a Management Address TLV
+    ///
+    /// An empty address, an address longer than 31 bytes or an OID longer than 128 bytes makes
+    /// [LldpduBuilder::build] fail.
+    pub fn management_address(
+        self,
+        address: impl Into<ManagementAddressValue>,
+        interface_number: u32,
+        ifsubtype: IFNumberingSubtype,
+        oid: Vec<u8>,
+    ) -> Self {
+        self.try_tlv(ManagementAddressTLV::try_new(
+            address,
+            interface_number,
+            ifsubtype,
+            oid,
+        ))
+    }
+
+    /// Add an Organizationally Specific TLV
+    pub fn org_specific(self, oui: [u8; 3], subtype: u8, value: Vec<u8>) -> Self {
+        self.tlv(OrganizationallySpecificTLV::new(oui.to_vec(), subtype, value).into())
+    }
+
+    /// Add an optional TLV
+    ///
+    /// Mandatory TLVs and End Of LLDPDU TLVs make [LldpduBuilder::build] fail, use the dedicated methods instead.
+    pub fn tlv(mut self, tlv: Tlv) -> Self {
+        self.optional.push(tlv);
+        self
+    }
+
+    /// Add an optional TLV created by a `try_new` constructor, keeping the first error for [LldpduBuilder::build].
+    fn try_tlv(mut self, tlv: Result<impl Into<Tlv>, LldpError>) -> Self {
+        match tlv {
+            Ok(tlv) => self.tlv(tlv.into()),
+            Err(e) => {
+                self.error.get_or_insert(e);
+                self
+            }
+        }
+    }
+
+    /// Create the LLDPDU.
+    ///
+    /// Returns an error if a TLV could not be created, a mandatory TLV is missing, the LLDPDU exceeds its maximum
+    /// size or has any other violation reported by [Lldpdu::validate].
+    pub fn build(self) -> Result<Lldpdu, LldpError> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+
+        let mut lldpdu = Lldpdu::new(vec![]);
+
+        let chassis_id = self
+            .chassis_id
+            .ok_or(LldpError::MissingTlv(TlvType::ChassisId))?;
+        let port_id = self.port_id.ok_or(LldpError::MissingTlv(TlvType::PortId))?;
+        let ttl = self.ttl.ok_or(LldpError::MissingTlv(TlvType::Ttl))?;
+
+        lldpdu.try_append(chassis_id.into())?;
+        lldpdu.try_append(port_id.into())?;
+        lldpdu.try_append(ttl.into())?;
+
+        for tlv in self.optional {
+            if tlv.get_type() == TlvType::EndOfLLDPDU {
+                return Err(LldpError::OrderingViolation {
+                    position: lldpdu.len(),
+                    tlv_type: TlvType::EndOfLLDPDU,
+                });
+            }
+            lldpdu.try_append(tlv)?;
+        }
+
+        lldpdu.try_append(EndOfLLDPDUTLV::new().into())?;
+
+        match lldpdu.validate().into_iter().next() {
+            Some(violation) => Err(violation),
+            None => Ok(lldpdu),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::systemcapabilities_tlv::SystemCapability;
+
+    fn mandatory() -> LldpduBuilder {
+        LldpduBuilder::new()
+            .chassis_mac([0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa])
+            .port_ifname("lo")
+            .ttl(60)
+    }
+
+    #[test]
+    fn test_build() {
+        let lldpdu = LldpduBuilder::new()
+            .system_name("HAL9000")
+            .ttl(60)
+            .org_specific([0x00, 0x80, 0xc2], 1, vec![0x00, 0x2a])
+            .port_ifname("lo")
+            .chassis_mac([0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            lldpdu.bytes(),
+            b"\x02\x07\x04\xff\xee\xdd\xcc\xbb\xaa\x04\x03\x05lo\x06\x02\x00\x3c\x0A\x07HAL9000\xFE\x06\x00\x80\xc2\x01\x00\x2a\x00\x00"
+        );
+        assert!(lldpdu.complete());
+        assert!(lldpdu.is_valid());
+    }
+
+    #[test]
+    fn test_build_all() {
+        let lldpdu = mandatory()
+            .port_description("Loopback")
+            .system_name("HAL9000")
+            .system_description("Heuristically programmed ALgorithmic computer")
+            .capabilities(
+                SystemCapability::Bridge | SystemCapability::Router,
+                SystemCapability::Bridge,
+            )
+            .management_address(
+                "192.0.2.1".parse::<std::net::IpAddr>().unwrap(),
+                1,
+                IFNumberingSubtype::IfIndex,
+                vec![],
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(lldpdu.len(), 9);
+        assert_eq!(
+            lldpdu.system_capabilities().unwrap().enabled(),
+            SystemCapability::Bridge.into()
+        );
+
+        // Station Only combined with other capabilities is only a warning
+        let lldpdu = mandatory().capabilities(0x00a0, 0x0080).build().unwrap();
+        assert_eq!(
+            lldpdu.validation_warnings(),
+            [LldpError::StationOnlyConflict(0x00a0)]
+        );
+    }
+
+    #[test]
+    fn test_build_missing_mandatory() {
+        assert_eq!(
+            LldpduBuilder::new()
+                .port_ifname("lo")
+                .ttl(60)
+                .build()
+                .unwrap_err(),
+            LldpError::MissingTlv(TlvType::ChassisId)
+        );
+        assert_eq!(
+            LldpduBuilder::new()
+                .chassis_mac([0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa])
+                .port_ifname("lo")
+                .build()
+                .unwrap_err(),
+            LldpError::MissingTlv(TlvType::Ttl)
+        );
+    }
+
+    #[test]
+    fn test_build_invalid() {
+        assert_eq!(
+            mandatory().tlv(TtlTLV::new(10).into()).build().unwrap_err(),
+            LldpError::OrderingViolation {
+                position: 3,
+                tlv_type: TlvType::Ttl
+            }
+        );
+        assert_eq!(
+            mandatory()
+                .tlv(EndOfLLDPDUTLV::new().into())
+                .build()
+                .unwrap_err(),
+            LldpError::OrderingViolation {
+                position: 3,
+                tlv_type: TlvType::EndOfLLDPDU
+            }
+        );
+        assert_eq!(
+            mandatory()
+                .system_name("HAL9000")
+                .system_name("HAL9001")
+                .build()
+                .unwrap_err(),
+            LldpError::DuplicateTlv(TlvType::SystemName)
+        );
+        assert_eq!(
+            mandatory()
+                .capabilities(0x0004, 0x0014)
+                .build()
+                .unwrap_err(),
+            LldpError::CapabilityMismatch {
+                supported: 0x0004,
+                enabled: 0x0014
+            }
+        );
+        assert_eq!(
+            mandatory()
+                .management_address(
+                    ManagementAddressValue::NetworkAddress {
+                        family: 6,
+                        bytes: vec![0xab; 300],
+                    },
+                    1,
+                    IFNumberingSubtype::IfIndex,
+                    vec![],
+                )
+                .build()
+                .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ManagementAddress,
+                length: 308
+            }
+        );
+    }
+
+    #[test]
+    fn test_build_too_large() {
+        let description = "x".repeat(255);
+        let mut builder = mandatory();
+        for _ in 0..6 {
+            builder = builder.tlv(SystemDescriptionTLV::new(description.clone()).into());
+        }
+
+        assert!(matches!(
+            builder.build(),
+            Err(LldpError::TooLarge { max: 1500, .. })
+        ));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::error::LldpError;
+use crate::lldpdu::Lldpdu;
+use crate::tlv::tlv_ref::TlvRef;
+use crate::tlv::TlvType;
+
+/// Borrowed LLDP Data Unit
+///
+/// A view of a packed LLDPDU, e.g. the payload of a captured Ethernet frame. TLVs are decoded lazily while iterating
+/// and borrow from the underlying buffer, so scanning an LLDPDU does not allocate.
+///
+/// Creating the view does not validate the LLDPDU. Malformed TLVs are reported by the iterator, and
+/// [LldpduRef::to_owned] performs the same checks as [Lldpdu::try_from_bytes].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LldpduRef<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> LldpduRef<'a> {
+    /// Constructor
+    pub fn new(data: &'a [u8]) -> LldpduRef<'a> {
+        LldpduRef { data }
+    }
+
+    /// Return the underlying bytes of the LLDPDU
+    pub fn bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Iterate over the TLVs of the LLDPDU.
+    ///
+    /// Iteration stops after the End Of LLDPDU TLV, so any padding following it is skipped. If a TLV is truncated
+    /// the iterator yields the error and stops.
+    pub fn tlvs(&self) -> TlvRefIter<'a> {
+        TlvRefIter {
+            data: self.data,
+            done: false,
+        }
+    }
+
+    /// Find the first TLV of type `tlv_type`.
+    ///
+    /// Returns `None` if there is no such TLV or a malformed TLV is encountered before it.
+    pub fn find(&self, tlv_type: TlvType) -> Option<TlvRef<'a>> {
+        self.tlvs()
+            .map_while(Result::ok)
+            .find(|tlv| tlv.tlv_type() == tlv_type)
+    }
+
+    /// Get the TTL of the LLDPDU
+    pub fn ttl(&self) -> Option<u16> {
+        self.find(TlvType::Ttl).and_then(|tlv| tlv.ttl())
+    }
+
+    /// Create an owned [Lldpdu] from the view.
+    ///
+    /// Returns an error if the LLDPDU contains errors.
+    pub fn to_owned(&self) -> Result<Lldpdu, LldpError> {
+        Lldpdu::try_from_bytes(self.data)
+    }
+}
+
+impl<'a> IntoIterator for LldpduRef<'a> {
+    type Item = Result<TlvRef<'a>, LldpError>;
+    type IntoIter = TlvRefIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tlvs()
+    }
+}
+
+impl<'a> IntoIterator for &LldpduRef<'a> {
+    type Item = Result<TlvRef<'a>, LldpError>;
+    type IntoIter = TlvRefIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tlvs()
+    }
+}
+
+/// Iterator over the TLVs of an [LldpduRef]
+#[derive(Debug, Clone)]
+pub struct TlvRefIter<'a> {
+    data: &'a [u8],
+    done: bool,
+}
+
+impl<'a> Iterator for TlvRefIter<'a> {
+    type Item = Result<TlvRef<'a>, LldpError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.data.is_empty() {
+            return None;
+        }
+
+        match TlvRef::new(self.data) {
+            Ok(tlv) => {
+                self.data = &self.data[tlv.bytes().len()..];
+                self.done = tlv.tlv_type() == TlvType::EndOfLLDPDU;
+                Some(Ok(tlv))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl std::iter::FusedIterator for TlvRefIter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: &[u8] =
+        b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x08\x0bEngineering\x00\x00\x00\x00\x00\x00";
+
+    #[test]
+    fn test_iterate() {
+        let lldpdu = LldpduRef::new(FRAME);
+        let types: Vec<u8> = lldpdu.tlvs().map(|tlv| tlv.unwrap().type_code()).collect();
+        assert_eq!(types, vec![1, 2, 3, 4, 0]);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut count = 0;
+        for tlv in LldpduRef::new(FRAME) {
+            assert!(tlv.is_ok());
+            count += 1;
+        }
+        assert_eq!(count, 5);
+    }
+
+    #[test]
+    fn test_accessors() {
+        let lldpdu = LldpduRef::new(FRAME);
+        assert_eq!(lldpdu.ttl(), Some(255));
+        assert_eq!(
+            lldpdu.find(TlvType::PortDescription).unwrap().as_str(),
+            Some("Engineering")
+        );
+        assert_eq!(lldpdu.find(TlvType::SystemName), None);
+    }
+
+    #[test]
+    fn test_truncated() {
+        let lldpdu = LldpduRef::new(b"\x02\x08\x07Voyager\x04\x06\x0710");
+        let tlvs: Vec<_> = lldpdu.tlvs().collect();
+        assert_eq!(tlvs.len(), 2);
+        assert!(tlvs[0].is_ok());
+        assert_eq!(
+            tlvs[1],
+            Err(LldpError::Truncated {
+                needed: 8,
+                available: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_owned() {
+        let lldpdu = LldpduRef::new(&FRAME[..FRAME.len() - 4])
+            .to_owned()
+            .unwrap();
+        assert_eq!(lldpdu.len(), 5);
+        assert_eq!(lldpdu.bytes(), &FRAME[..FRAME.len() - 4]);
+    }
+}
@@ -1,10 +1,6 @@
-use agent::LLDPAgent;
+use lldp_rs::agent::LLDPAgent;
 use pnet::datalink;
 
-mod agent;
-mod lldpdu;
-mod tlv;
-
 fn main() {
     let interface_name = std::env::args().nth(1).unwrap_or_else(|| "eth0".into());
 
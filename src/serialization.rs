@@ -0,0 +1,462 @@
+//! Serde support
+//!
+//! TLVs are serialized in a human-friendly form: MAC addresses as `aa:bb:cc:dd:ee:ff`, OUIs as `00-80-C2`, OIDs in
+//! dotted notation, capabilities as lists of names and raw values as hex strings. Every representation is lossless,
+//! i.e. deserializing a serialized TLV yields the same wire bytes.
+//!
+//! An [Lldpdu] is serialized as the sequence of its TLVs.
+//!
+//! TLVs are deserialized through their `try_new` constructors and have to encode to bytes that decode again, so
+//! invalid input is reported as a deserialization error.
+
+use crate::error::LldpError;
+use crate::lldpdu::Lldpdu;
+use crate::tlv::chassisid_tlv::{ChassisIdSubType, ChassisIdTLV, ChassisIdValue};
+use crate::tlv::mac_address::{Eui64, MacAddress};
+use crate::tlv::managementaddress_tlv::{
+    IFNumberingSubtype, ManagementAddressTLV, ManagementAddressValue,
+};
+use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+use crate::tlv::portdescription_tlv::PortDescriptionTLV;
+use crate::tlv::portid_tlv::{PortIdSubtype, PortIdTLV, PortIdValue};
+use crate::tlv::systemcapabilities_tlv::{SystemCapabilitiesTLV, SystemCapability};
+use crate::tlv::systemdescription_tlv::SystemDescriptionTLV;
+use crate::tlv::systemname_tlv::SystemNameTLV;
+use crate::tlv::{self, Tlv, TlvCodec, TlvType};
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+
+/// Default for the `tlv_type` field of a TLV, which is not serialized
+pub(crate) fn tlv_type<T: TlvCodec>() -> TlvType {
+    T::TLV_TYPE
+}
+
+fn parse_hex<E: Error>(s: &str) -> Result<Vec<u8>, E> {
+    if s.len() % 2 != 0 || !s.is_ascii() {
+        return Err(E::custom(format!("invalid hex string \"{}\"", s)));
+    }
+
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&s[i..i + 2], 16)
+                .map_err(|_| E::custom(format!("invalid hex string \"{}\"", s)))
+        })
+        .collect()
+}
+
+fn parse_separated<E: Error>(s: &str, separator: char, len: Option<usize>) -> Result<Vec<u8>, E> {
+    let bytes = s
+        .split(separator)
+        .map(|octet| match octet.len() {
+            1 | 2 => u8::from_str_radix(octet, 16).ok(),
+            _ => None,
+        })
+        .collect::<Option<Vec<u8>>>();
+
+    match bytes {
+        Some(bytes) if len.map_or(true, |len| bytes.len() == len) => Ok(bytes),
+        _ => Err(E::custom(format!("invalid address \"{}\"", s))),
+    }
+}
+
+/// Raw bytes as a lowercase hex string
+pub(crate) mod hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let hex: String = value.iter().map(|b| format!("{:02x}", b)).collect();
+        serializer.serialize_str(&hex)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        parse_hex(&String::deserialize(deserializer)?)
+    }
+}
+
+/// OUI as `00-80-C2`
+pub(crate) mod oui {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let octets: Vec<String> = value.iter().map(|b| format!("{:02X}", b)).collect();
+        serializer.serialize_str(&octets.join("-"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        parse_separated(&String::deserialize(deserializer)?, '-', Some(3))
+    }
+}
+
+/// BER encoded object identifier in dotted notation, e.g. `1.3.6.1.4.1.9`
+///
+/// OIDs that are not valid BER are serialized as hex strings instead, which never contain a dot.
+pub(crate) mod oid {
+    use super::*;
+    use crate::tlv::oid::Oid;
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        match Oid::from_ber(value) {
+            Ok(oid) if oid.to_ber() == value => serializer.serialize_str(&oid.to_string()),
+            _ => super::hex::serialize(value, serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        if s.contains('.') {
+            s.parse::<Oid>()
+                .map(|oid| oid.to_ber())
+                .map_err(D::Error::custom)
+        } else {
+            parse_hex(&s)
+        }
+    }
+}
+
+impl Serialize for MacAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MacAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for Eui64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Eui64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// A single capability bit, reserved bits are serialized as their numeric value
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Capability {
+    Named(SystemCapability),
+    Reserved(u16),
+}
+
+fn capability_list(bitmap: u16) -> Vec<Capability> {
+    (0..16)
+        .map(|bit| 1 << bit)
+        .filter(|flag| bitmap & flag != 0)
+        .map(|flag| match SystemCapability::try_from(flag) {
+            Ok(capability) => Capability::Named(capability),
+            Err(()) => Capability::Reserved(flag),
+        })
+        .collect()
+}
+
+fn capability_bitmap(list: &[Capability]) -> u16 {
+    list.iter()
+        .map(|capability| match capability {
+            Capability::Named(capability) => *capability as u16,
+            Capability::Reserved(flag) => *flag,
+        })
+        .fold(0, |bitmap, flag| bitmap | flag)
+}
+
+/// System capabilities as lists of capability names
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Capabilities {
+    supported: Vec<Capability>,
+    enabled: Vec<Capability>,
+}
+
+impl From<SystemCapabilitiesTLV> for Capabilities {
+    fn from(tlv: SystemCapabilitiesTLV) -> Self {
+        Capabilities {
+            supported: capability_list(tlv.supported.bits()),
+            enabled: capability_list(tlv.enabled.bits()),
+        }
+    }
+}
+
+impl TryFrom<Capabilities> for SystemCapabilitiesTLV {
+    type Error = LldpError;
+
+    fn try_from(capabilities: Capabilities) -> Result<Self, Self::Error> {
+        SystemCapabilitiesTLV::try_new(
+            capability_bitmap(&capabilities.supported),
+            capability_bitmap(&capabilities.enabled),
+        )
+    }
+}
+
+/// Check that `tlv` encodes to bytes that decode again, e.g. that the address family is known.
+fn checked<T: TlvCodec>(tlv: T) -> Result<T, LldpError> {
+    T::try_from_bytes(&tlv.bytes())?;
+    Ok(tlv)
+}
+
+/// Fields of a Chassis ID TLV
+#[derive(Deserialize)]
+pub(crate) struct ChassisIdFields {
+    subtype: ChassisIdSubType,
+    value: ChassisIdValue,
+}
+
+impl TryFrom<ChassisIdFields> for ChassisIdTLV {
+    type Error = LldpError;
+
+    fn try_from(fields: ChassisIdFields) -> Result<Self, Self::Error> {
+        ChassisIdTLV::try_new(fields.subtype, fields.value)
+    }
+}
+
+/// Fields of a Port ID TLV
+#[derive(Deserialize)]
+pub(crate) struct PortIdFields {
+    subtype: PortIdSubtype,
+    value: PortIdValue,
+}
+
+impl TryFrom<PortIdFields> for PortIdTLV {
+    type Error = LldpError;
+
+    fn try_from(fields: PortIdFields) -> Result<Self, Self::Error> {
+        PortIdTLV::try_new(fields.subtype, fields.value)
+    }
+}
+
+/// Fields of a Management Address TLV
+#[derive(Deserialize)]
+pub(crate) struct ManagementAddressFields {
+    subtype: IFNumberingSubtype,
+    value: ManagementAddressValue,
+    interface_number: u32,
+    #[serde(with = "oid")]
+    oid: Vec<u8>,
+}
+
+impl TryFrom<ManagementAddressFields> for ManagementAddressTLV {
+    type Error = LldpError;
+
+    fn try_from(fields: ManagementAddressFields) -> Result<Self, Self::Error> {
+        checked(ManagementAddressTLV::try_new(
+            fields.value,
+            fields.interface_number,
+            fields.subtype,
+            fields.oid,
+        )?)
+    }
+}
+
+/// Fields of an Organizationally Specific TLV
+#[derive(Deserialize)]
+pub(crate) struct OrganizationallySpecificFields {
+    #[serde(with = "oui")]
+    oui: Vec<u8>,
+    subtype: u8,
+    #[serde(with = "hex")]
+    value: Vec<u8>,
+}
+
+impl TryFrom<OrganizationallySpecificFields> for OrganizationallySpecificTLV {
+    type Error = LldpError;
+
+    fn try_from(fields: OrganizationallySpecificFields) -> Result<Self, Self::Error> {
+        OrganizationallySpecificTLV::try_new(fields.oui, fields.subtype, fields.value)
+    }
+}
+
+/// Implement the conversions of the TLVs that are serialized as a plain string.
+macro_rules! string_tlv {
+    ($($tlv:ident),*) => {
+        $(
+            impl TryFrom<String> for $tlv {
+                type Error = LldpError;
+
+                fn try_from(value: String) -> Result<Self, Self::Error> {
+                    $tlv::try_new(value)
+                }
+            }
+
+            impl From<$tlv> for String {
+                fn from(tlv: $tlv) -> Self {
+                    tlv.value
+                }
+            }
+        )*
+    };
+}
+
+string_tlv!(PortDescriptionTLV, SystemNameTLV, SystemDescriptionTLV);
+
+/// Deserialize the fields of a [Tlv::Unknown], checked like [Tlv::try_unknown]
+pub(crate) fn unknown<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<(u8, Vec<u8>), D::Error> {
+    #[derive(Deserialize)]
+    struct Fields {
+        tlv_type: u8,
+        #[serde(with = "hex")]
+        value: Vec<u8>,
+    }
+
+    let fields = Fields::deserialize(deserializer)?;
+    tlv::check_unknown(fields.tlv_type, &fields.value).map_err(D::Error::custom)?;
+
+    Ok((fields.tlv_type, fields.value))
+}
+
+impl Serialize for Lldpdu {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.tlvs())
+    }
+}
+
+impl<'de> Deserialize<'de> for Lldpdu {
+    /// Deserialize a sequence of TLVs.
+    ///
+    /// Returns an error if the TLVs could not be appended in this order, see [Lldpdu::try_append].
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut lldpdu = Lldpdu::new(vec![]);
+        for tlv in Vec::<Tlv>::deserialize(deserializer)? {
+            lldpdu.try_append(tlv).map_err(D::Error::custom)?;
+        }
+
+        Ok(lldpdu)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lldpdu::builder::LldpduBuilder;
+    use crate::tlv::managementaddress_tlv::{IFNumberingSubtype, ManagementAddressTLV};
+    use serde_json::json;
+    use std::net::IpAddr;
+
+    fn roundtrip(tlv: Tlv) -> serde_json::Value {
+        let value = serde_json::to_value(&tlv).unwrap();
+        let decoded: Tlv = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(decoded.bytes(), tlv.bytes());
+        value
+    }
+
+    #[test]
+    fn test_tlvs() {
+        let tlv = Tlv::try_from_bytes(b"\x02\x07\x04\x02\x04\xdf\x88\xa2\xb4").unwrap();
+        assert_eq!(
+            roundtrip(tlv),
+            json!({"ChassisId": {"subtype": "MacAddress", "value": {"Mac": "02:04:df:88:a2:b4"}}})
+        );
+
+        let tlv = Tlv::try_from_bytes(b"\x06\x02\x00\x78").unwrap();
+        assert_eq!(roundtrip(tlv), json!({"Ttl": 120}));
+
+        let tlv = Tlv::try_from_bytes(b"\x0e\x04\x08\x14\x00\x04").unwrap();
+        assert_eq!(
+            roundtrip(tlv),
+            json!({"SystemCapabilities": {"supported": ["Bridge", "Router", 2048], "enabled": ["Bridge"]}})
+        );
+
+        let tlv = Tlv::try_from_bytes(b"\xFE\x06\x00\x80\xc2\x01\x00\x2a").unwrap();
+        assert_eq!(
+            roundtrip(tlv),
+            json!({"OrganizationallySpecific": {"oui": "00-80-C2", "subtype": 1, "value": "002a"}})
+        );
+
+        let tlv = Tlv::try_from_bytes(b"\x12\x02\xbe\xef").unwrap();
+        assert_eq!(
+            roundtrip(tlv),
+            json!({"Unknown": {"tlv_type": 9, "value": "beef"}})
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let invalid = [
+            // Enabled capabilities that are not supported
+            json!({"SystemCapabilities": {"supported": ["Bridge"], "enabled": ["Router"]}}),
+            // Unknown TLVs of a defined type or a type that does not fit the header
+            json!({"Unknown": {"tlv_type": 1, "value": "0741"}}),
+            json!({"Unknown": {"tlv_type": 200, "value": ""}}),
+            // OUI of 2 bytes
+            json!({"OrganizationallySpecific": {"oui": "00-80", "subtype": 1, "value": "002a"}}),
+            // Strings that are too long
+            json!({ "SystemName": "x".repeat(256) }),
+            json!({ "SystemDescription": "x".repeat(512) }),
+            json!({"ChassisId": {"subtype": "Local", "value": {"Other": "x".repeat(256)}}}),
+            json!({"PortId": {"subtype": "Local", "value": {"Other": ""}}}),
+            // A MAC address subtype with a string ID
+            json!({"ChassisId": {"subtype": "MacAddress", "value": {"Other": "sw"}}}),
+            // An empty management address
+            json!({"ManagementAddress": {"subtype": "IfIndex", "value": {"family": 6, "bytes": ""}, "interface_number": 1, "oid": ""}}),
+        ];
+
+        for value in invalid {
+            assert!(
+                serde_json::from_value::<Tlv>(value.clone()).is_err(),
+                "{} deserialized",
+                value
+            );
+        }
+
+        let error = serde_json::from_value::<Tlv>(
+            json!({"SystemCapabilities": {"supported": ["Bridge"], "enabled": ["Router"]}}),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "enabled capabilities 0x0010 are not supported (0x0004)"
+        );
+    }
+
+    #[test]
+    fn test_oid() {
+        let tlv = ManagementAddressTLV::new(
+            "192.0.2.1".parse::<IpAddr>().unwrap(),
+            4,
+            IFNumberingSubtype::IfIndex,
+            b"\x2b\x06\x01\x04\x01\x82\x37".to_vec(),
+        );
+        let value = roundtrip(tlv.into());
+        assert_eq!(value["ManagementAddress"]["oid"], "1.3.6.1.4.1.311");
+        assert_eq!(value["ManagementAddress"]["value"], "192.0.2.1");
+
+        // Not valid BER, the last arc is truncated
+        let tlv = ManagementAddressTLV::new(
+            "192.0.2.1".parse::<IpAddr>().unwrap(),
+            4,
+            IFNumberingSubtype::IfIndex,
+            b"\x2b\x86".to_vec(),
+        );
+        assert_eq!(roundtrip(tlv.into())["ManagementAddress"]["oid"], "2b86");
+    }
+
+    #[test]
+    fn test_lldpdu() {
+        let lldpdu = LldpduBuilder::new()
+            .chassis_mac([0x02, 0x04, 0xdf, 0x88, 0xa2, 0xb4])
+            .port_ifname("eth0")
+            .ttl(120)
+            .system_name("HAL9000")
+            .capabilities(0x0014, 0x0004)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&lldpdu).unwrap();
+        let decoded: Lldpdu = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.bytes(), lldpdu.bytes());
+
+        assert!(serde_json::from_str::<Lldpdu>(r#"[{"Ttl": 120}]"#).is_err());
+    }
+}
@@ -1,15 +1,22 @@
+use bytes::BufMut;
 use std::convert::TryFrom;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 
+pub mod address_family;
 pub mod chassisid_tlv;
 pub mod eolldpdu_tlv;
+pub mod mac_address;
 pub mod managementaddress_tlv;
+pub mod oid;
 pub mod organizationallyspecific_tlv;
+pub(crate) mod parser;
 pub mod portdescription_tlv;
 pub mod portid_tlv;
 pub mod systemcapabilities_tlv;
 pub mod systemdescription_tlv;
 pub mod systemname_tlv;
+pub mod tlv_ref;
 pub mod ttl_tlv;
 
 #[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
@@ -24,6 +31,8 @@ pub enum TlvType {
     SystemCapabilities = 7,
     ManagementAddress = 8,
     OrganizationallySpecific = 127,
+    /// Any of the reserved TLV types 9 - 126. The actual type is kept by [Tlv::Unknown].
+    Unknown = 255,
 }
 
 impl TryFrom<u8> for TlvType {
@@ -48,8 +57,221 @@ impl TryFrom<u8> for TlvType {
     }
 }
 
+/// Maximum length of a TLV value in bytes, limited by the 9 bit length field.
+pub const TLV_MAX_VALUE_LEN: usize = 511;
+
+/// Maximum length of the string and ID fields of the basic TLVs in bytes.
+pub const MAX_STRING_LEN: usize = 255;
+
+/// TLV types reserved for future standardization, decoded as [Tlv::Unknown].
+pub const RESERVED_TLV_TYPES: RangeInclusive<u8> = 9..=126;
+
+/// Check that `tlv_type` is a reserved type and `value` fits the TLV length field, see [Tlv::try_unknown].
+pub(crate) fn check_unknown(tlv_type: u8, value: &[u8]) -> Result<(), LldpError> {
+    if !RESERVED_TLV_TYPES.contains(&tlv_type) {
+        return Err(LldpError::WrongType {
+            expected: TlvType::Unknown,
+            found: tlv_type,
+        });
+    }
+    if value.len() > TLV_MAX_VALUE_LEN {
+        return Err(LldpError::BadLength {
+            tlv_type: TlvType::Unknown,
+            length: value.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Check that the value length of `tlv` lies within `range`, used by the `try_new` constructors of the TLVs.
+pub(crate) fn check_len<T: TlvCodec>(tlv: T, range: RangeInclusive<usize>) -> Result<T, LldpError> {
+    let length = tlv.len();
+    if !range.contains(&length) {
+        return Err(LldpError::BadLength {
+            tlv_type: T::TLV_TYPE,
+            length,
+        });
+    }
+
+    Ok(tlv)
+}
+
+/// TLV Header
+///
+/// Every TLV starts with a two byte header holding a 7 bit type and a 9 bit value length:
+///
+/// ```text
+///      0                   1
+///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5
+///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///     |             |                 |
+///     |    Type     |     Length      |
+///     |             |                 |
+///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// The most significant bit of the length is stored in the least significant bit of the first byte, so values of up
+/// to 511 bytes can be described.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TlvHeader {
+    /// The raw TLV type (0 - 127)
+    pub tlv_type: u8,
+    /// The length of the TLV value in bytes (0 - 511)
+    pub length: usize,
+}
+
+impl TlvHeader {
+    /// Size of the header in bytes
+    pub const LEN: usize = 2;
+
+    /// Constructor
+    pub fn new(tlv_type: u8, length: usize) -> TlvHeader {
+        TlvHeader { tlv_type, length }
+    }
+
+    /// Read the header from the first two bytes of `bytes`.
+    pub fn parse(bytes: &[u8]) -> Result<TlvHeader, LldpError> {
+        if bytes.len() < TlvHeader::LEN {
+            return Err(LldpError::Truncated {
+                needed: TlvHeader::LEN,
+                available: bytes.len(),
+            });
+        }
+
+        let field = u16::from_be_bytes([bytes[0], bytes[1]]);
+
+        Ok(TlvHeader {
+            tlv_type: (field >> 9) as u8,
+            length: (field & 0x01FF) as usize,
+        })
+    }
+
+    /// Read the header of a TLV of type `expected` and return its value.
+    ///
+    /// Bytes following the value are ignored.
+    pub fn value_of(bytes: &[u8], expected: TlvType) -> Result<&[u8], LldpError> {
+        let header = TlvHeader::parse(bytes)?;
+
+        if header.tlv_type != expected as u8 {
+            return Err(LldpError::WrongType {
+                expected,
+                found: header.tlv_type,
+            });
+        }
+
+        header.value(bytes)
+    }
+
+    /// Return the value described by this header from `bytes`, which must start with the header.
+    pub fn value<'a>(&self, bytes: &'a [u8]) -> Result<&'a [u8], LldpError> {
+        let size = self.size();
+        if bytes.len() < size {
+            return Err(LldpError::Truncated {
+                needed: size,
+                available: bytes.len(),
+            });
+        }
+
+        Ok(&bytes[TlvHeader::LEN..size])
+    }
+
+    /// Size of the whole TLV (header and value) in bytes
+    pub fn size(&self) -> usize {
+        TlvHeader::LEN + self.length
+    }
+
+    /// Return the byte representation of the header.
+    ///
+    /// Panics if the type or length does not fit into its field.
+    pub fn bytes(&self) -> [u8; 2] {
+        assert!(
+            self.tlv_type <= 127,
+            "TLV type {} out of range",
+            self.tlv_type
+        );
+        assert!(
+            self.length <= TLV_MAX_VALUE_LEN,
+            "TLV length {} exceeds {} bytes",
+            self.length,
+            TLV_MAX_VALUE_LEN
+        );
+
+        (((self.tlv_type as u16) << 9) | self.length as u16).to_be_bytes()
+    }
+
+    /// Write the byte representation of the header to `buf`.
+    ///
+    /// Panics if the type or length does not fit into its field.
+    pub fn encode(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&self.bytes());
+    }
+}
+
+/// TLV Codec
+///
+/// Encoding and decoding of a TLV defined by IEEE 802.1AB (or an extension of it).
+///
+/// A TLV only has to describe its value, the TLV header is written and checked by the provided methods. Adding a new
+/// TLV to the crate means implementing this trait and listing the TLV in [Tlv].
+pub trait TlvCodec: Sized {
+    /// The type of the TLV
+    const TLV_TYPE: TlvType;
+
+    /// Return the length of the TLV value
+    fn len(&self) -> usize;
+
+    /// Write the TLV value (everything after the header) to `buf`.
+    fn encode_value(&self, buf: &mut impl BufMut);
+
+    /// Create a TLV instance from its value (everything after the header).
+    ///
+    /// Returns an error if the value contains errors.
+    fn decode_value(value: &[u8]) -> Result<Self, LldpError>;
+
+    /// Check if the TLV value is empty
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the size of the encoded TLV, including its header
+    fn encoded_len(&self) -> usize {
+        TlvHeader::LEN + self.len()
+    }
+
+    /// Write the byte representation of the TLV to `buf`.
+    fn encode(&self, buf: &mut impl BufMut) {
+        TlvHeader::new(Self::TLV_TYPE as u8, self.len()).encode(buf);
+        self.encode_value(buf);
+    }
+
+    /// Return the byte representation of the TLV.
+    fn bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.encoded_len());
+        self.encode(&mut result);
+
+        result
+    }
+
+    /// Create a TLV instance from raw bytes.
+    ///
+    /// Returns an error if the provided TLV contains errors (e.g. has the wrong type).
+    fn try_from_bytes(bytes: &[u8]) -> Result<Self, LldpError> {
+        Self::decode_value(TlvHeader::value_of(bytes, Self::TLV_TYPE)?)
+    }
+
+    /// Create a TLV instance from raw bytes.
+    ///
+    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
+    /// Use [TlvCodec::try_from_bytes] to handle malformed input.
+    fn new_from_bytes(bytes: &[u8]) -> Self {
+        Self::try_from_bytes(bytes).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
 // create bare tlv class, this allows for calling default TLV::functions
 
+use crate::error::LldpError;
 use crate::tlv::chassisid_tlv::ChassisIdTLV;
 use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
 use crate::tlv::managementaddress_tlv::ManagementAddressTLV;
@@ -74,6 +296,7 @@ use crate::tlv::ttl_tlv::TtlTLV;
 /// other TLVs. It might be worth checking out the formats of the other TLVs and implement a lowest common
 /// denominator here. It is not required however.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Tlv {
     ChassisId(ChassisIdTLV),
     EndOfLldpdu(EndOfLLDPDUTLV),
@@ -85,44 +308,130 @@ pub enum Tlv {
     SystemName(SystemNameTLV),
     SystemCapabilities(SystemCapabilitiesTLV),
     Ttl(TtlTLV),
+    /// A TLV of a reserved type (9 - 126) that is not defined by IEEE 802.1AB.
+    ///
+    /// Receivers have to skip such TLVs. They are kept as raw bytes so they can be inspected and re-encoded
+    /// unchanged. Use [Tlv::try_unknown] to create them, [Lldpdu::try_append](crate::lldpdu::Lldpdu::try_append)
+    /// rejects any other type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::unknown")
+    )]
+    Unknown {
+        tlv_type: u8,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::hex"))]
+        value: Vec<u8>,
+    },
 }
 
-impl Display for Tlv {
-    /// Write a printable representation of the TLV object.
-    ///
-    /// The representation should have the following form:
-    ///     StructName(arg1, arg2, arg3)
-    ///
-    /// (See also the test_display tests in the corresponding files)
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Tlv::ChassisId(value) => value.fmt(f),
-            Tlv::EndOfLldpdu(value) => value.fmt(f),
-            Tlv::ManagementAddress(value) => value.fmt(f),
-            Tlv::OrganizationallySpecific(value) => value.fmt(f),
-            Tlv::PortId(value) => value.fmt(f),
-            Tlv::PortDescription(value) => value.fmt(f),
-            Tlv::SystemDescription(value) => value.fmt(f),
-            Tlv::SystemName(value) => value.fmt(f),
-            Tlv::SystemCapabilities(value) => value.fmt(f),
-            Tlv::Ttl(value) => value.fmt(f),
+/// Dispatch the methods of [Tlv] to the [TlvCodec] implementation of each variant.
+macro_rules! tlv_dispatch {
+    ($($variant:ident($tlv:ident)),* $(,)?) => {
+        impl Display for Tlv {
+            /// Write a printable representation of the TLV object.
+            ///
+            /// The representation should have the following form:
+            ///     StructName(arg1, arg2, arg3)
+            ///
+            /// (See also the test_display tests in the corresponding files)
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Tlv::$variant(value) => value.fmt(f),)*
+                    Tlv::Unknown { tlv_type, value } => {
+                        let mut hex = String::new();
+                        for i in value {
+                            hex.push_str(&format!("{:02X}", i));
+                        }
+
+                        write!(f, "UnknownTLV({}, \"{}\")", tlv_type, hex)
+                    }
+                }
+            }
         }
-    }
+
+        $(
+            impl From<$tlv> for Tlv {
+                fn from(tlv: $tlv) -> Tlv {
+                    Tlv::$variant(tlv)
+                }
+            }
+        )*
+
+        impl Tlv {
+            /// Get the type of the TLV.
+            ///
+            /// TLVs of a reserved type are reported as [TlvType::Unknown], use [Tlv::type_code] to get the raw type.
+            pub fn get_type(&self) -> TlvType {
+                match self {
+                    $(Tlv::$variant(_) => $tlv::TLV_TYPE,)*
+                    Tlv::Unknown { .. } => TlvType::Unknown,
+                }
+            }
+
+            /// Return the size of the encoded TLV, including its header
+            pub fn encoded_len(&self) -> usize {
+                match self {
+                    $(Tlv::$variant(value) => value.encoded_len(),)*
+                    Tlv::Unknown { value, .. } => TlvHeader::LEN + value.len(),
+                }
+            }
+
+            /// Write the byte representation of the TLV to `buf`.
+            ///
+            /// See [Tlv::bytes] for the format.
+            pub fn encode(&self, buf: &mut impl BufMut) {
+                match self {
+                    $(Tlv::$variant(value) => value.encode(buf),)*
+                    Tlv::Unknown { tlv_type, value } => {
+                        TlvHeader::new(*tlv_type, value.len()).encode(buf);
+                        buf.put_slice(value);
+                    }
+                }
+            }
+
+            /// Decode a TLV of a type defined by IEEE 802.1AB.
+            fn decode_defined(tlv_type: TlvType, bytes: &[u8]) -> Result<Tlv, LldpError> {
+                $(
+                    if tlv_type == $tlv::TLV_TYPE {
+                        return $tlv::try_from_bytes(bytes).map(Tlv::$variant);
+                    }
+                )*
+
+                unreachable!("no TLV registered for {:?}", tlv_type)
+            }
+        }
+    };
+}
+
+tlv_dispatch! {
+    ChassisId(ChassisIdTLV),
+    EndOfLldpdu(EndOfLLDPDUTLV),
+    ManagementAddress(ManagementAddressTLV),
+    OrganizationallySpecific(OrganizationallySpecificTLV),
+    PortId(PortIdTLV),
+    PortDescription(PortDescriptionTLV),
+    SystemDescription(SystemDescriptionTLV),
+    SystemName(SystemNameTLV),
+    SystemCapabilities(SystemCapabilitiesTLV),
+    Ttl(TtlTLV),
 }
 
 impl Tlv {
-    pub fn get_type(&self) -> TlvType {
+    /// Create a TLV of a reserved type.
+    ///
+    /// Returns an error if `tlv_type` is not one of the [RESERVED_TLV_TYPES] or `value` is too long for the TLV length
+    /// field.
+    pub fn try_unknown(tlv_type: u8, value: Vec<u8>) -> Result<Tlv, LldpError> {
+        check_unknown(tlv_type, &value)?;
+
+        Ok(Tlv::Unknown { tlv_type, value })
+    }
+
+    /// Get the raw type of the TLV as transmitted in the TLV header.
+    pub fn type_code(&self) -> u8 {
         match self {
-            Tlv::ChassisId(value) => value.tlv_type,
-            Tlv::EndOfLldpdu(value) => value.tlv_type,
-            Tlv::ManagementAddress(value) => value.tlv_type,
-            Tlv::OrganizationallySpecific(value) => value.tlv_type,
-            Tlv::PortId(value) => value.tlv_type,
-            Tlv::PortDescription(value) => value.tlv_type,
-            Tlv::SystemDescription(value) => value.tlv_type,
-            Tlv::SystemName(value) => value.tlv_type,
-            Tlv::SystemCapabilities(value) => value.tlv_type,
-            Tlv::Ttl(value) => value.tlv_type,
+            Tlv::Unknown { tlv_type, .. } => *tlv_type,
+            tlv => tlv.get_type() as u8,
         }
     }
 
@@ -130,6 +439,7 @@ impl Tlv {
     ///
     /// Consider the following TLV:
     ///
+    /// ```text
     ///      0                   1                   2                   3
     ///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
     ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
@@ -137,61 +447,176 @@ impl Tlv {
     ///     |     0x3     |       0x2       |            0x003c             |
     ///     |             |                 |                               |
     ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+    /// ```
     ///
     /// When called on this TLV, this method should return `b"\x06\x02\x00\x3c".to_vec()`.
     pub fn bytes(&self) -> Vec<u8> {
-        match self {
-            Tlv::ChassisId(value) => value.bytes(),
-            Tlv::EndOfLldpdu(value) => value.bytes(),
-            Tlv::ManagementAddress(value) => value.bytes(),
-            Tlv::OrganizationallySpecific(value) => value.bytes(),
-            Tlv::PortId(value) => value.bytes(),
-            Tlv::PortDescription(value) => value.bytes(),
-            Tlv::SystemDescription(value) => value.bytes(),
-            Tlv::SystemName(value) => value.bytes(),
-            Tlv::SystemCapabilities(value) => value.bytes(),
-            Tlv::Ttl(value) => value.bytes(),
-        }
+        let mut result = Vec::with_capacity(self.encoded_len());
+        self.encode(&mut result);
+
+        result
     }
 
     /// Get the length of a packed TLV.
     ///
-    /// Extracts the relevant bytes from "data" and returns them.
+    /// Extracts the relevant bytes from "data" and returns the size of the TLV including its header.
+    ///
+    /// Panics if "bytes" is shorter than the TLV header.
     pub fn get_length(bytes: &[u8]) -> u16 {
-        let mut length = bytes[1] as u16;
-        if bytes[0] & 1 == 1 {
-            length += 1 << 9;
-        }
+        let header = TlvHeader::parse(bytes).unwrap_or_else(|e| panic!("{}", e));
 
-        length + 2
+        header.size() as u16
     }
 
-    ///Create a Tlv instance from raw bytes.
+    /// Create a Tlv instance from raw bytes.
     ///
     /// Reads the TLV Type of "bytes" and calls the from_bytes() method of the corresponding TLV subclass.
     ///
-    /// Panics if the provided TLV is of unknown type. Apart from that validity checks are left to the
-    /// subclass.
+    /// Panics if the provided TLV contains errors.
+    /// Use [Tlv::try_from_bytes] to handle malformed input.
     pub fn from_bytes(bytes: &[u8]) -> Tlv {
-        let mut type_field = bytes[0] & 0b11111110;
-        type_field = type_field >> 1;
-
-        let type_field = match TlvType::try_from(type_field) {
-            Ok(type_field) => type_field,
-            Err(_) => panic!("Invalid TypeField"),
-        };
-
-        match type_field {
-            TlvType::EndOfLLDPDU => Tlv::EndOfLldpdu(EndOfLLDPDUTLV::new_from_bytes(bytes)),
-            TlvType::ChassisId => Tlv::ChassisId(ChassisIdTLV::new_from_bytes(bytes)),
-            TlvType::PortId => Tlv::PortId(PortIdTLV::new_from_bytes(bytes)),
-            TlvType::Ttl => Tlv::Ttl(TtlTLV::new_from_bytes(bytes)),
-            TlvType::PortDescription => Tlv::PortDescription(PortDescriptionTLV::new_from_bytes(bytes)),
-            TlvType::SystemName => Tlv::SystemName(SystemNameTLV::new_from_bytes(bytes)),
-            TlvType::SystemDescription => Tlv::SystemDescription(SystemDescriptionTLV::new_from_bytes(bytes)),
-            TlvType::SystemCapabilities => Tlv::SystemCapabilities(SystemCapabilitiesTLV::new_from_bytes(bytes)),
-            TlvType::ManagementAddress => Tlv::ManagementAddress(ManagementAddressTLV::new_from_bytes(bytes)),
-            TlvType::OrganizationallySpecific => Tlv::OrganizationallySpecific(OrganizationallySpecificTLV::new_from_bytes(bytes)),
+        Tlv::try_from_bytes(bytes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create a Tlv instance from raw bytes.
+    ///
+    /// Reads the TLV Type of "bytes" and decodes the TLV using the [TlvCodec] of the corresponding TLV.
+    ///
+    /// TLVs of a reserved type are returned as [Tlv::Unknown]. Validity checks are left to the TLV.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Tlv, LldpError> {
+        let header = TlvHeader::parse(bytes)?;
+
+        match TlvType::try_from(header.tlv_type) {
+            Ok(tlv_type) => Tlv::decode_defined(tlv_type, bytes),
+            Err(_) => Ok(Tlv::Unknown {
+                tlv_type: header.tlv_type,
+                value: header.value(bytes)?.to_vec(),
+            }),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_dump() {
+        assert_eq!(TlvHeader::new(3, 2).bytes(), [0x06, 0x02]);
+        assert_eq!(TlvHeader::new(6, 255).bytes(), [0x0C, 0xFF]);
+        assert_eq!(TlvHeader::new(6, 256).bytes(), [0x0D, 0x00]);
+        assert_eq!(TlvHeader::new(127, 511).bytes(), [0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn test_header_load() {
+        assert_eq!(TlvHeader::parse(&[0x06, 0x02]), Ok(TlvHeader::new(3, 2)));
+        assert_eq!(TlvHeader::parse(&[0x0D, 0x2C]), Ok(TlvHeader::new(6, 300)));
+        assert_eq!(
+            TlvHeader::parse(&[0xFF, 0xFF]),
+            Ok(TlvHeader::new(127, 511))
+        );
+        assert_eq!(
+            TlvHeader::parse(&[0x06]),
+            Err(LldpError::Truncated {
+                needed: 2,
+                available: 1
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_header_length_overflow() {
+        TlvHeader::new(6, 512).bytes();
+    }
+
+    #[test]
+    fn test_get_length() {
+        assert_eq!(Tlv::get_length(b"\x06\x02\x00\x3c"), 4);
+        assert_eq!(Tlv::get_length(b"\xFF\xFF"), 513);
+    }
+
+    #[test]
+    fn test_unknown_tlv() {
+        let bytes = b"\x12\x03\x01\x0A\xFF";
+
+        let tlv = Tlv::from_bytes(bytes);
+        match &tlv {
+            Tlv::Unknown { tlv_type, value } => {
+                assert_eq!(*tlv_type, 9);
+                assert_eq!(value, &vec![0x01, 0x0A, 0xFF]);
+            }
+            tlv => panic!("expected Unknown, got {}", tlv),
+        }
+        assert_eq!(tlv.get_type(), TlvType::Unknown);
+        assert_eq!(tlv.type_code(), 9);
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+        assert_eq!(format!("{}", tlv), "UnknownTLV(9, \"010AFF\")");
+    }
+
+    #[test]
+    fn test_try_unknown() {
+        let tlv = Tlv::try_unknown(126, vec![0xBE, 0xEF]).unwrap();
+        assert_eq!(tlv.bytes(), b"\xFC\x02\xBE\xEF");
+
+        assert_eq!(
+            Tlv::try_unknown(1, vec![]).unwrap_err(),
+            (LldpError::WrongType {
+                expected: TlvType::Unknown,
+                found: 1
+            })
+        );
+        assert!(Tlv::try_unknown(127, vec![]).is_err());
+        assert!(Tlv::try_unknown(200, vec![]).is_err());
+        assert_eq!(
+            Tlv::try_unknown(9, vec![0; 512]).unwrap_err(),
+            (LldpError::BadLength {
+                tlv_type: TlvType::Unknown,
+                length: 512
+            })
+        );
+    }
+
+    #[test]
+    fn test_long_tlv_roundtrip() {
+        let mut bytes = vec![0x0D, 0x2C];
+        bytes.extend_from_slice(&[b'x'; 300]);
+
+        let tlv = Tlv::from_bytes(&bytes);
+        match &tlv {
+            Tlv::SystemDescription(tlv) => assert_eq!(tlv.value.len(), 300),
+            tlv => panic!("expected SystemDescription, got {}", tlv),
+        }
+        assert_eq!(tlv.bytes(), bytes);
+    }
+
+    #[test]
+    fn test_encode() {
+        let tlvs = [
+            Tlv::from_bytes(b"\x06\x02\x00\x3c"),
+            Tlv::from_bytes(b"\x02\x07\x04\xc8\xbc\xc8\x94\x92\xca"),
+            Tlv::from_bytes(b"\xFE\x06\xAA\xBB\xCC\x1A42"),
+            Tlv::from_bytes(b"\x12\x02\xBE\xEF"),
+        ];
+
+        let mut buf = bytes::BytesMut::new();
+        for tlv in &tlvs {
+            let before = buf.len();
+            tlv.encode(&mut buf);
+            assert_eq!(buf.len() - before, tlv.encoded_len());
+            assert_eq!(&buf[before..], tlv.bytes().as_slice());
+        }
+    }
+
+    #[test]
+    fn test_codec() {
+        let ttl = TtlTLV::new(60);
+        assert_eq!(TtlTLV::TLV_TYPE, TlvType::Ttl);
+        assert_eq!(ttl.encoded_len(), 4);
+
+        let tlv = Tlv::from(ttl);
+        assert_eq!(tlv.get_type(), TlvType::Ttl);
+        assert_eq!(tlv.bytes(), b"\x06\x02\x00\x3c");
+    }
+}
@@ -0,0 +1,100 @@
+use std::convert::TryFrom;
+
+/// Address family numbers
+///
+/// Network addresses in Chassis ID, Port ID and Management Address TLVs are prefixed with a one byte address family
+/// number as assigned by IANA. This enum names the common families, all other numbers are valid as well.
+///
+/// The full list of registered address families is available at:
+/// <https://www.iana.org/assignments/address-family-numbers/address-family-numbers.xhtml>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressFamily {
+    /// IP version 4
+    Ipv4 = 1,
+    /// IP version 6
+    Ipv6 = 2,
+    /// NSAP
+    Nsap = 3,
+    /// HDLC (8-bit multidrop)
+    Hdlc = 4,
+    /// BBN 1822
+    Bbn1822 = 5,
+    /// 802 (includes all 802 media plus Ethernet "canonical format")
+    Ieee802 = 6,
+    /// E.163
+    E163 = 7,
+    /// E.164 (SMDS, Frame Relay, ATM)
+    E164 = 8,
+    /// F.69 (Telex)
+    F69 = 9,
+    /// X.121 (X.25, Frame Relay)
+    X121 = 10,
+    /// IPX
+    Ipx = 11,
+    /// Appletalk
+    Appletalk = 12,
+    /// Decnet IV
+    DecnetIv = 13,
+    /// Banyan Vines
+    BanyanVines = 14,
+    /// E.164 with NSAP format subaddress
+    E164Nsap = 15,
+    /// DNS (Domain Name System)
+    Dns = 16,
+    /// Distinguished Name
+    DistinguishedName = 17,
+    /// AS Number
+    AsNumber = 18,
+}
+
+impl AddressFamily {
+    /// Check if `family` is a valid address family number, i.e. not reserved
+    pub fn is_valid(family: u8) -> bool {
+        family != 0 && family != 255
+    }
+}
+
+impl TryFrom<u8> for AddressFamily {
+    type Error = ();
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            x if x == AddressFamily::Ipv4 as u8 => Ok(AddressFamily::Ipv4),
+            x if x == AddressFamily::Ipv6 as u8 => Ok(AddressFamily::Ipv6),
+            x if x == AddressFamily::Nsap as u8 => Ok(AddressFamily::Nsap),
+            x if x == AddressFamily::Hdlc as u8 => Ok(AddressFamily::Hdlc),
+            x if x == AddressFamily::Bbn1822 as u8 => Ok(AddressFamily::Bbn1822),
+            x if x == AddressFamily::Ieee802 as u8 => Ok(AddressFamily::Ieee802),
+            x if x == AddressFamily::E163 as u8 => Ok(AddressFamily::E163),
+            x if x == AddressFamily::E164 as u8 => Ok(AddressFamily::E164),
+            x if x == AddressFamily::F69 as u8 => Ok(AddressFamily::F69),
+            x if x == AddressFamily::X121 as u8 => Ok(AddressFamily::X121),
+            x if x == AddressFamily::Ipx as u8 => Ok(AddressFamily::Ipx),
+            x if x == AddressFamily::Appletalk as u8 => Ok(AddressFamily::Appletalk),
+            x if x == AddressFamily::DecnetIv as u8 => Ok(AddressFamily::DecnetIv),
+            x if x == AddressFamily::BanyanVines as u8 => Ok(AddressFamily::BanyanVines),
+            x if x == AddressFamily::E164Nsap as u8 => Ok(AddressFamily::E164Nsap),
+            x if x == AddressFamily::Dns as u8 => Ok(AddressFamily::Dns),
+            x if x == AddressFamily::DistinguishedName as u8 => {
+                Ok(AddressFamily::DistinguishedName)
+            }
+            x if x == AddressFamily::AsNumber as u8 => Ok(AddressFamily::AsNumber),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_family() {
+        assert_eq!(AddressFamily::try_from(6), Ok(AddressFamily::Ieee802));
+        assert_eq!(AddressFamily::try_from(16), Ok(AddressFamily::Dns));
+        assert_eq!(AddressFamily::try_from(200), Err(()));
+        assert!(AddressFamily::is_valid(200));
+        assert!(!AddressFamily::is_valid(0));
+        assert!(!AddressFamily::is_valid(255));
+    }
+}
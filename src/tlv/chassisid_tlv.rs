@@ -1,10 +1,15 @@
-use crate::tlv::TlvType;
+use crate::error::LldpError;
+use crate::tlv::address_family::AddressFamily;
+use crate::tlv::mac_address::{Eui64, MacAddress};
+use crate::tlv::{check_len, TlvCodec, TlvType, MAX_STRING_LEN};
+use bytes::BufMut;
 
 use std::convert::{TryFrom, TryInto};
 use std::fmt::Display;
 use std::net::IpAddr;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChassisIdSubType {
     ChassisComponent = 1,
     InterfaceAlias = 2,
@@ -39,9 +44,18 @@ impl TryFrom<u8> for ChassisIdSubType {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChassisIdValue {
-    Mac(Vec<u8>),
+    Mac(MacAddress),
+    /// EUI-64 transmitted with the MAC address subtype
+    Eui64(Eui64),
     IpAddress(IpAddr),
+    /// Network address of any family other than IPv4 and IPv6, see [AddressFamily]
+    NetworkAddress {
+        family: u8,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::hex"))]
+        bytes: Vec<u8>,
+    },
     Other(String),
 }
 /// Chassis ID TLV
@@ -53,6 +67,7 @@ pub enum ChassisIdValue {
 ///
 /// # TLV Format:
 ///
+/// ```text
 ///      0                   1                   2
 ///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+
@@ -62,6 +77,7 @@ pub enum ChassisIdValue {
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+
 ///
 ///                                                        1 - 255 byte
+/// ```
 ///
 /// # Subtypes:
 ///
@@ -100,32 +116,46 @@ pub enum ChassisIdValue {
 ///  To determine the type of network protocol and the appropriate length of the network address transmitted in the
 ///  Chassis ID TLV, network addresses are prefixed with an extra byte identifying the address family.
 ///
-///  For this implementation we only consider IPv4 and IPv6.
+///  IPv4 and IPv6 addresses are represented as [IpAddr], addresses of any other family as raw bytes together with
+///  their family number. The common family numbers are named by [AddressFamily].
 ///
 /// | Protocol | Family Number |
 /// | -------- | ------------- |
 /// |   IPv4   |             1 |
 /// |   IPv6   |             2 |
+/// |   802    |             6 |
+/// |   DNS    |            16 |
 ///
+/// ```text
 ///     Examples (Address -> Bytes -> Prefixed Bytes):
 ///         134.96.86.110  ->  b"\x86\x60\x56\x6E"  -> b"\x01\x86\x60\x56\x6E"
 ///
 ///         20db::1        ->  b"\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
 ///                        ->  b"\x02\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
+/// ```
 ///
 ///  The full list of registered protocol families is available at:
 ///  <https://www.iana.org/assignments/address-family-numbers/address-family-numbers.xhtml>
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "crate::serialization::ChassisIdFields")
+)]
 pub struct ChassisIdTLV {
     /// The type of the TLV
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "crate::serialization::tlv_type::<ChassisIdTLV>")
+    )]
     pub tlv_type: TlvType,
     /// The chassis ID subtype
     pub subtype: ChassisIdSubType,
     /// The chassis ID
     ///
     /// The type of this field depends on the subtype
-    /// * MAC Address -> `ChassisIdValue::Mac(Vec<u8>)`,
-    /// * Network Address -> `ChassisIdValue::IpAddress(IpAddr)`,
+    /// * MAC Address -> `ChassisIdValue::Mac(MacAddress)` or `ChassisIdValue::Eui64(Eui64)`,
+    /// * Network Address -> `ChassisIdValue::IpAddress(IpAddr)` or `ChassisIdValue::NetworkAddress`,
     /// * Otherwise -> `ChassisIdValue::Other(String)`
     pub value: ChassisIdValue,
 }
@@ -134,18 +164,17 @@ impl Display for ChassisIdTLV {
     /// Write a printable representation of the TLV object.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let value = match &self.value {
-            ChassisIdValue::Mac(mac) => {
-                let mut result = String::new();
-                for (index, i) in mac.iter().enumerate() {
-                    result.push_str(&format!("{:X}", i));
-                    if index != mac.len() - 1 {
-                        result.push_str(&":");
-                    }
+            ChassisIdValue::Mac(mac) => mac.to_string(),
+            ChassisIdValue::Eui64(eui) => eui.to_string(),
+            ChassisIdValue::Other(s) => s.clone(),
+            ChassisIdValue::IpAddress(addr) => addr.to_string(),
+            ChassisIdValue::NetworkAddress { family, bytes } => {
+                let mut result = format!("{}:", family);
+                for i in bytes {
+                    result.push_str(&format!("{:02X}", i));
                 }
                 result
             }
-            ChassisIdValue::Other(s) => s.clone(),
-            ChassisIdValue::IpAddress(addr) => addr.to_string(),
         };
 
         write!(
@@ -161,120 +190,147 @@ impl ChassisIdTLV {
     /// Constructor
     ///
     /// The type of the `id` argument depends on the subtype
-    /// * MAC Address -> `ChassisIdValue::Mac(Vec<u8>)`,
-    /// * Network Address -> `ChassisIdValue::IpAddress(IpAddr)`,
+    /// * MAC Address -> `ChassisIdValue::Mac(MacAddress)` or `ChassisIdValue::Eui64(Eui64)`,
+    /// * Network Address -> `ChassisIdValue::IpAddress(IpAddr)` or `ChassisIdValue::NetworkAddress`,
     /// * Otherwise -> `ChassisIdValue::Other(String)`
     pub fn new(subtype: ChassisIdSubType, id: ChassisIdValue) -> ChassisIdTLV {
         // TODO: Implement
         ChassisIdTLV {
             tlv_type: TlvType::ChassisId,
-            subtype: subtype,
+            subtype,
             value: id,
         }
     }
 
-    /// Create a TLV instance from raw bytes.
+    /// Create a Chassis ID TLV, checking the ID.
     ///
-    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
-    pub fn new_from_bytes(bytes: &[u8]) -> ChassisIdTLV {
-        let mut type_field = bytes[0] & 0b11111110;
-        type_field = type_field >> 1;
+    /// Returns an error if the ID is empty or longer than 255 bytes, is of a different kind than the subtype requires
+    /// (see [ChassisIdTLV::new]) or cannot be decoded again, e.g. a network address of an unknown family.
+    pub fn try_new(
+        subtype: ChassisIdSubType,
+        id: ChassisIdValue,
+    ) -> Result<ChassisIdTLV, LldpError> {
+        let tlv = check_len(ChassisIdTLV::new(subtype, id), 2..=MAX_STRING_LEN + 1)?;
+
+        let matches = match tlv.subtype {
+            ChassisIdSubType::MacAddress => {
+                matches!(tlv.value, ChassisIdValue::Mac(_) | ChassisIdValue::Eui64(_))
+            }
+            ChassisIdSubType::NetworkAddress => matches!(
+                tlv.value,
+                ChassisIdValue::IpAddress(_) | ChassisIdValue::NetworkAddress { .. }
+            ),
+            _ => matches!(tlv.value, ChassisIdValue::Other(_)),
+        };
+        if !matches {
+            return Err(LldpError::SubtypeMismatch {
+                tlv_type: TlvType::ChassisId,
+                subtype: tlv.subtype.clone() as u8,
+            });
+        }
+
+        ChassisIdTLV::try_from_bytes(&tlv.bytes())?;
+        Ok(tlv)
+    }
+}
 
-        if type_field != TlvType::ChassisId as u8 {
-            panic!("Wrong TLV Type for ChassisId_Tlv");
+impl TlvCodec for ChassisIdTLV {
+    const TLV_TYPE: TlvType = TlvType::ChassisId;
+
+    fn len(&self) -> usize {
+        1 + match &self.value {
+            ChassisIdValue::Mac(_) => 6,
+            ChassisIdValue::Eui64(_) => 8,
+            ChassisIdValue::Other(s) => s.len(),
+            ChassisIdValue::IpAddress(IpAddr::V4(_)) => 4 + 1,
+            ChassisIdValue::IpAddress(IpAddr::V6(_)) => 16 + 1,
+            ChassisIdValue::NetworkAddress { bytes, .. } => 1 + bytes.len(),
         }
+    }
 
-        let mut length = bytes[1] as usize;
-        if bytes[0] & 1 == 1 {
-            length += 1 << 9;
+    fn encode_value(&self, buf: &mut impl BufMut) {
+        buf.put_u8(self.subtype.clone() as u8);
+
+        match &self.value {
+            ChassisIdValue::Mac(mac) => buf.put_slice(&mac.octets()),
+            ChassisIdValue::Eui64(eui) => buf.put_slice(&eui.octets()),
+            ChassisIdValue::Other(value) => buf.put_slice(value.as_bytes()),
+            ChassisIdValue::IpAddress(IpAddr::V4(address)) => {
+                buf.put_u8(1);
+                buf.put_slice(&address.octets());
+            }
+            ChassisIdValue::IpAddress(IpAddr::V6(address)) => {
+                buf.put_u8(2);
+                buf.put_slice(&address.octets());
+            }
+            ChassisIdValue::NetworkAddress { family, bytes } => {
+                buf.put_u8(*family);
+                buf.put_slice(bytes);
+            }
         }
+    }
 
-        assert_eq!(length, bytes[2..].len());
+    fn decode_value(value: &[u8]) -> Result<ChassisIdTLV, LldpError> {
+        let length = value.len();
 
-        let subtype = bytes[2];
+        if length < 2 {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::ChassisId,
+                length,
+            });
+        }
 
-        let subtype = match ChassisIdSubType::try_from(subtype) {
+        let subtype = match ChassisIdSubType::try_from(value[0]) {
             Ok(subtype) => subtype,
-            Err(_) => panic!("Invalid ChassisSubtype"),
+            Err(_) => {
+                return Err(LldpError::UnknownSubtype {
+                    tlv_type: TlvType::ChassisId,
+                    subtype: value[0],
+                })
+            }
+        };
+
+        let id = &value[1..];
+        let bad_length = LldpError::BadLength {
+            tlv_type: TlvType::ChassisId,
+            length,
         };
 
         let value = match subtype {
             ChassisIdSubType::MacAddress => {
-                assert_eq!(6, bytes[3..].len());
-                ChassisIdValue::Mac(bytes[3..].to_vec())
+                if let Ok(mac) = <[u8; 6]>::try_from(id) {
+                    ChassisIdValue::Mac(MacAddress::new(mac))
+                } else if let Ok(eui) = <[u8; 8]>::try_from(id) {
+                    ChassisIdValue::Eui64(Eui64::new(eui))
+                } else {
+                    return Err(bad_length);
+                }
             }
-            ChassisIdSubType::NetworkAddress => match bytes[3] {
+            ChassisIdSubType::NetworkAddress => match id[0] {
                 1u8 => {
-                    assert_eq!(4, bytes[4..].len());
-                    let addr: [u8; 4] = bytes[4..8].try_into().unwrap();
+                    let addr: [u8; 4] = id[1..].try_into().map_err(|_| bad_length)?;
                     ChassisIdValue::IpAddress(IpAddr::from(addr))
                 }
                 2u8 => {
-                    assert_eq!(16, bytes[4..].len());
-                    let addr: [u8; 16] = bytes[4..20].try_into().unwrap();
+                    let addr: [u8; 16] = id[1..].try_into().map_err(|_| bad_length)?;
                     ChassisIdValue::IpAddress(IpAddr::from(addr))
                 }
-                _ => panic!("Expected IP Address specifier"),
+                family if !AddressFamily::is_valid(family) => {
+                    return Err(LldpError::UnknownAddressFamily(family))
+                }
+                _ if id.len() < 2 => return Err(bad_length),
+                family => ChassisIdValue::NetworkAddress {
+                    family,
+                    bytes: id[1..].to_vec(),
+                },
             },
-            _ => match String::from_utf8(bytes[3..].to_vec()) {
+            _ => match String::from_utf8(id.to_vec()) {
                 Ok(value) => ChassisIdValue::Other(value),
-                Err(_) => panic!("Invlaid value for Chasis::Other type "),
+                Err(_) => return Err(LldpError::InvalidUtf8(TlvType::ChassisId)),
             },
         };
 
-        ChassisIdTLV {
-            tlv_type: TlvType::ChassisId,
-            subtype: subtype,
-            value: value,
-        }
-    }
-
-    /// Return the length of the TLV value
-    pub fn len(&self) -> usize {
-        1 + match &self.value {
-            ChassisIdValue::Mac(_) => 6,
-            ChassisIdValue::Other(s) => s.len(),
-            ChassisIdValue::IpAddress(IpAddr::V4(_)) => 4 + 1,
-            ChassisIdValue::IpAddress(IpAddr::V6(_)) => 16 + 1,
-        }
-    }
-
-    /// Return the byte representation of the TLV.
-    pub fn bytes(&self) -> Vec<u8> {
-        let mut type_field = (self.tlv_type as u8) << 1;
-
-        let length_field = self.len();
-        if length_field & (1 << 9) == 1 {
-            type_field |= 1;
-        }
-
-        let length_field = length_field as u8;
-
-        let mut result: Vec<u8> = Vec::new();
-        result.push(type_field);
-        result.push(length_field);
-
-        let subtype_field = self.subtype.clone() as u8;
-        result.push(subtype_field);
-
-        let value_field = match &self.value {
-            ChassisIdValue::Mac(addr) => addr.clone(),
-            ChassisIdValue::Other(value) => value.as_bytes().to_vec(),
-            ChassisIdValue::IpAddress(IpAddr::V4(address)) => address.octets().to_vec(),
-            ChassisIdValue::IpAddress(IpAddr::V6(address)) => address.octets().to_vec(),
-        };
-
-        if let ChassisIdValue::IpAddress(IpAddr::V4(_)) = self.value {
-            result.push(1);
-        }
-
-        if let ChassisIdValue::IpAddress(IpAddr::V6(_)) = self.value {
-            result.push(2);
-        }
-
-        result.extend_from_slice(&value_field);
-
-        result
+        Ok(ChassisIdTLV::new(subtype, value))
     }
 }
 
@@ -357,7 +413,7 @@ mod tests {
     fn test_mac_subtype_dump() {
         let tlv = ChassisIdTLV::new(
             ChassisIdSubType::MacAddress,
-            ChassisIdValue::Mac(b"\x00\x22\x12\xAA\xBB\xCC".to_vec()),
+            ChassisIdValue::Mac(MacAddress::new([0x00, 0x22, 0x12, 0xAA, 0xBB, 0xCC])),
         );
         assert_eq!(
             tlv.bytes(),
@@ -370,7 +426,7 @@ mod tests {
         let tlv = ChassisIdTLV::new_from_bytes(b"\x02\x07\x04\x00\x22\x12\xAA\xBB\xCC".as_ref());
         assert_eq!(tlv.subtype as u8, ChassisIdSubType::MacAddress as u8);
         match tlv.value {
-            ChassisIdValue::Mac(mac) => assert_eq!(mac, b"\x00\x22\x12\xAA\xBB\xCC".to_vec()),
+            ChassisIdValue::Mac(mac) => assert_eq!(mac.octets(), *b"\x00\x22\x12\xAA\xBB\xCC"),
             v => panic!("expected MAC, got {:?}", v),
         }
     }
@@ -427,11 +483,31 @@ mod tests {
         let tlv = ChassisIdTLV::new_from_bytes(b"\x02\x07\x04\xc8\xbc\xc8\x94\x92\xca".as_ref());
         assert_eq!(tlv.subtype as u8, ChassisIdSubType::MacAddress as u8);
         match tlv.value {
-            ChassisIdValue::Mac(mac) => assert_eq!(mac, b"\xc8\xbc\xc8\x94\x92\xca".to_vec()),
+            ChassisIdValue::Mac(mac) => assert_eq!(mac.octets(), *b"\xc8\xbc\xc8\x94\x92\xca"),
             v => panic!("expected MAC, got {:?}", v),
         }
     }
 
+    #[test]
+    fn test_load_eui64() {
+        let bytes = b"\x02\x09\x04\x02\x04\xdf\xff\xfe\x88\xa2\xb4";
+        let tlv = ChassisIdTLV::new_from_bytes(bytes.as_ref());
+        assert_eq!(
+            tlv.value,
+            ChassisIdValue::Eui64(Eui64::new([0x02, 0x04, 0xdf, 0xff, 0xfe, 0x88, 0xa2, 0xb4]))
+        );
+        assert_eq!(
+            tlv.to_string(),
+            "ChassisIdTLV(4, \"02:04:df:ff:fe:88:a2:b4\")"
+        );
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+
+        assert!(
+            ChassisIdTLV::try_from_bytes(b"\x02\x08\x04\x02\x04\xdf\xff\xfe\x88\xa2".as_ref())
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_load_ipv4() {
         let tlv = ChassisIdTLV::new_from_bytes(b"\x02\x06\x05\x01\xc0\x00\x02\x0e".as_ref());
@@ -466,6 +542,36 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_load_network_address() {
+        let tlv =
+            ChassisIdTLV::new_from_bytes(b"\x02\x08\x05\x06\x02\x04\xdf\x88\xa2\xb4".as_ref());
+        assert_eq!(
+            tlv.value,
+            ChassisIdValue::NetworkAddress {
+                family: AddressFamily::Ieee802 as u8,
+                bytes: b"\x02\x04\xdf\x88\xa2\xb4".to_vec()
+            }
+        );
+        assert_eq!(
+            tlv.bytes(),
+            b"\x02\x08\x05\x06\x02\x04\xdf\x88\xa2\xb4".to_vec()
+        );
+        assert_eq!(format!("{}", tlv), "ChassisIdTLV(5, \"6:0204DF88A2B4\")");
+
+        assert_eq!(
+            ChassisIdTLV::try_from_bytes(b"\x02\x03\x05\xff\x00".as_ref()).unwrap_err(),
+            LldpError::UnknownAddressFamily(255)
+        );
+        assert_eq!(
+            ChassisIdTLV::try_from_bytes(b"\x02\x02\x05\x10".as_ref()).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ChassisId,
+                length: 2
+            }
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_load_invalid_generic() {
@@ -481,15 +587,43 @@ mod tests {
     #[test]
     #[should_panic]
     fn test_load_invalid_ipv4() {
-        ChassisIdTLV::new_from_bytes(b"\x02\x04\x05\xc0\x00\x02".as_ref());
+        ChassisIdTLV::new_from_bytes(b"\x02\x05\x05\x01\xc0\x00\x02".as_ref());
     }
 
     #[test]
     #[should_panic]
     fn test_load_invalid_ipv6() {
         ChassisIdTLV::new_from_bytes(
-            b"\x02\x10\x05\x20\x01\x00\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\xff\x00".as_ref(),
+            b"\x02\x11\x05\x02\x20\x01\x00\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\xff\x00"
+                .as_ref(),
+        );
+    }
+
+    #[test]
+    fn test_try_load_invalid() {
+        assert_eq!(
+            ChassisIdTLV::try_from_bytes(b"\x02\x08\x04\xc8\xbc\xc8\x94\x92\xca\x11".as_ref())
+                .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ChassisId,
+                length: 8
+            }
         );
+        assert_eq!(
+            ChassisIdTLV::try_from_bytes(b"\x02\x02\x00X".as_ref()).unwrap_err(),
+            LldpError::UnknownSubtype {
+                tlv_type: TlvType::ChassisId,
+                subtype: 0
+            }
+        );
+        assert_eq!(
+            ChassisIdTLV::try_from_bytes(b"\x02\x03\x07\xff\xfe".as_ref()).unwrap_err(),
+            LldpError::InvalidUtf8(TlvType::ChassisId)
+        );
+        assert!(matches!(
+            ChassisIdTLV::try_from_bytes(b"\x04\x02\x07X".as_ref()),
+            Err(LldpError::WrongType { found: 2, .. })
+        ));
     }
 
     #[test]
@@ -506,7 +640,7 @@ mod tests {
     fn test_display2() {
         let tlv = ChassisIdTLV::new(
             ChassisIdSubType::MacAddress,
-            ChassisIdValue::Mac(vec![0x66, 0x6F, 0x6F, 0x62, 0x61, 0x72]),
+            ChassisIdValue::Mac(MacAddress::new([0x66, 0x6F, 0x6F, 0x62, 0x61, 0x72])),
         );
 
         let s = format!("{}", tlv);
@@ -524,4 +658,77 @@ mod tests {
 
         assert_eq!(format!("{}", tlv), "ChassisIdTLV(5, \"127.0.0.1\")")
     }
+
+    #[test]
+    fn test_try_new() {
+        let tlv = ChassisIdTLV::try_new(
+            ChassisIdSubType::Local,
+            ChassisIdValue::Other("x".repeat(255)),
+        )
+        .unwrap();
+        assert_eq!(tlv.len(), 256);
+
+        assert_eq!(
+            ChassisIdTLV::try_new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other("x".repeat(300))
+            )
+            .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ChassisId,
+                length: 301
+            }
+        );
+        assert_eq!(
+            ChassisIdTLV::try_new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::new())
+            )
+            .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ChassisId,
+                length: 1
+            }
+        );
+
+        // The ID must be of the kind required by the subtype
+        assert_eq!(
+            ChassisIdTLV::try_new(
+                ChassisIdSubType::MacAddress,
+                ChassisIdValue::Other(String::from("abc"))
+            )
+            .unwrap_err(),
+            LldpError::SubtypeMismatch {
+                tlv_type: TlvType::ChassisId,
+                subtype: 4
+            }
+        );
+        assert_eq!(
+            ChassisIdTLV::try_new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Mac(MacAddress::new([0x02, 0, 0, 0, 0, 1]))
+            )
+            .unwrap_err(),
+            LldpError::SubtypeMismatch {
+                tlv_type: TlvType::ChassisId,
+                subtype: 7
+            }
+        );
+        assert_eq!(
+            ChassisIdTLV::try_new(
+                ChassisIdSubType::NetworkAddress,
+                ChassisIdValue::NetworkAddress {
+                    family: 0,
+                    bytes: vec![1, 2, 3]
+                }
+            )
+            .unwrap_err(),
+            LldpError::UnknownAddressFamily(0)
+        );
+        assert!(ChassisIdTLV::try_new(
+            ChassisIdSubType::NetworkAddress,
+            ChassisIdValue::IpAddress(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)))
+        )
+        .is_ok());
+    }
 }
@@ -1,6 +1,8 @@
 use std::fmt::Display;
 
-use crate::tlv::TlvType;
+use crate::error::LldpError;
+use crate::tlv::{TlvCodec, TlvType};
+use bytes::BufMut;
 
 /// End of LLDP Data Unit TLV
 ///
@@ -9,6 +11,7 @@ use crate::tlv::TlvType;
 ///
 /// # TLV Format:
 ///
+/// ```text
 ///      0                   1
 ///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
@@ -16,9 +19,15 @@ use crate::tlv::TlvType;
 ///     |      0      |       0x0       |
 ///     |             |                 |
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EndOfLLDPDUTLV {
     /// The type of the TLV
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "crate::serialization::tlv_type::<EndOfLLDPDUTLV>")
+    )]
     pub tlv_type: TlvType,
 }
 
@@ -30,6 +39,12 @@ impl Display for EndOfLLDPDUTLV {
     }
 }
 
+impl Default for EndOfLLDPDUTLV {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EndOfLLDPDUTLV {
     /// Constructor
     pub fn new() -> EndOfLLDPDUTLV {
@@ -37,28 +52,26 @@ impl EndOfLLDPDUTLV {
             tlv_type: TlvType::EndOfLLDPDU,
         }
     }
+}
 
-    /// Create a TLV instance from raw bytes.
-    ///
-    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
-    pub fn new_from_bytes(bytes: &[u8]) -> EndOfLLDPDUTLV {
-        let mut type_field = bytes[0] & 0b11111110;
-        type_field = type_field >> 1;
+impl TlvCodec for EndOfLLDPDUTLV {
+    const TLV_TYPE: TlvType = TlvType::EndOfLLDPDU;
 
-        if type_field != 0u8 {
-            panic!("Wrong TLV Type for EndOfLLDPDU");
-        }
-        EndOfLLDPDUTLV::new()
+    fn len(&self) -> usize {
+        0
     }
 
-    /// Return the length of the TLV value
-    pub fn len(&self) -> usize {
-        return 0;
-    }
+    fn encode_value(&self, _buf: &mut impl BufMut) {}
+
+    fn decode_value(value: &[u8]) -> Result<EndOfLLDPDUTLV, LldpError> {
+        if !value.is_empty() {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::EndOfLLDPDU,
+                length: value.len(),
+            });
+        }
 
-    /// Return the byte representation of the TLV.
-    pub fn bytes(&self) -> Vec<u8> {
-        vec![0, 0]
+        Ok(EndOfLLDPDUTLV::new())
     }
 }
 
@@ -91,6 +104,18 @@ mod tests {
         assert_eq!(tlv.tlv_type as u8, 0);
     }
 
+    #[test]
+    fn test_eolldpdu_try_load_invalid() {
+        assert!(matches!(
+            EndOfLLDPDUTLV::try_from_bytes(b"\x02\x00".as_ref()),
+            Err(LldpError::WrongType { found: 1, .. })
+        ));
+        assert!(matches!(
+            EndOfLLDPDUTLV::try_from_bytes(b"\x00\x01\x00".as_ref()),
+            Err(LldpError::BadLength { length: 1, .. })
+        ));
+    }
+
     #[test]
     fn test_eolldpdu_display() {
         assert_eq!(format!("{}", EndOfLLDPDUTLV::new()), "EndOfLLDPDUTLV");
@@ -0,0 +1,215 @@
+use crate::error::LldpError;
+use pnet::datalink::MacAddr;
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parse `count` octets written as hex in one of the common notations.
+///
+/// Accepted are octets separated by colons (`02:04:df:88:a2:b4`, leading zeros may be omitted) or dashes
+/// (`02-04-DF-88-A2-B4`) and groups of four hex digits separated by dots as used by Cisco (`0204.df88.a2b4`).
+fn parse_octets(s: &str, count: usize) -> Option<Vec<u8>> {
+    let separator = if s.contains(':') {
+        ':'
+    } else if s.contains('-') {
+        '-'
+    } else {
+        '.'
+    };
+
+    let mut octets = vec![];
+    for group in s.split(separator) {
+        if !group.is_ascii() {
+            return None;
+        }
+        match (separator, group.len()) {
+            (':', 1..=2) | ('-', 1..=2) => octets.push(u8::from_str_radix(group, 16).ok()?),
+            ('.', 4) => {
+                octets.push(u8::from_str_radix(&group[..2], 16).ok()?);
+                octets.push(u8::from_str_radix(&group[2..], 16).ok()?);
+            }
+            _ => return None,
+        }
+    }
+
+    if octets.len() == count {
+        Some(octets)
+    } else {
+        None
+    }
+}
+
+/// Write octets in canonical notation, i.e. lowercase and separated by colons
+fn write_octets(f: &mut std::fmt::Formatter<'_>, octets: &[u8]) -> std::fmt::Result {
+    for (index, octet) in octets.iter().enumerate() {
+        if index != 0 {
+            write!(f, ":")?;
+        }
+        write!(f, "{:02x}", octet)?;
+    }
+
+    Ok(())
+}
+
+/// MAC Address
+///
+/// A 48 bit IEEE 802 MAC address (EUI-48). It converts from and to [pnet::datalink::MacAddr].
+///
+/// MAC addresses can be parsed from the common notations and are displayed in canonical form:
+///
+/// ```
+/// use lldp_rs::tlv::mac_address::MacAddress;
+///
+/// let mac: MacAddress = "0204.DF88.A2B4".parse().unwrap();
+/// assert_eq!(mac, "02-04-df-88-a2-b4".parse().unwrap());
+/// assert_eq!(mac.to_string(), "02:04:df:88:a2:b4");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MacAddress(pub [u8; 6]);
+
+impl MacAddress {
+    /// Constructor
+    pub fn new(octets: [u8; 6]) -> MacAddress {
+        MacAddress(octets)
+    }
+
+    /// Return the octets of the MAC address
+    pub fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+}
+
+impl Display for MacAddress {
+    /// Write the MAC address in canonical form, e.g. `02:04:df:88:a2:b4`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_octets(f, &self.0)
+    }
+}
+
+impl FromStr for MacAddress {
+    type Err = LldpError;
+
+    /// Parse a MAC address in colon, dash or Cisco dotted notation
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let octets = parse_octets(s, 6).ok_or(LldpError::InvalidMacAddress)?;
+        let mut mac = [0; 6];
+        mac.copy_from_slice(&octets);
+
+        Ok(MacAddress(mac))
+    }
+}
+
+impl From<[u8; 6]> for MacAddress {
+    fn from(octets: [u8; 6]) -> Self {
+        MacAddress(octets)
+    }
+}
+
+impl From<MacAddr> for MacAddress {
+    fn from(mac: MacAddr) -> Self {
+        MacAddress(mac.octets())
+    }
+}
+
+impl From<MacAddress> for MacAddr {
+    fn from(mac: MacAddress) -> Self {
+        let [a, b, c, d, e, f] = mac.0;
+        MacAddr::new(a, b, c, d, e, f)
+    }
+}
+
+/// EUI-64
+///
+/// A 64 bit extended unique identifier. Chassis ID and Port ID TLVs of the MAC address subtype may carry an EUI-64
+/// instead of a 48 bit MAC address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Eui64(pub [u8; 8]);
+
+impl Eui64 {
+    /// Constructor
+    pub fn new(octets: [u8; 8]) -> Eui64 {
+        Eui64(octets)
+    }
+
+    /// Return the octets of the EUI-64
+    pub fn octets(&self) -> [u8; 8] {
+        self.0
+    }
+}
+
+impl Display for Eui64 {
+    /// Write the EUI-64 in canonical form, e.g. `02:04:df:ff:fe:88:a2:b4`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_octets(f, &self.0)
+    }
+}
+
+impl FromStr for Eui64 {
+    type Err = LldpError;
+
+    /// Parse an EUI-64 in colon, dash or Cisco dotted notation
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let octets = parse_octets(s, 8).ok_or(LldpError::InvalidMacAddress)?;
+        let mut eui = [0; 8];
+        eui.copy_from_slice(&octets);
+
+        Ok(Eui64(eui))
+    }
+}
+
+impl From<[u8; 8]> for Eui64 {
+    fn from(octets: [u8; 8]) -> Self {
+        Eui64(octets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mac = MacAddress::new([0x02, 0x04, 0xdf, 0x88, 0xa2, 0xb4]);
+        assert_eq!("02:04:df:88:a2:b4".parse(), Ok(mac));
+        assert_eq!("02:04:DF:88:A2:B4".parse(), Ok(mac));
+        assert_eq!("02-04-DF-88-A2-B4".parse(), Ok(mac));
+        assert_eq!("0204.df88.a2b4".parse(), Ok(mac));
+        assert_eq!("2:4:DF:88:A2:B4".parse(), Ok(mac));
+
+        assert_eq!(
+            "0204.df88.a2b".parse::<MacAddress>(),
+            Err(LldpError::InvalidMacAddress)
+        );
+        assert_eq!(
+            "02:04:df:88:a2".parse::<MacAddress>(),
+            Err(LldpError::InvalidMacAddress)
+        );
+        assert_eq!(
+            "02:04-df:88:a2:b4".parse::<MacAddress>(),
+            Err(LldpError::InvalidMacAddress)
+        );
+        assert_eq!(
+            "0204df88a2b4".parse::<MacAddress>(),
+            Err(LldpError::InvalidMacAddress)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let mac = MacAddress::new([0x02, 0x04, 0xdf, 0x88, 0xa2, 0xb4]);
+        assert_eq!(mac.to_string(), "02:04:df:88:a2:b4");
+
+        let eui: Eui64 = "0204.dfff.fe88.a2b4".parse().unwrap();
+        assert_eq!(eui.to_string(), "02:04:df:ff:fe:88:a2:b4");
+    }
+
+    #[test]
+    fn test_pnet() {
+        let mac = MacAddr::new(0x02, 0x04, 0xdf, 0x88, 0xa2, 0xb4);
+        assert_eq!(MacAddr::from(MacAddress::from(mac)), mac);
+        assert_eq!(
+            MacAddress::from(mac).octets(),
+            [0x02, 0x04, 0xdf, 0x88, 0xa2, 0xb4]
+        );
+    }
+}
@@ -1,11 +1,21 @@
-use crate::tlv::TlvType;
+use crate::error::LldpError;
+use crate::tlv::address_family::AddressFamily;
+use crate::tlv::oid::Oid;
+use crate::tlv::{TlvCodec, TlvType};
+use bytes::BufMut;
 
-use bytes::{Buf, BufMut};
 use std::convert::{TryFrom, TryInto};
-use std::fmt::{format, Display};
+use std::fmt::Display;
 use std::net::IpAddr;
 
+/// Maximum length of a management address in bytes, without the address family.
+pub const MAX_ADDRESS_LEN: usize = 31;
+
+/// Maximum length of the BER encoded OID in bytes.
+pub const MAX_OID_LEN: usize = 128;
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IFNumberingSubtype {
     Unknown = 1,
     IfIndex = 2,
@@ -25,6 +35,69 @@ impl TryFrom<u8> for IFNumberingSubtype {
     }
 }
 
+/// Management address
+///
+/// IPv4 and IPv6 addresses are represented as [IpAddr], addresses of any other family as raw bytes together with
+/// their family number, see [AddressFamily].
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum ManagementAddressValue {
+    IpAddress(IpAddr),
+    NetworkAddress {
+        family: u8,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::hex"))]
+        bytes: Vec<u8>,
+    },
+}
+
+impl ManagementAddressValue {
+    /// Get the address family number
+    pub fn family(&self) -> u8 {
+        match self {
+            ManagementAddressValue::IpAddress(IpAddr::V4(_)) => AddressFamily::Ipv4 as u8,
+            ManagementAddressValue::IpAddress(IpAddr::V6(_)) => AddressFamily::Ipv6 as u8,
+            ManagementAddressValue::NetworkAddress { family, .. } => *family,
+        }
+    }
+
+    /// Get the length of the address in bytes, without the address family
+    pub fn len(&self) -> usize {
+        match self {
+            ManagementAddressValue::IpAddress(IpAddr::V4(_)) => 4,
+            ManagementAddressValue::IpAddress(IpAddr::V6(_)) => 16,
+            ManagementAddressValue::NetworkAddress { bytes, .. } => bytes.len(),
+        }
+    }
+
+    /// Check if the address is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl From<IpAddr> for ManagementAddressValue {
+    fn from(address: IpAddr) -> Self {
+        ManagementAddressValue::IpAddress(address)
+    }
+}
+
+impl Display for ManagementAddressValue {
+    /// Write the address, addresses of families other than IPv4 and IPv6 are written as `family:HEX`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManagementAddressValue::IpAddress(address) => write!(f, "{}", address),
+            ManagementAddressValue::NetworkAddress { family, bytes } => {
+                write!(f, "{}:", family)?;
+                for i in bytes {
+                    write!(f, "{:02X}", i)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 /// Management Address TLV
 ///
 /// The Management Address TLV identifies an address associated with the local LLDP agent that may be used to reach
@@ -35,6 +108,7 @@ impl TryFrom<u8> for IFNumberingSubtype {
 ///
 /// # TLV Format:
 ///
+/// ```text
 ///       0               1               2               3               4
 ///      +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+~
 ///      |             |                 |  Management   |  Management   |   Management    |
@@ -48,6 +122,7 @@ impl TryFrom<u8> for IFNumberingSubtype {
 ///      |   Numbering   |    Number     |    Length     |         (0-128 octets)          |
 ///      |    Subtype    |   (4 octets)  |   (1 octet)   |                                 |
 ///     ~+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+.....+-+-+-+-+-+-+-+
+/// ```
 ///
 /// # Management Address Subtype and Management Address String Length:
 ///
@@ -56,12 +131,15 @@ impl TryFrom<u8> for IFNumberingSubtype {
 /// To identify the type of network protocol and length of the network address the TLV includes a management address
 /// subtype and string length. Address lengths are given in bytes.
 ///
-/// For this implementation we only consider IPv4 and IPv6.
+/// Any address family can be used, see [ManagementAddressValue]. The address string length covers the subtype and
+/// the address, so addresses are limited to 31 bytes.
 ///
 /// | Protocol | Subtype |
 /// | -------- | ------- |
 /// |   IPv4   |       1 |
 /// |   IPv6   |       2 |
+/// |   802    |       6 |
+/// |   DNS    |      16 |
 ///
 ///  Example:
 ///  134.96.86.110 is an IPv4 address, so it has a subtype of 1 and it has a length of 4 bytes.
@@ -90,26 +168,47 @@ impl TryFrom<u8> for IFNumberingSubtype {
 /// An OID (Object IDentifier) is a globally unabiguous name for any type of object / thing.
 /// It can be used to e.g. identify the kind of hardware component associated with the management address.
 ///
-/// This implementation will not make use of the OID, but it nevertheless has to be handled properly if included in
-/// a TLV. It does not have to be interpreted.
+/// The OID is kept in its BER encoding, so TLVs are re-encoded unchanged even if their OID is malformed.
+/// [ManagementAddressTLV::object_identifier] decodes it into an [Oid], [ManagementAddressTLV::with_oid] sets it.
 ///
 /// Example:
 ///
-///     let tlv = ManagementAddressTLV::new( "192.2.0.1".parse().unwrap(), 4, IFNumberingSubtype::IF_INDEX, b"\x00\x08\x15".to_vec());
-///     println!("{:?}", tlv.oid);
-///     // Should print:
-///     [0, 8, 21]
+/// ```
+/// use lldp_rs::tlv::managementaddress_tlv::{IFNumberingSubtype, ManagementAddressTLV};
+/// use std::net::IpAddr;
+///
+/// let address: IpAddr = "192.0.2.1".parse().unwrap();
+/// let tlv = ManagementAddressTLV::new(address, 4, IFNumberingSubtype::IfIndex, vec![])
+///     .with_oid(&"1.3.6.1.4.1.311".parse().unwrap())
+///     .unwrap();
+///
+/// assert_eq!(tlv.oid, b"\x2b\x06\x01\x04\x01\x82\x37");
+/// assert_eq!(tlv.object_identifier().unwrap().unwrap().to_string(), "1.3.6.1.4.1.311");
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "crate::serialization::ManagementAddressFields")
+)]
 pub struct ManagementAddressTLV {
     /// The type of the TLV
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip,
+            default = "crate::serialization::tlv_type::<ManagementAddressTLV>"
+        )
+    )]
     pub tlv_type: TlvType,
     /// The interface numbering subtype
     pub subtype: IFNumberingSubtype,
     /// The management address
-    pub value: IpAddr,
+    pub value: ManagementAddressValue,
     /// The interface number
     pub interface_number: u32,
-    /// The object identifier of the device sending the TLV
+    /// The BER encoded object identifier of the device sending the TLV
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::oid"))]
     pub oid: Vec<u8>,
 }
 
@@ -126,142 +225,198 @@ impl Display for ManagementAddressTLV {
         write!(
             f,
             "ManagementAddressTLV(\"{}\", {}, \"{}\")",
-            self.value.to_string(),
-            self.interface_number,
-            oid_string
+            self.value, self.interface_number, oid_string
         )
     }
 }
 
 impl ManagementAddressTLV {
     /// Constructor
+    ///
+    /// Use [ManagementAddressTLV::try_new] to check the length of the address and the OID.
     pub fn new(
-        address: IpAddr,
+        address: impl Into<ManagementAddressValue>,
         interface_number: u32,
         ifsubtype: IFNumberingSubtype,
         oid: Vec<u8>,
     ) -> ManagementAddressTLV {
-        // TODO: Implement
         ManagementAddressTLV {
             tlv_type: TlvType::ManagementAddress,
             subtype: ifsubtype,
-            value: address,
-            interface_number: interface_number,
-            oid: oid,
+            value: address.into(),
+            interface_number,
+            oid,
         }
     }
 
-    /// Create a TLV instance from raw bytes.
+    /// Create a Management Address TLV of any address family, checking the length of the address and the OID.
     ///
-    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
-    pub fn new_from_bytes(bytes: &[u8]) -> ManagementAddressTLV {
-        let mut type_field = bytes[0] & 0b11111110;
-        type_field = type_field >> 1;
-
-        if type_field != TlvType::ManagementAddress as u8 {
-            panic!("Wrong TLV Type for ManagementAddress_Tlv");
-        }
+    /// Returns an error if the address is empty or longer than 31 bytes, or the OID is longer than 128 bytes.
+    pub fn try_new(
+        address: impl Into<ManagementAddressValue>,
+        interface_number: u32,
+        ifsubtype: IFNumberingSubtype,
+        oid: Vec<u8>,
+    ) -> Result<ManagementAddressTLV, LldpError> {
+        let tlv = ManagementAddressTLV::new(address, interface_number, ifsubtype, oid);
+        tlv.check_lengths()?;
 
-        let mut length = bytes[1] as usize;
-        if bytes[0] & 1 == 1 {
-            length += 1 << 9;
-        }
-        assert!(length < 512, "length overflow");
+        Ok(tlv)
+    }
 
-        let mgmt_add_length = bytes[2];
-        let mgmt_add_subtype = bytes[3];
+    /// Set the management address, e.g. to an address of a family other than IPv4 and IPv6
+    ///
+    /// Returns an error if the address is empty or longer than 31 bytes.
+    pub fn with_address(
+        mut self,
+        address: ManagementAddressValue,
+    ) -> Result<ManagementAddressTLV, LldpError> {
+        self.value = address;
+        self.check_lengths()?;
+
+        Ok(self)
+    }
 
-        let address = match mgmt_add_subtype {
-            1u8 => {
-                assert_eq!(mgmt_add_length, 4 + 1);
-                let addr: [u8; 4] = bytes[4..8].try_into().unwrap();
-                IpAddr::from(addr)
-            }
-            2u8 => {
-                assert_eq!(mgmt_add_length, 16 + 1);
-                let addr: [u8; 16] = bytes[4..20].try_into().unwrap();
-                IpAddr::from(addr)
-            }
-            _ => panic!("Unknown ManagementAddressSubtype"),
-        };
+    /// Set the OID
+    ///
+    /// Returns an error if the BER encoding of the OID is longer than 128 bytes.
+    pub fn with_oid(mut self, oid: &Oid) -> Result<ManagementAddressTLV, LldpError> {
+        self.oid = oid.to_ber();
+        self.check_lengths()?;
 
-        let length = (bytes[2] - 1) as usize;
+        Ok(self)
+    }
 
-        let ifsubtype = bytes[4 + length];
-        let ifsubtype = IFNumberingSubtype::try_from(ifsubtype).unwrap();
+    /// Check that the address is 1 - 31 bytes and the OID at most 128 bytes long, so their lengths fit into the
+    /// length fields.
+    pub(crate) fn check_lengths(&self) -> Result<(), LldpError> {
+        if !(1..=MAX_ADDRESS_LEN).contains(&self.value.len()) || self.oid.len() > MAX_OID_LEN {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::ManagementAddress,
+                length: self.len(),
+            });
+        }
 
-        let if_number_bytes = [
-            bytes[5 + length],
-            bytes[6 + length],
-            bytes[7 + length],
-            bytes[8 + length],
-        ];
-        let interface_number = u32::from_be_bytes(if_number_bytes);
+        Ok(())
+    }
 
-        let oid_length = bytes[9 + length] as usize;
-        assert!(oid_length < 129);
+    /// Decode the OID.
+    ///
+    /// Returns `None` if the TLV has no OID and an error if the OID is not valid BER.
+    pub fn object_identifier(&self) -> Option<Result<Oid, LldpError>> {
+        if self.oid.is_empty() {
+            None
+        } else {
+            Some(Oid::from_ber(&self.oid))
+        }
+    }
+}
 
-        let oid = bytes[(10 + length)..(10 + length + oid_length)].to_vec();
+impl TlvCodec for ManagementAddressTLV {
+    const TLV_TYPE: TlvType = TlvType::ManagementAddress;
 
-        ManagementAddressTLV::new(address, interface_number, ifsubtype, oid)
+    fn len(&self) -> usize {
+        8 + self.value.len() + self.oid.len()
     }
 
-    /// Return the length of the TLV value
-    pub fn len(&self) -> usize {
-        8 + self.oid.len() + {
-            if self.value.is_ipv4() {
-                4
-            } else {
-                16
+    /// Encode the TLV value.
+    ///
+    /// The address and the OID must fit into their length fields, see [ManagementAddressTLV::try_new].
+    fn encode_value(&self, buf: &mut impl BufMut) {
+        debug_assert!(
+            self.check_lengths().is_ok(),
+            "management address or OID too long"
+        );
+
+        buf.put_u8(1 + self.value.len() as u8);
+        buf.put_u8(self.value.family());
+
+        match &self.value {
+            ManagementAddressValue::IpAddress(IpAddr::V4(address)) => {
+                buf.put_slice(&address.octets())
             }
+            ManagementAddressValue::IpAddress(IpAddr::V6(address)) => {
+                buf.put_slice(&address.octets())
+            }
+            ManagementAddressValue::NetworkAddress { bytes, .. } => buf.put_slice(bytes),
         }
+
+        buf.put_u8(self.subtype.clone() as u8);
+        buf.put_u32(self.interface_number);
+
+        buf.put_u8(self.oid.len() as u8);
+        buf.put_slice(&self.oid);
     }
 
-    /// Return the byte representation of the TLV.
-    pub fn bytes(&self) -> Vec<u8> {
-        let mut type_field = (self.tlv_type as u8) << 1;
+    fn decode_value(value: &[u8]) -> Result<ManagementAddressTLV, LldpError> {
+        let bad_length = LldpError::BadLength {
+            tlv_type: TlvType::ManagementAddress,
+            length: value.len(),
+        };
 
-        let length_field = self.len();
-        if length_field & (1 << 9) == 1 {
-            type_field |= 1;
+        if value.is_empty() {
+            return Err(bad_length);
         }
 
-        let length_field = length_field as u8;
-
-        let mut result: Vec<u8> = Vec::new();
-        result.push(type_field);
-        result.push(length_field);
+        // The address string (subtype and 1 - 31 byte address) is followed by the interface numbering subtype
+        // (1 byte), the interface number (4 bytes) and the OID string length (1 byte).
+        let mgmt_add_length = value[0] as usize;
+        if !(2..=1 + MAX_ADDRESS_LEN).contains(&mgmt_add_length)
+            || value.len() < 1 + mgmt_add_length + 6
+        {
+            return Err(bad_length);
+        }
 
-        let mgmt_address_length = 1 + if self.value.is_ipv4() { 4 } else { 16 };
-        result.push(mgmt_address_length);
+        let mgmt_add_subtype = value[1];
+        let bytes = &value[2..1 + mgmt_add_length];
 
-        let mgmt_address_subtype = if self.value.is_ipv4() { 1 } else { 2 };
-        result.push(mgmt_address_subtype);
+        let address = match mgmt_add_subtype {
+            1u8 => {
+                let addr: [u8; 4] = bytes.try_into().map_err(|_| bad_length.clone())?;
+                ManagementAddressValue::IpAddress(IpAddr::from(addr))
+            }
+            2u8 => {
+                let addr: [u8; 16] = bytes.try_into().map_err(|_| bad_length.clone())?;
+                ManagementAddressValue::IpAddress(IpAddr::from(addr))
+            }
+            family if AddressFamily::is_valid(family) => ManagementAddressValue::NetworkAddress {
+                family,
+                bytes: bytes.to_vec(),
+            },
+            family => return Err(LldpError::UnknownAddressFamily(family)),
+        };
 
-        if let IpAddr::V4(address) = self.value {
-            result.extend_from_slice(&address.octets());
-        }
-        if let IpAddr::V6(address) = self.value {
-            result.extend_from_slice(&address.octets());
-        }
+        let rest = &value[1 + mgmt_add_length..];
 
-        let ifnumber_subtype = self.subtype.clone() as u8;
-        result.push(ifnumber_subtype);
+        let ifsubtype = match IFNumberingSubtype::try_from(rest[0]) {
+            Ok(ifsubtype) => ifsubtype,
+            Err(_) => {
+                return Err(LldpError::UnknownSubtype {
+                    tlv_type: TlvType::ManagementAddress,
+                    subtype: rest[0],
+                })
+            }
+        };
 
-        let mask: u32 = 0xFF;
-        result.push(((self.interface_number & (mask << 24)) >> 24) as u8);
-        result.push(((self.interface_number & (mask << 16)) >> 16) as u8);
-        result.push(((self.interface_number & (mask << 8)) >> 8) as u8);
-        result.push((self.interface_number & mask) as u8);
+        let interface_number = u32::from_be_bytes([rest[1], rest[2], rest[3], rest[4]]);
 
-        let oid_length = self.oid.len() as u8;
-        result.push(oid_length);
+        let oid_length = rest[5] as usize;
+        if oid_length > MAX_OID_LEN || rest.len() != 6 + oid_length {
+            return Err(bad_length);
+        }
 
-        result.extend_from_slice(&self.oid);
+        let oid = rest[6..].to_vec();
 
-        result
+        Ok(ManagementAddressTLV {
+            tlv_type: TlvType::ManagementAddress,
+            subtype: ifsubtype,
+            value: address,
+            interface_number,
+            oid,
+        })
     }
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,21 +467,17 @@ mod tests {
     fn test_value() {
         let (tlv4, tlv6) = set_up();
         match tlv4.value {
-            IpAddr::V4(ip) => {
+            ManagementAddressValue::IpAddress(IpAddr::V4(ip)) => {
                 assert_eq!(ip.octets(), [192, 0, 2, 100]);
             }
-            IpAddr::V6(_) => {
-                panic!("Expected IPv4, got IPv6 address");
-            }
+            v => panic!("Expected IPv4, got {:?}", v),
         }
         match tlv6.value {
-            IpAddr::V4(_) => {
-                panic!("Expected IPv6, got IPv4 address");
-            }
-            IpAddr::V6(ip) => {
+            ManagementAddressValue::IpAddress(IpAddr::V6(ip)) => {
                 let parsed: Ipv6Addr = "2001:db::4".parse().unwrap();
                 assert_eq!(ip.octets(), parsed.octets());
             }
+            v => panic!("Expected IPv6, got {:?}", v),
         }
     }
 
@@ -342,8 +493,8 @@ mod tests {
         let (tlv4, tlv6) = set_up();
         let t1 = ManagementAddressTLV::new(tlv4.value, 5, IFNumberingSubtype::Unknown, vec![]);
         let t2 = ManagementAddressTLV::new(tlv6.value, 5, IFNumberingSubtype::Unknown, vec![]);
-        assert_eq!(t1.oid, vec![]);
-        assert_eq!(t2.oid, vec![]);
+        assert_eq!(t1.oid, Vec::<u8>::new());
+        assert_eq!(t2.oid, Vec::<u8>::new());
     }
 
     #[test]
@@ -416,12 +567,10 @@ mod tests {
         assert_eq!(tlv.tlv_type as u8, TlvType::ManagementAddress as u8);
         assert_eq!(tlv.subtype as u8, IFNumberingSubtype::IfIndex as u8);
         match tlv.value {
-            IpAddr::V4(ip) => {
+            ManagementAddressValue::IpAddress(IpAddr::V4(ip)) => {
                 assert_eq!(ip.octets(), ipv4.octets());
             }
-            IpAddr::V6(_) => {
-                panic!("Expected IPv4, got IPv6 address");
-            }
+            v => panic!("Expected IPv4, got {:?}", v),
         };
         assert_eq!(tlv.oid, b"\x0A".to_vec());
     }
@@ -436,12 +585,10 @@ mod tests {
         assert_eq!(tlv.tlv_type as u8, TlvType::ManagementAddress as u8);
         assert_eq!(tlv.subtype as u8, IFNumberingSubtype::IfIndex as u8);
         match tlv.value {
-            IpAddr::V4(_) => {
-                panic!("Expected IPv6, got IPv4 address");
-            }
-            IpAddr::V6(ip) => {
+            ManagementAddressValue::IpAddress(IpAddr::V6(ip)) => {
                 assert_eq!(ip.octets(), ipv6.octets());
             }
+            v => panic!("Expected IPv6, got {:?}", v),
         };
         assert_eq!(tlv.oid, b"\x0A".to_vec());
     }
@@ -451,7 +598,115 @@ mod tests {
         let tlv = ManagementAddressTLV::new_from_bytes(
             b"\x10\x0C\x05\x01\xC0\x00\x02*\x03\x00\x00\x00\x01\x00",
         );
-        assert_eq!(tlv.oid, vec![]);
+        assert_eq!(tlv.oid, Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_try_load_invalid() {
+        assert_eq!(
+            ManagementAddressTLV::try_from_bytes(
+                b"\x10\x0C\x05\x00\xC0\x00\x02*\x03\x00\x00\x00\x01\x00"
+            )
+            .unwrap_err(),
+            LldpError::UnknownAddressFamily(0)
+        );
+        assert_eq!(
+            ManagementAddressTLV::try_from_bytes(
+                b"\x10\x0C\x05\x01\xC0\x00\x02*\x03\x00\x00\x00\x01\x02"
+            )
+            .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ManagementAddress,
+                length: 12
+            }
+        );
+        assert_eq!(
+            ManagementAddressTLV::try_from_bytes(b"\x10\x0C\x05\x01\xC0\x00").unwrap_err(),
+            LldpError::Truncated {
+                needed: 14,
+                available: 6
+            }
+        );
+    }
+
+    #[test]
+    fn test_network_address() {
+        let tlv = ManagementAddressTLV::new_from_bytes(
+            b"\x10\x10\x07\x06\x02\x04\xdf\x88\xa2\xb4\x02\x00\x00\x00\x01\x02\x2b\x06",
+        );
+        assert_eq!(
+            tlv.value,
+            ManagementAddressValue::NetworkAddress {
+                family: AddressFamily::Ieee802 as u8,
+                bytes: b"\x02\x04\xdf\x88\xa2\xb4".to_vec()
+            }
+        );
+        assert_eq!(
+            tlv.bytes(),
+            b"\x10\x10\x07\x06\x02\x04\xdf\x88\xa2\xb4\x02\x00\x00\x00\x01\x02\x2b\x06"
+        );
+        assert_eq!(
+            format!("{}", tlv),
+            "ManagementAddressTLV(\"6:0204DF88A2B4\", 1, \"2B06\")"
+        );
+
+        // Address string length exceeds the TLV
+        assert_eq!(
+            ManagementAddressTLV::try_from_bytes(b"\x10\x08\x20\x10lldp\x00\x00").unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ManagementAddress,
+                length: 8
+            }
+        );
+        // OID length exceeds the TLV
+        assert_eq!(
+            ManagementAddressTLV::try_from_bytes(
+                b"\x10\x0E\x05\x10lldp\x02\x00\x00\x00\x01\x04\x2b\x06"
+            )
+            .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ManagementAddress,
+                length: 14
+            }
+        );
+    }
+
+    #[test]
+    fn test_object_identifier() {
+        let (tlv, _) = set_up();
+        assert_eq!(
+            tlv.object_identifier().unwrap().unwrap().to_string(),
+            "1.3.6.1.4.1.311.21.20"
+        );
+
+        let tlv = tlv.with_oid(&"1.3.6.1.4.1.9".parse().unwrap()).unwrap();
+        assert_eq!(tlv.oid, b"\x2b\x06\x01\x04\x01\x09");
+
+        // The BER encoding of 1.3 followed by 100 arcs of 16383 takes 201 bytes
+        let long: Oid = format!("1.3{}", ".16383".repeat(100)).parse().unwrap();
+        assert_eq!(
+            tlv.clone().with_oid(&long).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ManagementAddress,
+                length: 213
+            }
+        );
+
+        let tlv = ManagementAddressTLV::new(
+            "192.0.2.1".parse::<IpAddr>().unwrap(),
+            1,
+            IFNumberingSubtype::IfIndex,
+            b"\x2b\x86".to_vec(),
+        );
+        assert_eq!(tlv.object_identifier(), Some(Err(LldpError::InvalidOid)));
+
+        let tlv = ManagementAddressTLV::new(
+            "192.0.2.1".parse::<IpAddr>().unwrap(),
+            1,
+            IFNumberingSubtype::IfIndex,
+            vec![],
+        );
+        assert_eq!(tlv.object_identifier(), None);
     }
 
     #[test]
@@ -471,4 +726,71 @@ mod tests {
             "ManagementAddressTLV(\"2001:db::4\", 5, \"2B0601040182371514\")"
         )
     }
+
+    #[test]
+    fn test_try_new() {
+        let address = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+        assert!(ManagementAddressTLV::try_new(
+            address,
+            1,
+            IFNumberingSubtype::IfIndex,
+            vec![0x2b; 128]
+        )
+        .is_ok());
+        assert_eq!(
+            ManagementAddressTLV::try_new(address, 1, IFNumberingSubtype::IfIndex, vec![0x2b; 129])
+                .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ManagementAddress,
+                length: 141
+            }
+        );
+
+        let address = ManagementAddressValue::NetworkAddress {
+            family: 6,
+            bytes: vec![0xab; 31],
+        };
+        let tlv =
+            ManagementAddressTLV::try_new(address.clone(), 1, IFNumberingSubtype::IfIndex, vec![])
+                .unwrap();
+        assert_eq!(
+            ManagementAddressTLV::try_from_bytes(&tlv.bytes())
+                .unwrap()
+                .value,
+            address
+        );
+
+        let address = ManagementAddressValue::NetworkAddress {
+            family: 6,
+            bytes: vec![0xab; 300],
+        };
+        assert_eq!(
+            ManagementAddressTLV::try_new(address.clone(), 1, IFNumberingSubtype::IfIndex, vec![])
+                .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ManagementAddress,
+                length: 308
+            }
+        );
+        let empty = ManagementAddressValue::NetworkAddress {
+            family: 6,
+            bytes: vec![],
+        };
+        assert!(ManagementAddressTLV::try_new(
+            empty.clone(),
+            1,
+            IFNumberingSubtype::IfIndex,
+            vec![]
+        )
+        .is_err());
+
+        let tlv = ManagementAddressTLV::new(
+            "192.0.2.1".parse::<IpAddr>().unwrap(),
+            1,
+            IFNumberingSubtype::IfIndex,
+            vec![],
+        );
+        assert!(tlv.clone().with_address(address).is_err());
+        assert!(tlv.with_address(empty).is_err());
+    }
 }
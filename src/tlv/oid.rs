@@ -0,0 +1,167 @@
+use crate::error::LldpError;
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Object Identifier
+///
+/// An OID is a sequence of numbers (arcs) naming an object in a global tree, written in dotted notation like
+/// `1.3.6.1.4.1.311`. Management Address TLVs carry OIDs in their BER encoding, [Oid::from_ber] and [Oid::to_ber]
+/// convert between the two representations.
+///
+/// OIDs can be built arc by arc:
+///
+/// ```
+/// use lldp_rs::tlv::oid::Oid;
+///
+/// let enterprises: Oid = "1.3.6.1.4.1".parse().unwrap();
+/// let microsoft = enterprises.child(311);
+///
+/// assert_eq!(microsoft.to_string(), "1.3.6.1.4.1.311");
+/// assert_eq!(microsoft.to_ber(), b"\x2b\x06\x01\x04\x01\x82\x37");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Oid {
+    arcs: Vec<u64>,
+}
+
+impl Oid {
+    /// Create an OID from its arcs.
+    ///
+    /// Returns an error if there are less than two arcs, the first arc is greater than 2 or the second arc is greater
+    /// than 39 below the roots 0 and 1.
+    pub fn new(arcs: Vec<u64>) -> Result<Oid, LldpError> {
+        match arcs[..] {
+            [0..=1, 0..=39, ..] => Ok(Oid { arcs }),
+            [2, second, ..] if second <= u64::MAX - 80 => Ok(Oid { arcs }),
+            _ => Err(LldpError::InvalidOid),
+        }
+    }
+
+    /// Append an arc to the OID
+    pub fn child(mut self, arc: u64) -> Oid {
+        self.arcs.push(arc);
+        self
+    }
+
+    /// Get the arcs of the OID
+    pub fn arcs(&self) -> &[u64] {
+        &self.arcs
+    }
+
+    /// Decode a BER encoded OID (without tag and length).
+    ///
+    /// Returns an error if the encoding is empty, truncated or not minimal, or an arc does not fit into 64 bits.
+    pub fn from_ber(bytes: &[u8]) -> Result<Oid, LldpError> {
+        let mut arcs = vec![];
+        let mut arc: u64 = 0;
+        let mut start = true;
+        for b in bytes {
+            if (start && *b == 0x80) || arc > u64::MAX >> 7 {
+                return Err(LldpError::InvalidOid);
+            }
+            arc = (arc << 7) | (b & 0x7f) as u64;
+            start = b & 0x80 == 0;
+            if start {
+                arcs.push(arc);
+                arc = 0;
+            }
+        }
+
+        // The first subidentifier combines the first two arcs
+        let first = match arcs.first() {
+            Some(first) if start => *first,
+            _ => return Err(LldpError::InvalidOid),
+        };
+        let root = match first {
+            0..=39 => 0,
+            40..=79 => 1,
+            _ => 2,
+        };
+        arcs[0] = first - root * 40;
+        arcs.insert(0, root);
+
+        Ok(Oid { arcs })
+    }
+
+    /// Return the BER encoding of the OID (without tag and length)
+    pub fn to_ber(&self) -> Vec<u8> {
+        let first = self.arcs[0] * 40 + self.arcs[1];
+
+        let mut bytes = vec![];
+        for arc in std::iter::once(first).chain(self.arcs[2..].iter().copied()) {
+            let mut groups = vec![(arc & 0x7f) as u8];
+            let mut rest = arc >> 7;
+            while rest > 0 {
+                groups.push((rest & 0x7f) as u8 | 0x80);
+                rest >>= 7;
+            }
+            bytes.extend(groups.iter().rev());
+        }
+
+        bytes
+    }
+}
+
+impl Display for Oid {
+    /// Write the OID in dotted notation
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arcs: Vec<String> = self.arcs.iter().map(|arc| arc.to_string()).collect();
+        write!(f, "{}", arcs.join("."))
+    }
+}
+
+impl FromStr for Oid {
+    type Err = LldpError;
+
+    /// Parse an OID in dotted notation
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let arcs = s
+            .split('.')
+            .map(|arc| arc.parse::<u64>())
+            .collect::<Result<Vec<u64>, _>>()
+            .map_err(|_| LldpError::InvalidOid)?;
+
+        Oid::new(arcs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ber() {
+        let oid = Oid::from_ber(b"\x2b\x06\x01\x04\x01\x82\x37\x15\x14").unwrap();
+        assert_eq!(oid.arcs(), &[1, 3, 6, 1, 4, 1, 311, 21, 20]);
+        assert_eq!(oid.to_string(), "1.3.6.1.4.1.311.21.20");
+        assert_eq!(oid.to_ber(), b"\x2b\x06\x01\x04\x01\x82\x37\x15\x14");
+
+        let oid = Oid::from_ber(b"\x88\x37\x03").unwrap();
+        assert_eq!(oid.to_string(), "2.999.3");
+        assert_eq!(oid.to_ber(), b"\x88\x37\x03");
+    }
+
+    #[test]
+    fn test_invalid_ber() {
+        assert_eq!(Oid::from_ber(b""), Err(LldpError::InvalidOid));
+        assert_eq!(Oid::from_ber(b"\x2b\x86"), Err(LldpError::InvalidOid));
+        assert_eq!(Oid::from_ber(b"\x2b\x80\x01"), Err(LldpError::InvalidOid));
+        assert_eq!(
+            Oid::from_ber(b"\x2b\xff\xff\xff\xff\xff\xff\xff\xff\xff\x7f"),
+            Err(LldpError::InvalidOid)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let oid: Oid = "1.3.6.1".parse().unwrap();
+        assert_eq!(oid.child(4).to_ber(), b"\x2b\x06\x01\x04");
+
+        assert_eq!("1".parse::<Oid>(), Err(LldpError::InvalidOid));
+        assert_eq!("1.40".parse::<Oid>(), Err(LldpError::InvalidOid));
+        assert_eq!("3.1".parse::<Oid>(), Err(LldpError::InvalidOid));
+        assert_eq!("1..3".parse::<Oid>(), Err(LldpError::InvalidOid));
+        assert!("2.40.1".parse::<Oid>().is_ok());
+    }
+}
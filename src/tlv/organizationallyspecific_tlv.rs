@@ -1,8 +1,136 @@
 use std::fmt::Display;
 
-use crate::tlv::TlvType;
+use crate::error::LldpError;
+use crate::tlv::{check_len, TlvCodec, TlvType};
 use bytes::BufMut;
 
+/// Dispatch the methods of an enum of the TLVs defined by one organization to the [OrgTlvCodec] implementation of
+/// each variant.
+macro_rules! org_tlv_dispatch {
+    ($name:ident { $($variant:ident($tlv:ident)),* $(,)? }) => {
+        impl std::fmt::Display for $name {
+            /// Write a printable representation of the TLV object.
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $($name::$variant(value) => value.fmt(f),)*
+                }
+            }
+        }
+
+        $(
+            impl From<$tlv> for $name {
+                fn from(tlv: $tlv) -> $name {
+                    $name::$variant(tlv)
+                }
+            }
+        )*
+
+        impl $name {
+            /// Decode the information of `tlv` according to its subtype.
+            ///
+            /// Returns `None` if the OUI or the subtype is not known and an error if the information is malformed.
+            pub fn decode(
+                tlv: &crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV,
+            ) -> Option<Result<$name, crate::error::LldpError>> {
+                use crate::tlv::organizationallyspecific_tlv::OrgTlvCodec;
+
+                $(
+                    if tlv.oui == $tlv::OUI && tlv.subtype == $tlv::SUBTYPE {
+                        return Some($tlv::decode_info(&tlv.value).map($name::$variant));
+                    }
+                )*
+
+                None
+            }
+
+            /// Return the Organizationally Specific TLV carrying this TLV
+            pub fn to_tlv(&self) -> crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV {
+                use crate::tlv::organizationallyspecific_tlv::OrgTlvCodec;
+
+                match self {
+                    $($name::$variant(value) => value.to_tlv(),)*
+                }
+            }
+
+            /// Register the decoders of all TLVs of the organization in `registry`.
+            pub fn register(registry: &mut crate::tlv::organizationallyspecific_tlv::registry::OrgTlvRegistry) {
+                $(registry.register_codec::<$tlv>();)*
+            }
+        }
+    };
+}
+
+pub mod dcbx;
+pub mod ieee8021;
+pub mod ieee8023;
+pub mod med;
+pub mod profinet;
+pub mod registry;
+
+/// Maximum length of the organizationally defined information in bytes
+pub const MAX_INFO_LEN: usize = 507;
+
+/// Organizationally Specific TLV Codec
+///
+/// Implemented by TLVs that are defined by an organization. They are transmitted as the organizationally defined
+/// information of an [OrganizationallySpecificTLV] with the OUI of the organization and a subtype.
+pub trait OrgTlvCodec: Sized {
+    /// The OUI of the defining organization
+    const OUI: [u8; 3];
+    /// The subtype assigned by the organization
+    const SUBTYPE: u8;
+
+    /// Write the organizationally defined information to `buf`.
+    fn encode_info(&self, buf: &mut impl BufMut);
+
+    /// Create a TLV instance from the organizationally defined information.
+    ///
+    /// Returns an error if the information contains errors.
+    fn decode_info(info: &[u8]) -> Result<Self, LldpError>;
+
+    /// Return the Organizationally Specific TLV carrying this TLV
+    fn to_tlv(&self) -> OrganizationallySpecificTLV {
+        let mut info = vec![];
+        self.encode_info(&mut info);
+
+        OrganizationallySpecificTLV::new(Self::OUI.to_vec(), Self::SUBTYPE, info)
+    }
+
+    /// Create a TLV instance from an Organizationally Specific TLV.
+    ///
+    /// Returns an error if the OUI or the subtype do not match or the information contains errors.
+    fn try_from_tlv(tlv: &OrganizationallySpecificTLV) -> Result<Self, LldpError> {
+        if tlv.oui != Self::OUI || tlv.subtype != Self::SUBTYPE {
+            return Err(LldpError::UnknownOrgSubtype {
+                oui: tlv.oui.clone(),
+                subtype: tlv.subtype,
+            });
+        }
+
+        Self::decode_info(&tlv.value)
+    }
+}
+
+/// Check that the organizationally defined information `info` has a length within `range`.
+pub(crate) fn check_info_len(
+    info: &[u8],
+    range: std::ops::RangeInclusive<usize>,
+) -> Result<(), LldpError> {
+    if !range.contains(&info.len()) {
+        return Err(bad_info_len(info));
+    }
+
+    Ok(())
+}
+
+/// Return the error for organizationally defined information `info` of an invalid length.
+pub(crate) fn bad_info_len(info: &[u8]) -> LldpError {
+    LldpError::BadLength {
+        tlv_type: TlvType::OrganizationallySpecific,
+        length: 4 + info.len(),
+    }
+}
+
 /// Organizationally Specific TLV
 ///
 /// This TLV type is provided to allow organizations, software developers and equipment vendors to define TLVs
@@ -13,6 +141,7 @@ use bytes::BufMut;
 ///
 /// # TLV Format:
 ///
+/// ```text
 ///      0               1               2               5               6
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-|
 ///     |             |                 |    Organiz.   |    Organiz.   |   Organizationally  |
@@ -21,19 +150,34 @@ use bytes::BufMut;
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-|
 ///
 ///                                                                          0 - 507 byte
+/// ```
 ///
 /// The OUI is a 24 bit number uniquely identifying a vendor, manufacturer or organization.
 ///
 /// The subtype should be a unique subtype value assigned by the defining organization.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "crate::serialization::OrganizationallySpecificFields")
+)]
 pub struct OrganizationallySpecificTLV {
     /// The type of the TLV
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip,
+            default = "crate::serialization::tlv_type::<OrganizationallySpecificTLV>"
+        )
+    )]
     pub tlv_type: TlvType,
     /// Organizationally unique identifier
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::oui"))]
     pub oui: Vec<u8>,
     /// Organizationally defined subtype
     pub subtype: u8,
     /// Organizationally defined information
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::hex"))]
     pub value: Vec<u8>,
 }
 
@@ -42,12 +186,12 @@ impl Display for OrganizationallySpecificTLV {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut oui = String::new();
         for i in &self.oui {
-            oui.push_str(&format!("{:X}", i));
+            oui.push_str(&format!("{:02X}", i));
         }
 
         let mut value = String::new();
         for i in &self.value {
-            value.push_str(&format!("{:X}", i));
+            value.push_str(&format!("{:02X}", i));
         }
 
         write!(
@@ -64,63 +208,57 @@ impl OrganizationallySpecificTLV {
         // TODO: Implement
         OrganizationallySpecificTLV {
             tlv_type: TlvType::OrganizationallySpecific,
-            oui: oui,
-            subtype: subtype,
-            value: value,
+            oui,
+            subtype,
+            value,
         }
     }
 
-    /// Create a TLV instance from raw bytes.
+    /// Create an Organizationally Specific TLV, checking the lengths of the OUI and the information.
     ///
-    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
-    pub fn new_from_bytes(bytes: &[u8]) -> OrganizationallySpecificTLV {
-        let mut type_field = bytes[0] & 0b11111110;
-        type_field = type_field >> 1;
-
-        if type_field != TlvType::OrganizationallySpecific as u8 {
-            panic!("Wrong TLV Type for ChassisId_Tlv");
+    /// Returns an error if the OUI is not 3 bytes long or the information is longer than 507 bytes.
+    pub fn try_new(
+        oui: Vec<u8>,
+        subtype: u8,
+        value: Vec<u8>,
+    ) -> Result<OrganizationallySpecificTLV, LldpError> {
+        if oui.len() != 3 {
+            return Err(LldpError::InvalidOui(oui.len()));
         }
 
-        let mut length = bytes[1] as usize;
-        if bytes[0] & 1 == 1 {
-            length += 1 << 9;
-        }
-
-        assert_eq!(length, bytes[2..].len());
-
-        let oui = bytes[2..5].to_vec();
-        let subtype = bytes[5];
-        let value = bytes[6..].to_vec();
-        println!("{:?}", value);
-
-        OrganizationallySpecificTLV::new(oui, subtype, value)
+        check_len(
+            OrganizationallySpecificTLV::new(oui, subtype, value),
+            4..=4 + MAX_INFO_LEN,
+        )
     }
+}
+
+impl TlvCodec for OrganizationallySpecificTLV {
+    const TLV_TYPE: TlvType = TlvType::OrganizationallySpecific;
 
-    /// Return the length of the TLV value
-    pub fn len(&self) -> usize {
+    fn len(&self) -> usize {
         4 + self.value.len()
     }
 
-    /// Return the byte representation of the TLV.
-    pub fn bytes(&self) -> Vec<u8> {
-        let mut type_field = (self.tlv_type as u8) << 1;
+    fn encode_value(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&self.oui);
+        buf.put_u8(self.subtype);
+        buf.put_slice(&self.value);
+    }
 
-        let length_field = self.len();
-        if length_field & (1 << 9) == 1 {
-            type_field |= 1;
+    fn decode_value(value: &[u8]) -> Result<OrganizationallySpecificTLV, LldpError> {
+        if value.len() < 4 {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: value.len(),
+            });
         }
 
-        let length_field = length_field as u8;
-
-        let mut result: Vec<u8> = Vec::new();
-        result.push(type_field);
-        result.push(length_field);
+        let oui = value[0..3].to_vec();
+        let subtype = value[3];
+        let value = value[4..].to_vec();
 
-        result.extend_from_slice(&self.oui);
-        result.push(self.subtype);
-        result.extend_from_slice(&self.value);
-
-        result
+        Ok(OrganizationallySpecificTLV::new(oui, subtype, value))
     }
 }
 
@@ -128,6 +266,14 @@ impl OrganizationallySpecificTLV {
 mod tests {
     use super::*;
 
+    /// Decode `bytes` into a `T`, check that encoding it yields `bytes` again and return it.
+    pub(super) fn roundtrip<T: OrgTlvCodec>(bytes: &[u8]) -> T {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes);
+        let decoded = T::try_from_tlv(&tlv).unwrap();
+        assert_eq!(decoded.to_tlv().bytes(), bytes);
+        decoded
+    }
+
     fn set_up() -> (OrganizationallySpecificTLV, Vec<u8>, u8, String) {
         let oui = b"\xAA\xBB\xCC".to_vec();
         let subtype = 5;
@@ -164,7 +310,7 @@ mod tests {
     fn test_dump() {
         let (tlv, oui, subtype, data) = set_up();
         let mut bytes = b"\xFE".to_vec();
-        bytes.put_u8(data.as_bytes().len() as u8 + 4);
+        bytes.put_u8(data.len() as u8 + 4);
         bytes.put(oui.as_slice());
         bytes.put_u8(subtype);
         bytes.put(data.as_bytes());
@@ -183,6 +329,40 @@ mod tests {
         assert_eq!(tlv.subtype, 0x1A);
     }
 
+    #[test]
+    fn test_long_value_roundtrip() {
+        let value = vec![0x5A; 507];
+        let tlv = OrganizationallySpecificTLV::new(b"\xAA\xBB\xCC".to_vec(), 1, value.clone());
+        let bytes = tlv.bytes();
+        assert_eq!(&bytes[..2], b"\xFF\xFF");
+        assert_eq!(bytes.len(), 513);
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(&bytes);
+        assert_eq!(tlv.len(), 511);
+        assert_eq!(tlv.value, value);
+        assert_eq!(tlv.bytes(), bytes);
+    }
+
+    #[test]
+    fn test_try_load_invalid() {
+        assert_eq!(
+            OrganizationallySpecificTLV::try_from_bytes(b"\xFE\x03\xAA\xBB\xCC".as_ref())
+                .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 3
+            }
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::try_from_bytes(b"\xFE\x1D\xAA\xBB\xCC\x1A0118".as_ref())
+                .unwrap_err(),
+            LldpError::Truncated {
+                needed: 31,
+                available: 10
+            }
+        );
+    }
+
     #[test]
     fn test_display() {
         let (tlv, _, _, _) = set_up();
@@ -191,4 +371,23 @@ mod tests {
             "OrganizationallySpecificTLV(\"AABBCC\", 5, \"4855525A21\")"
         );
     }
+
+    #[test]
+    fn test_try_new() {
+        let tlv = OrganizationallySpecificTLV::try_new(vec![0x00, 0x80, 0xc2], 1, vec![0; 507]);
+        assert_eq!(tlv.unwrap().len(), 511);
+
+        assert_eq!(
+            OrganizationallySpecificTLV::try_new(vec![0x00, 0x80, 0xc2], 1, vec![0; 508])
+                .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 512
+            }
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::try_new(vec![0x00, 0x80], 1, vec![]).unwrap_err(),
+            LldpError::InvalidOui(2)
+        );
+    }
 }
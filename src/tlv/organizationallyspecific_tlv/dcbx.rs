@@ -0,0 +1,1036 @@
+//! DCBX Organizationally Specific TLVs
+//!
+//! TLVs of the Data Center Bridging Capability Exchange protocol. IEEE 802.1Qaz defines the ETS, PFC and Application
+//! Priority TLVs, transmitted with the OUI 00-80-C2. The legacy CEE version of DCBX (DCBX 1.01) transmits a single TLV
+//! with the OUI 00-1B-21, which carries the features as sub-TLVs.
+//!
+//! ```
+//! use lldp_rs::tlv::organizationallyspecific_tlv::dcbx::PfcConfigurationTLV;
+//! use lldp_rs::tlv::organizationallyspecific_tlv::OrgTlvCodec;
+//!
+//! let tlv = PfcConfigurationTLV::new(true, 8, 0b0001_1000);
+//! assert_eq!(tlv.to_tlv().value, b"\x88\x18");
+//! assert_eq!(tlv.priorities(), vec![3, 4]);
+//! assert_eq!(tlv.to_string(), "PfcConfigurationTLV(willing, 8 TCs, PFC on [3 4])");
+//! ```
+
+use crate::error::LldpError;
+use crate::tlv::organizationallyspecific_tlv::{
+    bad_info_len, check_info_len, ieee8021, OrgTlvCodec,
+};
+use bytes::BufMut;
+use std::fmt::Display;
+
+/// OUI of the legacy CEE DCBX TLV
+pub const CEE_OUI: [u8; 3] = [0x00, 0x1B, 0x21];
+
+/// Number of priorities and traffic classes
+pub const NUM_PRIORITIES: usize = 8;
+
+/// Decode 8 values of 4 bits, the first value is in the upper nibble of the first byte.
+fn decode_nibbles(bytes: &[u8]) -> [u8; NUM_PRIORITIES] {
+    let mut values = [0; NUM_PRIORITIES];
+    for (i, byte) in bytes.iter().take(NUM_PRIORITIES / 2).enumerate() {
+        values[2 * i] = byte >> 4;
+        values[2 * i + 1] = byte & 0x0F;
+    }
+
+    values
+}
+
+fn encode_nibbles(values: &[u8; NUM_PRIORITIES], buf: &mut impl BufMut) {
+    for pair in values.chunks(2) {
+        buf.put_u8((pair[0] & 0x0F) << 4 | pair[1] & 0x0F);
+    }
+}
+
+/// Write a list of priorities, e.g. `[3 4]`
+fn write_priorities(
+    f: &mut std::fmt::Formatter<'_>,
+    priorities: impl Iterator<Item = usize>,
+) -> std::fmt::Result {
+    let priorities: Vec<String> = priorities.map(|priority| priority.to_string()).collect();
+    write!(f, "[{}]", priorities.join(" "))
+}
+
+/// Get the priorities set in a bitmap, bit n corresponds to priority n
+fn bitmap_priorities(bitmap: u8) -> Vec<u8> {
+    (0..NUM_PRIORITIES as u8)
+        .filter(|priority| bitmap & (1 << priority) != 0)
+        .collect()
+}
+
+/// Transmission selection algorithms of the [EtsTables]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransmissionSelection {
+    StrictPriority = 0,
+    CreditBasedShaper = 1,
+    Ets = 2,
+    VendorSpecific = 255,
+}
+
+impl TransmissionSelection {
+    /// Get the name of the transmission selection algorithm
+    pub fn name(tsa: u8) -> Option<&'static str> {
+        match tsa {
+            0 => Some("strict priority"),
+            1 => Some("credit-based shaper"),
+            2 => Some("ETS"),
+            255 => Some("vendor specific"),
+            _ => None,
+        }
+    }
+}
+
+/// Priority assignment, bandwidth and transmission selection tables of the ETS TLVs
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EtsTables {
+    /// The traffic class of each priority
+    pub priority_tc: [u8; NUM_PRIORITIES],
+    /// The bandwidth of each traffic class in percent
+    pub tc_bandwidth: [u8; NUM_PRIORITIES],
+    /// The transmission selection algorithm of each traffic class, see [TransmissionSelection]
+    pub tsa: [u8; NUM_PRIORITIES],
+}
+
+impl EtsTables {
+    /// Length of the encoded tables in bytes
+    pub const LEN: usize = 20;
+
+    /// Constructor
+    pub fn new(
+        priority_tc: [u8; NUM_PRIORITIES],
+        tc_bandwidth: [u8; NUM_PRIORITIES],
+        tsa: [u8; NUM_PRIORITIES],
+    ) -> EtsTables {
+        EtsTables {
+            priority_tc,
+            tc_bandwidth,
+            tsa,
+        }
+    }
+
+    /// Get the priorities assigned to a traffic class
+    pub fn priorities(&self, tc: u8) -> Vec<u8> {
+        (0..NUM_PRIORITIES as u8)
+            .filter(|priority| self.priority_tc[*priority as usize] == tc)
+            .collect()
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) {
+        encode_nibbles(&self.priority_tc, buf);
+        buf.put_slice(&self.tc_bandwidth);
+        buf.put_slice(&self.tsa);
+    }
+
+    fn decode(bytes: &[u8]) -> EtsTables {
+        let mut tables = EtsTables::new(decode_nibbles(&bytes[..4]), [0; 8], [0; 8]);
+        tables.tc_bandwidth.copy_from_slice(&bytes[4..12]);
+        tables.tsa.copy_from_slice(&bytes[12..20]);
+        tables
+    }
+}
+
+impl Display for EtsTables {
+    /// Write the traffic classes in use with their algorithm and priorities, e.g. `TC0: ETS 50% [0 1 2]; TC1: strict
+    /// priority [3]`. The bandwidth is only written for traffic classes using ETS.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for tc in 0..NUM_PRIORITIES {
+            let priorities = self.priorities(tc as u8);
+            if priorities.is_empty() && self.tc_bandwidth[tc] == 0 {
+                continue;
+            }
+
+            if !first {
+                write!(f, "; ")?;
+            }
+            first = false;
+
+            write!(f, "TC{}: ", tc)?;
+            match TransmissionSelection::name(self.tsa[tc]) {
+                Some("ETS") => write!(f, "ETS {}% ", self.tc_bandwidth[tc])?,
+                Some(name) => write!(f, "{} ", name)?,
+                None => write!(f, "TSA {} ", self.tsa[tc])?,
+            }
+            write_priorities(f, priorities.iter().map(|priority| *priority as usize))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// ETS Configuration TLV
+///
+/// Advertises the Enhanced Transmission Selection configuration of the port.
+///
+/// | Subtype | Information                                                                               |
+/// | ------- | ----------------------------------------------------------------------------------------- |
+/// |    9    | Willing (1 bit), CBS (1 bit), Reserved (3 bits), Max TCs (3 bits), [EtsTables] (20 bytes) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EtsConfigurationTLV {
+    /// The station accepts the recommendation of the peer
+    pub willing: bool,
+    /// The station supports the credit-based shaper algorithm
+    pub cbs: bool,
+    /// The number of supported traffic classes (1 - 8)
+    pub max_tcs: u8,
+    /// The ETS tables
+    pub tables: EtsTables,
+}
+
+impl EtsConfigurationTLV {
+    /// Constructor
+    pub fn new(willing: bool, max_tcs: u8, tables: EtsTables) -> EtsConfigurationTLV {
+        EtsConfigurationTLV {
+            willing,
+            cbs: false,
+            max_tcs,
+            tables,
+        }
+    }
+}
+
+impl Display for EtsConfigurationTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EtsConfigurationTLV({}, {}{} TCs, {})",
+            if self.willing {
+                "willing"
+            } else {
+                "not willing"
+            },
+            if self.cbs { "CBS, " } else { "" },
+            self.max_tcs,
+            self.tables
+        )
+    }
+}
+
+impl OrgTlvCodec for EtsConfigurationTLV {
+    const OUI: [u8; 3] = ieee8021::OUI;
+    const SUBTYPE: u8 = 9;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        // A maximum of 8 traffic classes is encoded as 0
+        buf.put_u8((self.willing as u8) << 7 | (self.cbs as u8) << 6 | self.max_tcs & 0x07);
+        self.tables.encode(buf);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<EtsConfigurationTLV, LldpError> {
+        check_info_len(info, 1 + EtsTables::LEN..=1 + EtsTables::LEN)?;
+
+        Ok(EtsConfigurationTLV {
+            willing: info[0] & 0x80 != 0,
+            cbs: info[0] & 0x40 != 0,
+            max_tcs: match info[0] & 0x07 {
+                0 => 8,
+                max_tcs => max_tcs,
+            },
+            tables: EtsTables::decode(&info[1..]),
+        })
+    }
+}
+
+/// ETS Recommendation TLV
+///
+/// Advertises the Enhanced Transmission Selection configuration recommended to a willing peer.
+///
+/// | Subtype | Information                                  |
+/// | ------- | -------------------------------------------- |
+/// |   10    | Reserved (1 byte), [EtsTables] (20 bytes)    |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EtsRecommendationTLV {
+    /// The recommended ETS tables
+    pub tables: EtsTables,
+}
+
+impl EtsRecommendationTLV {
+    /// Constructor
+    pub fn new(tables: EtsTables) -> EtsRecommendationTLV {
+        EtsRecommendationTLV { tables }
+    }
+}
+
+impl Display for EtsRecommendationTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EtsRecommendationTLV({})", self.tables)
+    }
+}
+
+impl OrgTlvCodec for EtsRecommendationTLV {
+    const OUI: [u8; 3] = ieee8021::OUI;
+    const SUBTYPE: u8 = 10;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8(0);
+        self.tables.encode(buf);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<EtsRecommendationTLV, LldpError> {
+        check_info_len(info, 1 + EtsTables::LEN..=1 + EtsTables::LEN)?;
+
+        Ok(EtsRecommendationTLV::new(EtsTables::decode(&info[1..])))
+    }
+}
+
+/// PFC Configuration TLV
+///
+/// Advertises the priorities with Priority-based Flow Control enabled.
+///
+/// | Subtype | Information                                                                          |
+/// | ------- | ------------------------------------------------------------------------------------ |
+/// |   11    | Willing (1 bit), MBC (1 bit), Reserved (2 bits), PFC Cap (4 bits), PFC Enable (1 byte) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PfcConfigurationTLV {
+    /// The station accepts the configuration of the peer
+    pub willing: bool,
+    /// The station is capable of bypassing MACsec (MACsec Bypass Capability)
+    pub mbc: bool,
+    /// The number of traffic classes that may simultaneously have PFC enabled
+    pub capability: u8,
+    /// Bitmap of the priorities with PFC enabled, bit n corresponds to priority n
+    pub enabled: u8,
+}
+
+impl PfcConfigurationTLV {
+    /// Constructor
+    pub fn new(willing: bool, capability: u8, enabled: u8) -> PfcConfigurationTLV {
+        PfcConfigurationTLV {
+            willing,
+            mbc: false,
+            capability,
+            enabled,
+        }
+    }
+
+    /// Get the priorities with PFC enabled
+    pub fn priorities(&self) -> Vec<u8> {
+        bitmap_priorities(self.enabled)
+    }
+}
+
+impl Display for PfcConfigurationTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PfcConfigurationTLV({}, {}{} TCs, PFC on ",
+            if self.willing {
+                "willing"
+            } else {
+                "not willing"
+            },
+            if self.mbc { "MBC, " } else { "" },
+            self.capability
+        )?;
+        write_priorities(
+            f,
+            self.priorities().iter().map(|priority| *priority as usize),
+        )?;
+        write!(f, ")")
+    }
+}
+
+impl OrgTlvCodec for PfcConfigurationTLV {
+    const OUI: [u8; 3] = ieee8021::OUI;
+    const SUBTYPE: u8 = 11;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8((self.willing as u8) << 7 | (self.mbc as u8) << 6 | self.capability & 0x0F);
+        buf.put_u8(self.enabled);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<PfcConfigurationTLV, LldpError> {
+        check_info_len(info, 2..=2)?;
+
+        Ok(PfcConfigurationTLV {
+            willing: info[0] & 0x80 != 0,
+            mbc: info[0] & 0x40 != 0,
+            capability: info[0] & 0x0F,
+            enabled: info[1],
+        })
+    }
+}
+
+/// Entry of the [ApplicationPriorityTLV]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AppPriority {
+    /// The priority of the application (3 bits)
+    pub priority: u8,
+    /// The meaning of the protocol field (3 bits), see [AppPriority::selector_name]
+    pub selector: u8,
+    /// The Ethertype, port number or DSCP value
+    pub protocol: u16,
+}
+
+impl AppPriority {
+    /// Constructor
+    pub fn new(priority: u8, selector: u8, protocol: u16) -> AppPriority {
+        AppPriority {
+            priority,
+            selector,
+            protocol,
+        }
+    }
+
+    /// Get the name of the selector, e.g. `Ethertype`
+    pub fn selector_name(&self) -> Option<&'static str> {
+        match self.selector {
+            1 => Some("Ethertype"),
+            2 => Some("TCP/SCTP port"),
+            3 => Some("UDP/DCCP port"),
+            4 => Some("TCP/SCTP/UDP/DCCP port"),
+            5 => Some("DSCP"),
+            _ => None,
+        }
+    }
+}
+
+impl Display for AppPriority {
+    /// Write the entry, e.g. `Ethertype 0x8906 -> 3`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.selector {
+            1 => write!(f, "Ethertype 0x{:04X}", self.protocol)?,
+            _ => match self.selector_name() {
+                Some(name) => write!(f, "{} {}", name, self.protocol)?,
+                None => write!(f, "selector {} {}", self.selector, self.protocol)?,
+            },
+        }
+
+        write!(f, " -> {}", self.priority)
+    }
+}
+
+/// Application Priority TLV
+///
+/// Advertises the priorities to use for applications, identified by Ethertype, port number or DSCP value.
+///
+/// | Subtype | Information                                                                    |
+/// | ------- | ------------------------------------------------------------------------------ |
+/// |   12    | Reserved (1 byte), Application Priority Table (3 bytes per [AppPriority])       |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApplicationPriorityTLV {
+    /// The application priority table
+    pub entries: Vec<AppPriority>,
+}
+
+impl ApplicationPriorityTLV {
+    /// Constructor
+    pub fn new(entries: Vec<AppPriority>) -> ApplicationPriorityTLV {
+        ApplicationPriorityTLV { entries }
+    }
+}
+
+impl Display for ApplicationPriorityTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries: Vec<String> = self.entries.iter().map(|entry| entry.to_string()).collect();
+        write!(f, "ApplicationPriorityTLV({})", entries.join(", "))
+    }
+}
+
+impl OrgTlvCodec for ApplicationPriorityTLV {
+    const OUI: [u8; 3] = ieee8021::OUI;
+    const SUBTYPE: u8 = 12;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8(0);
+        for entry in &self.entries {
+            buf.put_u8((entry.priority & 0x07) << 5 | entry.selector & 0x07);
+            buf.put_u16(entry.protocol);
+        }
+    }
+
+    fn decode_info(info: &[u8]) -> Result<ApplicationPriorityTLV, LldpError> {
+        if info.is_empty() || (info.len() - 1) % 3 != 0 {
+            return Err(bad_info_len(info));
+        }
+
+        let entries = info[1..]
+            .chunks(3)
+            .map(|entry| {
+                AppPriority::new(
+                    entry[0] >> 5,
+                    entry[0] & 0x07,
+                    u16::from_be_bytes([entry[1], entry[2]]),
+                )
+            })
+            .collect();
+
+        Ok(ApplicationPriorityTLV::new(entries))
+    }
+}
+
+/// Control sub-TLV of the [CeeDcbxTLV]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CeeControl {
+    /// The operating version of the DCBX protocol
+    pub oper_version: u8,
+    /// The highest supported version of the DCBX protocol
+    pub max_version: u8,
+    /// The sequence number, incremented when the local configuration changes
+    pub seq_no: u32,
+    /// The last sequence number received from the peer
+    pub ack_no: u32,
+}
+
+impl CeeControl {
+    /// Constructor
+    pub fn new(seq_no: u32, ack_no: u32) -> CeeControl {
+        CeeControl {
+            oper_version: 0,
+            max_version: 0,
+            seq_no,
+            ack_no,
+        }
+    }
+}
+
+/// Application entry of the CEE Application feature
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CeeAppEntry {
+    /// The Ethertype or port number
+    pub protocol: u16,
+    /// The meaning of the protocol field (0: Ethertype, 1: TCP/UDP port)
+    pub selector: u8,
+    /// The OUI field (22 bits), usually 00-1B-21
+    pub oui: u32,
+    /// Bitmap of the priorities of the application, bit n corresponds to priority n
+    pub priority_map: u8,
+}
+
+impl CeeAppEntry {
+    /// Constructor
+    pub fn new(protocol: u16, selector: u8, priority_map: u8) -> CeeAppEntry {
+        CeeAppEntry {
+            protocol,
+            selector,
+            oui: 0x001B21,
+            priority_map,
+        }
+    }
+}
+
+impl Display for CeeAppEntry {
+    /// Write the entry, e.g. `Ethertype 0x8906 -> [3]`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.selector {
+            0 => write!(f, "Ethertype 0x{:04X} -> ", self.protocol)?,
+            1 => write!(f, "TCP/UDP port {} -> ", self.protocol)?,
+            selector => write!(f, "selector {} {} -> ", selector, self.protocol)?,
+        }
+
+        let priorities = bitmap_priorities(self.priority_map);
+        write_priorities(f, priorities.iter().map(|priority| *priority as usize))
+    }
+}
+
+/// Configuration of a CEE DCBX feature
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CeeFeatureConfig {
+    /// Priority Groups (type 2), PG ID 15 denotes strict priority
+    PriorityGroups {
+        pg_ids: [u8; NUM_PRIORITIES],
+        pg_bandwidth: [u8; NUM_PRIORITIES],
+        num_tcs: u8,
+    },
+    /// Priority-based Flow Control (type 3)
+    Pfc { enabled: u8, num_tcs: u8 },
+    /// Application (type 4)
+    Application(Vec<CeeAppEntry>),
+    /// A feature of another type, e.g. Logical Link Down
+    Unknown {
+        feature_type: u8,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::hex"))]
+        data: Vec<u8>,
+    },
+}
+
+impl CeeFeatureConfig {
+    /// Get the sub-TLV type of the feature
+    pub fn feature_type(&self) -> u8 {
+        match self {
+            CeeFeatureConfig::PriorityGroups { .. } => 2,
+            CeeFeatureConfig::Pfc { .. } => 3,
+            CeeFeatureConfig::Application(_) => 4,
+            CeeFeatureConfig::Unknown { feature_type, .. } => *feature_type,
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            CeeFeatureConfig::PriorityGroups { .. } => 13,
+            CeeFeatureConfig::Pfc { .. } => 2,
+            CeeFeatureConfig::Application(entries) => 6 * entries.len(),
+            CeeFeatureConfig::Unknown { data, .. } => data.len(),
+        }
+    }
+}
+
+impl Display for CeeFeatureConfig {
+    /// Write the priority group table, PFC priorities or application entries
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CeeFeatureConfig::PriorityGroups {
+                pg_ids,
+                pg_bandwidth,
+                num_tcs,
+            } => {
+                for pg in (0..NUM_PRIORITIES).chain(std::iter::once(15)) {
+                    let priorities: Vec<usize> = (0..NUM_PRIORITIES)
+                        .filter(|priority| pg_ids[*priority] as usize == pg)
+                        .collect();
+                    let bandwidth = pg_bandwidth.get(pg).copied().unwrap_or(0);
+                    if priorities.is_empty() && bandwidth == 0 {
+                        continue;
+                    }
+
+                    match pg {
+                        15 => write!(f, "PG15: strict ")?,
+                        _ => write!(f, "PG{}: {}% ", pg, bandwidth)?,
+                    }
+                    write_priorities(f, priorities.into_iter())?;
+                    write!(f, "; ")?;
+                }
+
+                write!(f, "{} TCs", num_tcs)
+            }
+            CeeFeatureConfig::Pfc { enabled, num_tcs } => {
+                write!(f, "PFC on ")?;
+                let priorities = bitmap_priorities(*enabled);
+                write_priorities(f, priorities.iter().map(|priority| *priority as usize))?;
+                write!(f, ", {} TCs", num_tcs)
+            }
+            CeeFeatureConfig::Application(entries) => {
+                let entries: Vec<String> = entries.iter().map(|entry| entry.to_string()).collect();
+                write!(f, "{}", entries.join(", "))
+            }
+            CeeFeatureConfig::Unknown { data, .. } => {
+                let mut hex = String::new();
+                for i in data {
+                    hex.push_str(&format!("{:02X}", i));
+                }
+
+                write!(f, "\"{}\"", hex)
+            }
+        }
+    }
+}
+
+/// Feature sub-TLV of the [CeeDcbxTLV]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CeeFeature {
+    /// The operating version of the feature
+    pub oper_version: u8,
+    /// The highest supported version of the feature
+    pub max_version: u8,
+    /// The feature is enabled locally
+    pub enabled: bool,
+    /// The station accepts the configuration of the peer
+    pub willing: bool,
+    /// The feature has not been configured successfully
+    pub error: bool,
+    /// The feature subtype
+    pub subtype: u8,
+    /// The feature configuration
+    pub config: CeeFeatureConfig,
+}
+
+impl CeeFeature {
+    /// Create an enabled feature
+    pub fn new(willing: bool, config: CeeFeatureConfig) -> CeeFeature {
+        CeeFeature {
+            oper_version: 0,
+            max_version: 0,
+            enabled: true,
+            willing,
+            error: false,
+            subtype: 0,
+            config,
+        }
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) {
+        let header = (self.config.feature_type() as u16) << 9 | (4 + self.config.len()) as u16;
+        buf.put_u16(header);
+        buf.put_u8(self.oper_version);
+        buf.put_u8(self.max_version);
+        buf.put_u8((self.enabled as u8) << 7 | (self.willing as u8) << 6 | (self.error as u8) << 5);
+        buf.put_u8(self.subtype);
+
+        match &self.config {
+            CeeFeatureConfig::PriorityGroups {
+                pg_ids,
+                pg_bandwidth,
+                num_tcs,
+            } => {
+                encode_nibbles(pg_ids, buf);
+                buf.put_slice(pg_bandwidth);
+                buf.put_u8(*num_tcs);
+            }
+            CeeFeatureConfig::Pfc { enabled, num_tcs } => {
+                buf.put_u8(*enabled);
+                buf.put_u8(*num_tcs);
+            }
+            CeeFeatureConfig::Application(entries) => {
+                for entry in entries {
+                    buf.put_u16(entry.protocol);
+                    buf.put_u8(((entry.oui >> 16) as u8 & 0x3F) << 2 | entry.selector & 0x03);
+                    buf.put_u16(entry.oui as u16);
+                    buf.put_u8(entry.priority_map);
+                }
+            }
+            CeeFeatureConfig::Unknown { data, .. } => buf.put_slice(data),
+        }
+    }
+
+    /// Decode the value of a feature sub-TLV, returns `None` if the length does not match the feature type.
+    fn decode(feature_type: u8, value: &[u8]) -> Option<CeeFeature> {
+        if value.len() < 4 {
+            return None;
+        }
+
+        let data = &value[4..];
+        let config = match feature_type {
+            2 if data.len() == 13 => {
+                let mut pg_bandwidth = [0; NUM_PRIORITIES];
+                pg_bandwidth.copy_from_slice(&data[4..12]);
+                CeeFeatureConfig::PriorityGroups {
+                    pg_ids: decode_nibbles(&data[..4]),
+                    pg_bandwidth,
+                    num_tcs: data[12],
+                }
+            }
+            3 if data.len() == 2 => CeeFeatureConfig::Pfc {
+                enabled: data[0],
+                num_tcs: data[1],
+            },
+            4 if data.len() % 6 == 0 => CeeFeatureConfig::Application(
+                data.chunks(6)
+                    .map(|entry| CeeAppEntry {
+                        protocol: u16::from_be_bytes([entry[0], entry[1]]),
+                        selector: entry[2] & 0x03,
+                        oui: ((entry[2] >> 2) as u32) << 16
+                            | u16::from_be_bytes([entry[3], entry[4]]) as u32,
+                        priority_map: entry[5],
+                    })
+                    .collect(),
+            ),
+            2..=4 => return None,
+            _ => CeeFeatureConfig::Unknown {
+                feature_type,
+                data: data.to_vec(),
+            },
+        };
+
+        Some(CeeFeature {
+            oper_version: value[0],
+            max_version: value[1],
+            enabled: value[2] & 0x80 != 0,
+            willing: value[2] & 0x40 != 0,
+            error: value[2] & 0x20 != 0,
+            subtype: value[3],
+            config,
+        })
+    }
+}
+
+impl Display for CeeFeature {
+    /// Write the feature, e.g. `PFC(enabled, willing, PFC on [3], 8 TCs)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.config.feature_type() {
+            2 => write!(f, "PG(")?,
+            3 => write!(f, "PFC(")?,
+            4 => write!(f, "App(")?,
+            feature_type => write!(f, "Feature{}(", feature_type)?,
+        }
+
+        write!(f, "{}", if self.enabled { "enabled" } else { "disabled" })?;
+        if self.willing {
+            write!(f, ", willing")?;
+        }
+        if self.error {
+            write!(f, ", error")?;
+        }
+
+        write!(f, ", {})", self.config)
+    }
+}
+
+/// Legacy CEE DCBX TLV
+///
+/// Carries the control sub-TLV and the feature sub-TLVs of the CEE version of DCBX. Each sub-TLV starts with a 7 bit
+/// type and a 9 bit length.
+///
+/// | Subtype | Information                                                |
+/// | ------- | ---------------------------------------------------------- |
+/// |    2    | Control sub-TLV (type 1), Feature sub-TLVs (types 2 - 127) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CeeDcbxTLV {
+    /// The control sub-TLV, which should always be present
+    pub control: Option<CeeControl>,
+    /// The feature sub-TLVs
+    pub features: Vec<CeeFeature>,
+}
+
+impl CeeDcbxTLV {
+    /// Constructor
+    pub fn new(control: CeeControl, features: Vec<CeeFeature>) -> CeeDcbxTLV {
+        CeeDcbxTLV {
+            control: Some(control),
+            features,
+        }
+    }
+}
+
+impl Display for CeeDcbxTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CeeDcbxTLV(")?;
+        match &self.control {
+            Some(control) => write!(
+                f,
+                "version {}/{}, seq {}, ack {}",
+                control.oper_version, control.max_version, control.seq_no, control.ack_no
+            )?,
+            None => write!(f, "no control")?,
+        }
+
+        for feature in &self.features {
+            write!(f, "; {}", feature)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl OrgTlvCodec for CeeDcbxTLV {
+    const OUI: [u8; 3] = CEE_OUI;
+    const SUBTYPE: u8 = 2;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        if let Some(control) = &self.control {
+            buf.put_u16(1 << 9 | 10);
+            buf.put_u8(control.oper_version);
+            buf.put_u8(control.max_version);
+            buf.put_u32(control.seq_no);
+            buf.put_u32(control.ack_no);
+        }
+
+        for feature in &self.features {
+            feature.encode(buf);
+        }
+    }
+
+    /// Returns an error if a sub-TLV is truncated or its length does not match its type.
+    fn decode_info(info: &[u8]) -> Result<CeeDcbxTLV, LldpError> {
+        let mut tlv = CeeDcbxTLV {
+            control: None,
+            features: vec![],
+        };
+
+        let mut rest = info;
+        while !rest.is_empty() {
+            if rest.len() < 2 {
+                return Err(bad_info_len(info));
+            }
+            let header = u16::from_be_bytes([rest[0], rest[1]]);
+            let length = (header & 0x01FF) as usize;
+            if rest.len() < 2 + length {
+                return Err(bad_info_len(info));
+            }
+            let (value, tail) = rest[2..].split_at(length);
+
+            match (header >> 9) as u8 {
+                1 if length == 10 => {
+                    tlv.control = Some(CeeControl {
+                        oper_version: value[0],
+                        max_version: value[1],
+                        seq_no: u32::from_be_bytes([value[2], value[3], value[4], value[5]]),
+                        ack_no: u32::from_be_bytes([value[6], value[7], value[8], value[9]]),
+                    })
+                }
+                1 => return Err(bad_info_len(info)),
+                feature_type => match CeeFeature::decode(feature_type, value) {
+                    Some(feature) => tlv.features.push(feature),
+                    None => return Err(bad_info_len(info)),
+                },
+            }
+
+            rest = tail;
+        }
+
+        Ok(tlv)
+    }
+}
+
+/// DCBX Organizationally Specific TLVs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DcbxTlv {
+    EtsConfiguration(EtsConfigurationTLV),
+    EtsRecommendation(EtsRecommendationTLV),
+    PfcConfiguration(PfcConfigurationTLV),
+    ApplicationPriority(ApplicationPriorityTLV),
+    Cee(CeeDcbxTLV),
+}
+
+org_tlv_dispatch! {
+    DcbxTlv {
+        EtsConfiguration(EtsConfigurationTLV),
+        EtsRecommendation(EtsRecommendationTLV),
+        PfcConfiguration(PfcConfigurationTLV),
+        ApplicationPriority(ApplicationPriorityTLV),
+        Cee(CeeDcbxTLV),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::tests::roundtrip;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+    use crate::tlv::{TlvCodec, TlvType};
+
+    fn tables() -> EtsTables {
+        EtsTables::new(
+            [0, 0, 0, 1, 2, 2, 2, 2],
+            [50, 50, 0, 0, 0, 0, 0, 0],
+            [2, 2, 0, 0, 0, 0, 0, 0],
+        )
+    }
+
+    #[test]
+    fn test_ets_configuration() {
+        let tlv = roundtrip::<EtsConfigurationTLV>(
+            b"\xFE\x19\x00\x80\xC2\x09\x80\x00\x01\x22\x22\x32\x32\x00\x00\x00\x00\x00\x00\x02\x02\x00\x00\x00\x00\
+              \x00\x00",
+        );
+        assert_eq!(tlv, EtsConfigurationTLV::new(true, 8, tables()));
+        assert_eq!(
+            tlv.to_string(),
+            "EtsConfigurationTLV(willing, 8 TCs, TC0: ETS 50% [0 1 2]; TC1: ETS 50% [3]; TC2: strict priority [4 5 \
+             6 7])"
+        );
+    }
+
+    #[test]
+    fn test_ets_recommendation() {
+        let tlv = EtsRecommendationTLV::new(tables());
+        let decoded = roundtrip::<EtsRecommendationTLV>(&tlv.to_tlv().bytes());
+        assert_eq!(decoded, tlv);
+        assert_eq!(tables().priorities(2), vec![4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_pfc_configuration() {
+        let tlv = roundtrip::<PfcConfigurationTLV>(b"\xFE\x06\x00\x80\xC2\x0B\x48\x08");
+        assert_eq!(
+            tlv.to_string(),
+            "PfcConfigurationTLV(not willing, MBC, 8 TCs, PFC on [3])"
+        );
+    }
+
+    #[test]
+    fn test_application_priority() {
+        let tlv = roundtrip::<ApplicationPriorityTLV>(
+            b"\xFE\x0B\x00\x80\xC2\x0C\x00\x61\x89\x06\x82\x0C\xBC",
+        );
+        assert_eq!(
+            tlv,
+            ApplicationPriorityTLV::new(vec![
+                AppPriority::new(3, 1, 0x8906),
+                AppPriority::new(4, 2, 3260),
+            ])
+        );
+        assert_eq!(
+            tlv.to_string(),
+            "ApplicationPriorityTLV(Ethertype 0x8906 -> 3, TCP/SCTP port 3260 -> 4)"
+        );
+    }
+
+    #[test]
+    fn test_cee() {
+        let tlv = roundtrip::<CeeDcbxTLV>(
+            b"\xFE\x37\x00\x1B\x21\x02\
+              \x02\x0A\x00\x00\x00\x00\x00\x01\x00\x00\x00\x00\
+              \x04\x11\x00\x00\xC0\x00\x00\x01\x11\x1F\x32\x32\x00\x00\x00\x00\x00\x00\x08\
+              \x06\x06\x00\x00\xC0\x00\x08\x08\
+              \x08\x0A\x00\x00\x80\x00\x89\x06\x00\x1B\x21\x08",
+        );
+        assert_eq!(
+            tlv,
+            CeeDcbxTLV::new(
+                CeeControl::new(1, 0),
+                vec![
+                    CeeFeature::new(
+                        true,
+                        CeeFeatureConfig::PriorityGroups {
+                            pg_ids: [0, 0, 0, 1, 1, 1, 1, 15],
+                            pg_bandwidth: [50, 50, 0, 0, 0, 0, 0, 0],
+                            num_tcs: 8,
+                        }
+                    ),
+                    CeeFeature::new(
+                        true,
+                        CeeFeatureConfig::Pfc {
+                            enabled: 0x08,
+                            num_tcs: 8
+                        }
+                    ),
+                    CeeFeature::new(
+                        false,
+                        CeeFeatureConfig::Application(vec![CeeAppEntry::new(0x8906, 0, 0x08)])
+                    ),
+                ]
+            )
+        );
+        assert_eq!(
+            tlv.to_string(),
+            "CeeDcbxTLV(version 0/0, seq 1, ack 0; PG(enabled, willing, PG0: 50% [0 1 2]; PG1: 50% [3 4 5 6]; \
+             PG15: strict [7]; 8 TCs); PFC(enabled, willing, PFC on [3], 8 TCs); App(enabled, Ethertype 0x8906 -> \
+             [3]))"
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        let bad_length = |length| LldpError::BadLength {
+            tlv_type: TlvType::OrganizationallySpecific,
+            length,
+        };
+
+        let tlv = OrganizationallySpecificTLV::new(ieee8021::OUI.to_vec(), 9, vec![0; 20]);
+        assert_eq!(DcbxTlv::decode(&tlv), Some(Err(bad_length(24))));
+
+        let tlv = OrganizationallySpecificTLV::new(ieee8021::OUI.to_vec(), 12, vec![0; 3]);
+        assert_eq!(DcbxTlv::decode(&tlv), Some(Err(bad_length(7))));
+
+        // Truncated sub-TLV
+        let tlv =
+            OrganizationallySpecificTLV::new(CEE_OUI.to_vec(), 2, b"\x06\x06\x00\x00".to_vec());
+        assert_eq!(DcbxTlv::decode(&tlv), Some(Err(bad_length(8))));
+
+        // PFC feature with a PG configuration
+        let tlv = OrganizationallySpecificTLV::new(
+            CEE_OUI.to_vec(),
+            2,
+            b"\x06\x05\x00\x00\x00\x00\x00".to_vec(),
+        );
+        assert_eq!(DcbxTlv::decode(&tlv), Some(Err(bad_length(11))));
+    }
+}
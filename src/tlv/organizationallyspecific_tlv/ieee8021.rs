@@ -0,0 +1,944 @@
+//! IEEE 802.1 Organizationally Specific TLVs
+//!
+//! TLVs defined by IEEE 802.1Q (including the EVB and CDCP TLVs of IEEE 802.1Qbg) and IEEE 802.1AX (Annex D and F of
+//! IEEE 802.1Q), transmitted with the OUI 00-80-C2. The DCBX TLVs are defined in [super::dcbx].
+//!
+//! ```
+//! use lldp_rs::tlv::organizationallyspecific_tlv::ieee8021::{Ieee8021Tlv, VlanNameTLV};
+//! use lldp_rs::tlv::organizationallyspecific_tlv::OrgTlvCodec;
+//!
+//! let tlv = VlanNameTLV::new(100, String::from("mgmt")).to_tlv();
+//! assert_eq!(tlv.value, b"\x00\x64\x04mgmt");
+//!
+//! match Ieee8021Tlv::decode(&tlv) {
+//!     Some(Ok(Ieee8021Tlv::VlanName(vlan))) => assert_eq!(vlan.name, "mgmt"),
+//!     _ => panic!("expected a VLAN Name TLV"),
+//! }
+//! ```
+
+use crate::error::LldpError;
+use crate::tlv::organizationallyspecific_tlv::{bad_info_len, check_info_len, OrgTlvCodec};
+use crate::tlv::TlvType;
+use bytes::BufMut;
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+/// OUI of IEEE 802.1
+pub const OUI: [u8; 3] = [0x00, 0x80, 0xC2];
+
+/// Maximum length of a VLAN name in bytes
+pub const MAX_VLAN_NAME_LEN: usize = 32;
+
+/// Maximum length of a protocol identity in bytes
+pub const MAX_PROTOCOL_IDENTITY_LEN: usize = 255;
+
+/// Port VLAN ID TLV
+///
+/// Advertises the VLAN identifier (PVID) that is associated with untagged or priority tagged frames received on the
+/// port.
+///
+/// | Subtype | Information     |
+/// | ------- | --------------- |
+/// |    1    | PVID (2 bytes)  |
+///
+/// A PVID of 0 indicates that the port does not support port based VLANs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PortVlanIdTLV {
+    /// The port VLAN identifier
+    pub pvid: u16,
+}
+
+impl PortVlanIdTLV {
+    /// Constructor
+    pub fn new(pvid: u16) -> PortVlanIdTLV {
+        PortVlanIdTLV { pvid }
+    }
+}
+
+impl Display for PortVlanIdTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PortVlanIdTLV({})", self.pvid)
+    }
+}
+
+impl OrgTlvCodec for PortVlanIdTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 1;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.pvid);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<PortVlanIdTLV, LldpError> {
+        check_info_len(info, 2..=2)?;
+
+        Ok(PortVlanIdTLV::new(u16::from_be_bytes([info[0], info[1]])))
+    }
+}
+
+/// Port And Protocol VLAN ID TLV
+///
+/// Advertises a port and protocol VLAN identifier (PPVID) and whether port and protocol VLANs are supported and
+/// enabled on the port.
+///
+/// | Subtype | Information                          |
+/// | ------- | ------------------------------------ |
+/// |    2    | Flags (1 byte), PPVID (2 bytes)      |
+///
+/// Bit 1 of the flags indicates support, bit 2 indicates that port and protocol VLANs are enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PortAndProtocolVlanIdTLV {
+    /// Port and protocol VLANs are supported
+    pub supported: bool,
+    /// Port and protocol VLANs are enabled
+    pub enabled: bool,
+    /// The port and protocol VLAN identifier
+    pub ppvid: u16,
+}
+
+impl PortAndProtocolVlanIdTLV {
+    /// Constructor
+    pub fn new(supported: bool, enabled: bool, ppvid: u16) -> PortAndProtocolVlanIdTLV {
+        PortAndProtocolVlanIdTLV {
+            supported,
+            enabled,
+            ppvid,
+        }
+    }
+}
+
+impl Display for PortAndProtocolVlanIdTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PortAndProtocolVlanIdTLV({}, {}, {})",
+            self.ppvid,
+            if self.supported {
+                "supported"
+            } else {
+                "not supported"
+            },
+            if self.enabled { "enabled" } else { "disabled" }
+        )
+    }
+}
+
+impl OrgTlvCodec for PortAndProtocolVlanIdTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 2;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8((self.supported as u8) << 1 | (self.enabled as u8) << 2);
+        buf.put_u16(self.ppvid);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<PortAndProtocolVlanIdTLV, LldpError> {
+        check_info_len(info, 3..=3)?;
+
+        Ok(PortAndProtocolVlanIdTLV::new(
+            info[0] & 0x02 != 0,
+            info[0] & 0x04 != 0,
+            u16::from_be_bytes([info[1], info[2]]),
+        ))
+    }
+}
+
+/// VLAN Name TLV
+///
+/// Advertises the name assigned to a VLAN.
+///
+/// | Subtype | Information                                                         |
+/// | ------- | ------------------------------------------------------------------- |
+/// |    3    | VID (2 bytes), Name Length (1 byte), VLAN Name (1 - 32 bytes)       |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VlanNameTLV {
+    /// The VLAN identifier
+    pub vid: u16,
+    /// The name of the VLAN
+    pub name: String,
+}
+
+impl VlanNameTLV {
+    /// Constructor
+    pub fn new(vid: u16, name: String) -> VlanNameTLV {
+        VlanNameTLV { vid, name }
+    }
+
+    /// Create a VLAN Name TLV, checking the length of the name.
+    ///
+    /// Returns an error if the name is empty or longer than 32 bytes.
+    pub fn try_new(vid: u16, name: String) -> Result<VlanNameTLV, LldpError> {
+        if !(1..=MAX_VLAN_NAME_LEN).contains(&name.len()) {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 7 + name.len(),
+            });
+        }
+
+        Ok(VlanNameTLV::new(vid, name))
+    }
+}
+
+impl Display for VlanNameTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VlanNameTLV({}, \"{}\")", self.vid, self.name)
+    }
+}
+
+impl OrgTlvCodec for VlanNameTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 3;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.vid);
+        buf.put_u8(self.name.len() as u8);
+        buf.put_slice(self.name.as_bytes());
+    }
+
+    /// Returns an error if the name is empty, longer than 32 bytes or does not match the name length.
+    fn decode_info(info: &[u8]) -> Result<VlanNameTLV, LldpError> {
+        check_info_len(info, 4..=3 + MAX_VLAN_NAME_LEN)?;
+        check_info_len(info, 3 + info[2] as usize..=3 + info[2] as usize)?;
+
+        let name = String::from_utf8(info[3..].to_vec())
+            .map_err(|_| LldpError::InvalidUtf8(TlvType::OrganizationallySpecific))?;
+
+        Ok(VlanNameTLV::new(
+            u16::from_be_bytes([info[0], info[1]]),
+            name,
+        ))
+    }
+}
+
+/// Protocol Identity TLV
+///
+/// Advertises a protocol that is accessible through the port, identified by the first bytes of its frames following
+/// the length/type field, e.g. `42 42 03 00 00` for the Spanning Tree Protocol.
+///
+/// | Subtype | Information                                                      |
+/// | ------- | ---------------------------------------------------------------- |
+/// |    4    | Identity Length (1 byte), Protocol Identity (0 - 255 bytes)      |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtocolIdentityTLV {
+    /// The protocol identity
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::hex"))]
+    pub identity: Vec<u8>,
+}
+
+impl ProtocolIdentityTLV {
+    /// Constructor
+    pub fn new(identity: Vec<u8>) -> ProtocolIdentityTLV {
+        ProtocolIdentityTLV { identity }
+    }
+
+    /// Create a Protocol Identity TLV, checking the length of the identity.
+    ///
+    /// Returns an error if the identity is longer than 255 bytes.
+    pub fn try_new(identity: Vec<u8>) -> Result<ProtocolIdentityTLV, LldpError> {
+        if identity.len() > MAX_PROTOCOL_IDENTITY_LEN {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 5 + identity.len(),
+            });
+        }
+
+        Ok(ProtocolIdentityTLV::new(identity))
+    }
+}
+
+impl Display for ProtocolIdentityTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut identity = String::new();
+        for i in &self.identity {
+            identity.push_str(&format!("{:02X}", i));
+        }
+
+        write!(f, "ProtocolIdentityTLV(\"{}\")", identity)
+    }
+}
+
+impl OrgTlvCodec for ProtocolIdentityTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 4;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8(self.identity.len() as u8);
+        buf.put_slice(&self.identity);
+    }
+
+    /// Returns an error if the identity does not match the identity length.
+    fn decode_info(info: &[u8]) -> Result<ProtocolIdentityTLV, LldpError> {
+        check_info_len(info, 1..=1 + MAX_PROTOCOL_IDENTITY_LEN)?;
+        check_info_len(info, 1 + info[0] as usize..=1 + info[0] as usize)?;
+
+        Ok(ProtocolIdentityTLV::new(info[1..].to_vec()))
+    }
+}
+
+/// VID Usage Digest TLV
+///
+/// Advertises the CRC32 digest of the VID to MSTI allocation table of the port, see IEEE 802.1Q clause 13.
+///
+/// | Subtype | Information                 |
+/// | ------- | --------------------------- |
+/// |    5    | VID Usage Digest (4 bytes)  |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VidUsageDigestTLV {
+    /// The VID usage digest
+    pub digest: u32,
+}
+
+impl VidUsageDigestTLV {
+    /// Constructor
+    pub fn new(digest: u32) -> VidUsageDigestTLV {
+        VidUsageDigestTLV { digest }
+    }
+}
+
+impl Display for VidUsageDigestTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VidUsageDigestTLV({:#010X})", self.digest)
+    }
+}
+
+impl OrgTlvCodec for VidUsageDigestTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 5;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u32(self.digest);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<VidUsageDigestTLV, LldpError> {
+        check_info_len(info, 4..=4)?;
+
+        Ok(VidUsageDigestTLV::new(u32::from_be_bytes([
+            info[0], info[1], info[2], info[3],
+        ])))
+    }
+}
+
+/// Management VID TLV
+///
+/// Advertises the VLAN identifier used for management of the system. A VID of 0 indicates that the system has no
+/// management VLAN.
+///
+/// | Subtype | Information               |
+/// | ------- | ------------------------- |
+/// |    6    | Management VID (2 bytes)  |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ManagementVidTLV {
+    /// The management VLAN identifier
+    pub vid: u16,
+}
+
+impl ManagementVidTLV {
+    /// Constructor
+    pub fn new(vid: u16) -> ManagementVidTLV {
+        ManagementVidTLV { vid }
+    }
+}
+
+impl Display for ManagementVidTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ManagementVidTLV({})", self.vid)
+    }
+}
+
+impl OrgTlvCodec for ManagementVidTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 6;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.vid);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<ManagementVidTLV, LldpError> {
+        check_info_len(info, 2..=2)?;
+
+        Ok(ManagementVidTLV::new(u16::from_be_bytes([
+            info[0], info[1],
+        ])))
+    }
+}
+
+/// Port type of a link aggregation member, see [LinkAggregationTLV]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AggregationPortType {
+    NotSpecified = 0,
+    AggregationPort = 1,
+    Aggregator = 2,
+    AggregatorWithSinglePort = 3,
+}
+
+impl TryFrom<u8> for AggregationPortType {
+    type Error = ();
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0 => Ok(AggregationPortType::NotSpecified),
+            1 => Ok(AggregationPortType::AggregationPort),
+            2 => Ok(AggregationPortType::Aggregator),
+            3 => Ok(AggregationPortType::AggregatorWithSinglePort),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Link Aggregation TLV
+///
+/// Advertises whether the link can be aggregated, whether it currently is aggregated and the identifier of the
+/// aggregated port.
+///
+/// | Subtype | Information                                               |
+/// | ------- | --------------------------------------------------------- |
+/// |    7    | Aggregation Status (1 byte), Aggregated Port ID (4 bytes) |
+///
+/// Bit 0 of the status indicates the aggregation capability, bit 1 the aggregation status and bits 2 and 3 the port
+/// type. The port ID is 0 if the port is not aggregated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinkAggregationTLV {
+    /// The link is capable of being aggregated
+    pub capable: bool,
+    /// The link is currently aggregated
+    pub enabled: bool,
+    /// The type of the port
+    pub port_type: AggregationPortType,
+    /// The interface index of the aggregated port
+    pub port_id: u32,
+}
+
+impl LinkAggregationTLV {
+    /// Constructor
+    pub fn new(
+        capable: bool,
+        enabled: bool,
+        port_type: AggregationPortType,
+        port_id: u32,
+    ) -> LinkAggregationTLV {
+        LinkAggregationTLV {
+            capable,
+            enabled,
+            port_type,
+            port_id,
+        }
+    }
+
+    /// Get the aggregation status byte
+    pub(crate) fn status(&self) -> u8 {
+        self.capable as u8 | (self.enabled as u8) << 1 | (self.port_type as u8) << 2
+    }
+
+    /// Create a TLV instance from the aggregation status byte and the port ID
+    pub(crate) fn from_status(status: u8, port_id: u32) -> LinkAggregationTLV {
+        let port_type = AggregationPortType::try_from((status >> 2) & 0x03)
+            .unwrap_or(AggregationPortType::NotSpecified);
+
+        LinkAggregationTLV::new(status & 0x01 != 0, status & 0x02 != 0, port_type, port_id)
+    }
+}
+
+impl Display for LinkAggregationTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LinkAggregationTLV({}, {}, {:?}, {})",
+            if self.capable {
+                "capable"
+            } else {
+                "not capable"
+            },
+            if self.enabled {
+                "aggregated"
+            } else {
+                "not aggregated"
+            },
+            self.port_type,
+            self.port_id
+        )
+    }
+}
+
+impl OrgTlvCodec for LinkAggregationTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 7;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8(self.status());
+        buf.put_u32(self.port_id);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<LinkAggregationTLV, LldpError> {
+        check_info_len(info, 5..=5)?;
+
+        Ok(LinkAggregationTLV::from_status(
+            info[0],
+            u32::from_be_bytes([info[1], info[2], info[3], info[4]]),
+        ))
+    }
+}
+
+/// Edge Virtual Bridging TLV
+///
+/// Advertises the EVB capabilities of a bridge or station (IEEE 802.1Qbg) and the parameters of the ECP and VDP
+/// protocols.
+///
+/// | Subtype | Information                                                                                        |
+/// | ------- | -------------------------------------------------------------------------------------------------- |
+/// |   13    | Bridge Status (1 byte), Station Status (1 byte), R/RTE (1 byte), Mode/ROL/RWD (1 byte), ROL/RKA (1 byte) |
+///
+/// The bridge status holds the BGID, RRCAP and RRCTR bits, the station status the SGID and RRREQ bits and the 2 bit
+/// RRSTAT field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EvbTLV {
+    /// The bridge supports the group ID in VDP (BGID)
+    pub bgid: bool,
+    /// The bridge supports reflective relay (RRCAP)
+    pub rrcap: bool,
+    /// Reflective relay is enabled on the bridge port (RRCTR)
+    pub rrctr: bool,
+    /// The station supports the group ID in VDP (SGID)
+    pub sgid: bool,
+    /// The station requests reflective relay (RRREQ)
+    pub rrreq: bool,
+    /// The reflective relay status of the station (RRSTAT, 2 bits)
+    pub rrstat: u8,
+    /// The maximum number of ECP retransmissions (R, 3 bits)
+    pub r: u8,
+    /// The exponent of the ECP retransmission timer (RTE, 5 bits)
+    pub rte: u8,
+    /// The EVB mode (0: not supported, 1: EVB Bridge, 2: EVB station)
+    pub mode: u8,
+    /// The resource wait delay is set by the remote station (ROL)
+    pub rol_rwd: bool,
+    /// The exponent of the VDP resource wait delay (RWD, 5 bits)
+    pub rwd: u8,
+    /// The reinit keep alive is set by the remote station (ROL)
+    pub rol_rka: bool,
+    /// The exponent of the VDP reinit keep alive (RKA, 5 bits)
+    pub rka: u8,
+}
+
+impl EvbTLV {
+    /// Create a TLV with local timer values and all status bits cleared
+    pub fn new(mode: u8, r: u8, rte: u8, rwd: u8, rka: u8) -> EvbTLV {
+        EvbTLV {
+            bgid: false,
+            rrcap: false,
+            rrctr: false,
+            sgid: false,
+            rrreq: false,
+            rrstat: 0,
+            r,
+            rte,
+            mode,
+            rol_rwd: false,
+            rwd,
+            rol_rka: false,
+            rka,
+        }
+    }
+}
+
+impl Display for EvbTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EvbTLV(")?;
+        match self.mode {
+            0 => write!(f, "not supported")?,
+            1 => write!(f, "EVB Bridge")?,
+            2 => write!(f, "EVB station")?,
+            mode => write!(f, "mode {}", mode)?,
+        }
+
+        let flags: Vec<&str> = [
+            (self.bgid, "BGID"),
+            (self.rrcap, "RRCAP"),
+            (self.rrctr, "RRCTR"),
+            (self.sgid, "SGID"),
+            (self.rrreq, "RRREQ"),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, flag)| *flag)
+        .collect();
+
+        write!(
+            f,
+            ", [{}], RRSTAT {}, R {}, RTE {}, RWD {}{}, RKA {}{})",
+            flags.join(" "),
+            self.rrstat,
+            self.r,
+            self.rte,
+            self.rwd,
+            if self.rol_rwd { " remote" } else { "" },
+            self.rka,
+            if self.rol_rka { " remote" } else { "" }
+        )
+    }
+}
+
+impl OrgTlvCodec for EvbTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 13;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8((self.bgid as u8) << 2 | (self.rrcap as u8) << 1 | self.rrctr as u8);
+        buf.put_u8((self.sgid as u8) << 3 | (self.rrreq as u8) << 2 | self.rrstat & 0x03);
+        buf.put_u8((self.r & 0x07) << 5 | self.rte & 0x1F);
+        buf.put_u8((self.mode & 0x03) << 6 | (self.rol_rwd as u8) << 5 | self.rwd & 0x1F);
+        buf.put_u8((self.rol_rka as u8) << 5 | self.rka & 0x1F);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<EvbTLV, LldpError> {
+        check_info_len(info, 5..=5)?;
+
+        Ok(EvbTLV {
+            bgid: info[0] & 0x04 != 0,
+            rrcap: info[0] & 0x02 != 0,
+            rrctr: info[0] & 0x01 != 0,
+            sgid: info[1] & 0x08 != 0,
+            rrreq: info[1] & 0x04 != 0,
+            rrstat: info[1] & 0x03,
+            r: info[2] >> 5,
+            rte: info[2] & 0x1F,
+            mode: info[3] >> 6,
+            rol_rwd: info[3] & 0x20 != 0,
+            rwd: info[3] & 0x1F,
+            rol_rka: info[4] & 0x20 != 0,
+            rka: info[4] & 0x1F,
+        })
+    }
+}
+
+/// S-channel of the [CdcpTLV]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SChannel {
+    /// The S-channel identifier (12 bits)
+    pub scid: u16,
+    /// The S-VLAN identifier assigned to the S-channel (12 bits), 0 if not assigned yet
+    pub svid: u16,
+}
+
+impl SChannel {
+    /// Constructor
+    pub fn new(scid: u16, svid: u16) -> SChannel {
+        SChannel { scid, svid }
+    }
+}
+
+/// S-Channel Discovery and Configuration Protocol TLV
+///
+/// Advertises the S-channels requested by a station or the S-channels and S-VLANs assigned by a bridge
+/// (IEEE 802.1Qbg).
+///
+/// | Subtype | Information                                                                                     |
+/// | ------- | ----------------------------------------------------------------------------------------------- |
+/// |   14    | Role (1 bit), Reserved (3 bits), SComp (1 bit), Reserved (15 bits), ChnCap (12 bits), SCID/SVID pairs (3 bytes each) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CdcpTLV {
+    /// The sender is a station, otherwise a bridge
+    pub station: bool,
+    /// The S-VLAN component is present
+    pub scomp: bool,
+    /// The number of S-channels supported (12 bits)
+    pub channel_capacity: u16,
+    /// The S-channels
+    pub channels: Vec<SChannel>,
+}
+
+impl CdcpTLV {
+    /// Constructor
+    pub fn new(station: bool, channel_capacity: u16, channels: Vec<SChannel>) -> CdcpTLV {
+        CdcpTLV {
+            station,
+            scomp: false,
+            channel_capacity,
+            channels,
+        }
+    }
+}
+
+impl Display for CdcpTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let channels: Vec<String> = self
+            .channels
+            .iter()
+            .map(|channel| format!("SCID {} SVID {}", channel.scid, channel.svid))
+            .collect();
+
+        write!(
+            f,
+            "CdcpTLV({}, {}{} channels, [{}])",
+            if self.station { "station" } else { "bridge" },
+            if self.scomp { "SComp, " } else { "" },
+            self.channel_capacity,
+            channels.join(", ")
+        )
+    }
+}
+
+impl OrgTlvCodec for CdcpTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 14;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u32(
+            (self.station as u32) << 31
+                | (self.scomp as u32) << 27
+                | self.channel_capacity as u32 & 0x0FFF,
+        );
+        for channel in &self.channels {
+            let pair = (channel.scid as u32 & 0x0FFF) << 12 | channel.svid as u32 & 0x0FFF;
+            buf.put_slice(&pair.to_be_bytes()[1..]);
+        }
+    }
+
+    fn decode_info(info: &[u8]) -> Result<CdcpTLV, LldpError> {
+        if info.len() < 4 || (info.len() - 4) % 3 != 0 {
+            return Err(bad_info_len(info));
+        }
+
+        let header = u32::from_be_bytes([info[0], info[1], info[2], info[3]]);
+        let channels = info[4..]
+            .chunks(3)
+            .map(|pair| {
+                let pair = u32::from_be_bytes([0, pair[0], pair[1], pair[2]]);
+                SChannel::new((pair >> 12) as u16, (pair & 0x0FFF) as u16)
+            })
+            .collect();
+
+        Ok(CdcpTLV {
+            station: header & (1 << 31) != 0,
+            scomp: header & (1 << 27) != 0,
+            channel_capacity: (header & 0x0FFF) as u16,
+            channels,
+        })
+    }
+}
+
+/// IEEE 802.1 Organizationally Specific TLVs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ieee8021Tlv {
+    PortVlanId(PortVlanIdTLV),
+    PortAndProtocolVlanId(PortAndProtocolVlanIdTLV),
+    VlanName(VlanNameTLV),
+    ProtocolIdentity(ProtocolIdentityTLV),
+    VidUsageDigest(VidUsageDigestTLV),
+    ManagementVid(ManagementVidTLV),
+    LinkAggregation(LinkAggregationTLV),
+    Evb(EvbTLV),
+    Cdcp(CdcpTLV),
+}
+
+org_tlv_dispatch! {
+    Ieee8021Tlv {
+        PortVlanId(PortVlanIdTLV),
+        PortAndProtocolVlanId(PortAndProtocolVlanIdTLV),
+        VlanName(VlanNameTLV),
+        ProtocolIdentity(ProtocolIdentityTLV),
+        VidUsageDigest(VidUsageDigestTLV),
+        ManagementVid(ManagementVidTLV),
+        LinkAggregation(LinkAggregationTLV),
+        Evb(EvbTLV),
+        Cdcp(CdcpTLV),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::tests::roundtrip;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+    use crate::tlv::TlvCodec;
+
+    #[test]
+    fn test_port_vlan_id() {
+        let tlv = roundtrip::<PortVlanIdTLV>(b"\xFE\x06\x00\x80\xC2\x01\x00\x64");
+        assert_eq!(tlv, PortVlanIdTLV::new(100));
+        assert_eq!(tlv.to_string(), "PortVlanIdTLV(100)");
+    }
+
+    #[test]
+    fn test_port_and_protocol_vlan_id() {
+        let tlv = roundtrip::<PortAndProtocolVlanIdTLV>(b"\xFE\x07\x00\x80\xC2\x02\x02\x00\x0A");
+        assert_eq!(tlv, PortAndProtocolVlanIdTLV::new(true, false, 10));
+        assert_eq!(
+            tlv.to_string(),
+            "PortAndProtocolVlanIdTLV(10, supported, disabled)"
+        );
+    }
+
+    #[test]
+    fn test_vlan_name() {
+        let tlv = roundtrip::<VlanNameTLV>(b"\xFE\x0B\x00\x80\xC2\x03\x00\x64\x04mgmt");
+        assert_eq!(tlv, VlanNameTLV::new(100, String::from("mgmt")));
+        assert_eq!(tlv.to_string(), "VlanNameTLV(100, \"mgmt\")");
+    }
+
+    #[test]
+    fn test_protocol_identity() {
+        let tlv =
+            roundtrip::<ProtocolIdentityTLV>(b"\xFE\x0A\x00\x80\xC2\x04\x05\x42\x42\x03\x00\x00");
+        assert_eq!(tlv.to_string(), "ProtocolIdentityTLV(\"4242030000\")");
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            VlanNameTLV::try_new(1, "x".repeat(32)).unwrap().name.len(),
+            32
+        );
+        assert_eq!(
+            VlanNameTLV::try_new(1, String::new()).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 7
+            }
+        );
+        assert_eq!(
+            VlanNameTLV::try_new(1, "x".repeat(33)).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 40
+            }
+        );
+
+        assert!(ProtocolIdentityTLV::try_new(Vec::new())
+            .unwrap()
+            .identity
+            .is_empty());
+        assert_eq!(
+            ProtocolIdentityTLV::try_new(vec![0; 255])
+                .unwrap()
+                .to_tlv()
+                .value
+                .len(),
+            256
+        );
+        assert_eq!(
+            ProtocolIdentityTLV::try_new(vec![0; 256]).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 261
+            }
+        );
+    }
+
+    #[test]
+    fn test_vid_usage_digest() {
+        let tlv = roundtrip::<VidUsageDigestTLV>(b"\xFE\x08\x00\x80\xC2\x05\xDE\xAD\xBE\xEF");
+        assert_eq!(tlv.to_string(), "VidUsageDigestTLV(0xDEADBEEF)");
+    }
+
+    #[test]
+    fn test_management_vid() {
+        let tlv = roundtrip::<ManagementVidTLV>(b"\xFE\x06\x00\x80\xC2\x06\x00\x01");
+        assert_eq!(tlv, ManagementVidTLV::new(1));
+        assert_eq!(tlv.to_string(), "ManagementVidTLV(1)");
+    }
+
+    #[test]
+    fn test_link_aggregation() {
+        let tlv = roundtrip::<LinkAggregationTLV>(b"\xFE\x09\x00\x80\xC2\x07\x07\x00\x00\x00\x0C");
+        assert_eq!(
+            tlv,
+            LinkAggregationTLV::new(true, true, AggregationPortType::AggregationPort, 12)
+        );
+        assert_eq!(
+            tlv.to_string(),
+            "LinkAggregationTLV(capable, aggregated, AggregationPort, 12)"
+        );
+    }
+
+    #[test]
+    fn test_evb() {
+        let tlv = roundtrip::<EvbTLV>(b"\xFE\x09\x00\x80\xC2\x0D\x06\x00\x74\x54\x14");
+        let mut evb = EvbTLV::new(1, 3, 20, 20, 20);
+        evb.bgid = true;
+        evb.rrcap = true;
+        assert_eq!(tlv, evb);
+        assert_eq!(
+            tlv.to_string(),
+            "EvbTLV(EVB Bridge, [BGID RRCAP], RRSTAT 0, R 3, RTE 20, RWD 20, RKA 20)"
+        );
+    }
+
+    #[test]
+    fn test_cdcp() {
+        let tlv = roundtrip::<CdcpTLV>(
+            b"\xFE\x0E\x00\x80\xC2\x0E\x80\x00\x00\xA7\x00\x10\x00\x00\x20\x00",
+        );
+        assert_eq!(
+            tlv,
+            CdcpTLV::new(true, 167, vec![SChannel::new(1, 0), SChannel::new(2, 0)])
+        );
+        assert_eq!(
+            tlv.to_string(),
+            "CdcpTLV(station, 167 channels, [SCID 1 SVID 0, SCID 2 SVID 0])"
+        );
+
+        let tlv = CdcpTLV::new(
+            false,
+            167,
+            vec![SChannel::new(1, 1), SChannel::new(2, 4001)],
+        );
+        assert_eq!(roundtrip::<CdcpTLV>(&tlv.to_tlv().bytes()), tlv);
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        let bad_length = |length| LldpError::BadLength {
+            tlv_type: TlvType::OrganizationallySpecific,
+            length,
+        };
+
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 1, vec![0x00]);
+        assert_eq!(Ieee8021Tlv::decode(&tlv), Some(Err(bad_length(5))));
+
+        // Name length does not match the name
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 3, b"\x00\x64\x05mgmt".to_vec());
+        assert_eq!(Ieee8021Tlv::decode(&tlv), Some(Err(bad_length(11))));
+
+        // VLAN names are limited to 32 bytes
+        let mut info = b"\x00\x64\x21".to_vec();
+        info.extend([b'x'; 33].iter());
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 3, info);
+        assert_eq!(Ieee8021Tlv::decode(&tlv), Some(Err(bad_length(40))));
+
+        // Truncated S-channel
+        let tlv =
+            OrganizationallySpecificTLV::new(OUI.to_vec(), 14, vec![0x80, 0, 0, 0xA7, 0x00, 0x10]);
+        assert_eq!(Ieee8021Tlv::decode(&tlv), Some(Err(bad_length(10))));
+
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 42, vec![]);
+        assert_eq!(Ieee8021Tlv::decode(&tlv), None);
+
+        assert_eq!(
+            PortVlanIdTLV::try_from_tlv(&ManagementVidTLV::new(1).to_tlv()),
+            Err(LldpError::UnknownOrgSubtype {
+                oui: OUI.to_vec(),
+                subtype: 6
+            })
+        );
+    }
+}
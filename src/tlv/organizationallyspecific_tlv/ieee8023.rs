@@ -0,0 +1,835 @@
+//! IEEE 802.3 Organizationally Specific TLVs
+//!
+//! TLVs defined by IEEE 802.3 clause 79, transmitted with the OUI 00-12-0F.
+//!
+//! ```
+//! use lldp_rs::tlv::organizationallyspecific_tlv::ieee8023::MacPhyConfigStatusTLV;
+//! use lldp_rs::tlv::organizationallyspecific_tlv::OrgTlvCodec;
+//!
+//! let tlv = MacPhyConfigStatusTLV::new(true, true, 0x6C01, 30);
+//! assert_eq!(tlv.mau_type_name(), Some("1000BaseTFD"));
+//! assert_eq!(
+//!     tlv.to_string(),
+//!     "MacPhyConfigStatusTLV(supported, enabled, \"10BASE-T half, 10BASE-T full, 100BASE-TX half, \
+//!      100BASE-TX full, 1000BASE-T full\", 1000BaseTFD)"
+//! );
+//! assert_eq!(tlv.to_tlv().value, b"\x03\x6C\x01\x00\x1E");
+//! ```
+
+use crate::error::LldpError;
+use crate::tlv::organizationallyspecific_tlv::{bad_info_len, check_info_len, OrgTlvCodec};
+use bytes::BufMut;
+use std::fmt::Display;
+
+/// OUI of IEEE 802.3
+pub const OUI: [u8; 3] = [0x00, 0x12, 0x0F];
+
+/// Names of the operational MAU types as defined by the IANA-MAU-MIB (RFC 4836), indexed by MAU type - 1
+const MAU_TYPES: [&str; 78] = [
+    "AUI",
+    "10Base5",
+    "Foirl",
+    "10Base2",
+    "10BaseT",
+    "10BaseFP",
+    "10BaseFB",
+    "10BaseFL",
+    "10Broad36",
+    "10BaseTHD",
+    "10BaseTFD",
+    "10BaseFLHD",
+    "10BaseFLFD",
+    "100BaseT4",
+    "100BaseTXHD",
+    "100BaseTXFD",
+    "100BaseFXHD",
+    "100BaseFXFD",
+    "100BaseT2HD",
+    "100BaseT2FD",
+    "1000BaseXHD",
+    "1000BaseXFD",
+    "1000BaseLXHD",
+    "1000BaseLXFD",
+    "1000BaseSXHD",
+    "1000BaseSXFD",
+    "1000BaseCXHD",
+    "1000BaseCXFD",
+    "1000BaseTHD",
+    "1000BaseTFD",
+    "10GigBaseX",
+    "10GigBaseLX4",
+    "10GigBaseR",
+    "10GigBaseER",
+    "10GigBaseLR",
+    "10GigBaseSR",
+    "10GigBaseW",
+    "10GigBaseEW",
+    "10GigBaseLW",
+    "10GigBaseSW",
+    "10GigBaseCX4",
+    "2BaseTL",
+    "10PassTS",
+    "100BaseBX10D",
+    "100BaseBX10U",
+    "100BaseLX10",
+    "1000BaseBX10D",
+    "1000BaseBX10U",
+    "1000BaseLX10",
+    "1000BasePX10D",
+    "1000BasePX10U",
+    "1000BasePX20D",
+    "1000BasePX20U",
+    "10GbaseT",
+    "10GbaseLRM",
+    "1000BaseKX",
+    "10GbaseKX4",
+    "10GbaseKR",
+    "10G1GbasePRXD1",
+    "10G1GbasePRXD2",
+    "10G1GbasePRXD3",
+    "10G1GbasePRXU1",
+    "10G1GbasePRXU2",
+    "10G1GbasePRXU3",
+    "10GbasePRD1",
+    "10GbasePRD2",
+    "10GbasePRD3",
+    "10GbasePRU1",
+    "10GbasePRU3",
+    "40GbaseKR4",
+    "40GbaseCR4",
+    "40GbaseSR4",
+    "40GbaseFR",
+    "40GbaseLR4",
+    "100GbaseCR10",
+    "100GbaseSR10",
+    "100GbaseLR4",
+    "100GbaseER4",
+];
+
+/// Names of the bits of the PMD auto-negotiation advertised capability, starting with the most significant bit
+const AUTONEG_CAPABILITIES: [&str; 16] = [
+    "other",
+    "10BASE-T half",
+    "10BASE-T full",
+    "100BASE-T4",
+    "100BASE-TX half",
+    "100BASE-TX full",
+    "100BASE-T2 half",
+    "100BASE-T2 full",
+    "PAUSE",
+    "asymmetric PAUSE",
+    "symmetric PAUSE",
+    "asymmetric and symmetric PAUSE",
+    "1000BASE-X half",
+    "1000BASE-X full",
+    "1000BASE-T half",
+    "1000BASE-T full",
+];
+
+/// Format a power value given in units of 0.1 W
+fn watts(deciwatts: u16) -> String {
+    format!("{}.{} W", deciwatts / 10, deciwatts % 10)
+}
+
+/// MAC/PHY Configuration/Status TLV
+///
+/// Advertises the auto-negotiation support and status, the advertised auto-negotiation capabilities and the
+/// operational MAU type of the port.
+///
+/// | Subtype | Information                                                                                  |
+/// | ------- | -------------------------------------------------------------------------------------------- |
+/// |    1    | Auto-negotiation Support/Status (1 byte), Advertised Capability (2 bytes), MAU Type (2 bytes) |
+///
+/// Bit 0 of the support/status byte indicates support for auto-negotiation, bit 1 that it is enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MacPhyConfigStatusTLV {
+    /// Auto-negotiation is supported
+    pub autoneg_supported: bool,
+    /// Auto-negotiation is enabled
+    pub autoneg_enabled: bool,
+    /// Bitmap of the advertised PMD auto-negotiation capabilities, see [MacPhyConfigStatusTLV::advertised]
+    pub advertised: u16,
+    /// The operational MAU type, see [MacPhyConfigStatusTLV::mau_type_name]
+    pub mau_type: u16,
+}
+
+impl MacPhyConfigStatusTLV {
+    /// Constructor
+    pub fn new(
+        autoneg_supported: bool,
+        autoneg_enabled: bool,
+        advertised: u16,
+        mau_type: u16,
+    ) -> MacPhyConfigStatusTLV {
+        MacPhyConfigStatusTLV {
+            autoneg_supported,
+            autoneg_enabled,
+            advertised,
+            mau_type,
+        }
+    }
+
+    /// Get the names of the advertised auto-negotiation capabilities, e.g. `1000BASE-T full`
+    pub fn advertised(&self) -> Vec<&'static str> {
+        AUTONEG_CAPABILITIES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| self.advertised & (0x8000 >> bit) != 0)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+
+    /// Get the name of the operational MAU type, e.g. `1000BaseTFD`.
+    ///
+    /// Returns `None` if the MAU type is unknown.
+    pub fn mau_type_name(&self) -> Option<&'static str> {
+        match self.mau_type {
+            0 => None,
+            mau_type => MAU_TYPES.get(mau_type as usize - 1).copied(),
+        }
+    }
+}
+
+impl Display for MacPhyConfigStatusTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MacPhyConfigStatusTLV({}, {}, \"{}\", ",
+            if self.autoneg_supported {
+                "supported"
+            } else {
+                "not supported"
+            },
+            if self.autoneg_enabled {
+                "enabled"
+            } else {
+                "disabled"
+            },
+            self.advertised().join(", ")
+        )?;
+
+        match self.mau_type_name() {
+            Some(name) => write!(f, "{})", name),
+            None => write!(f, "{})", self.mau_type),
+        }
+    }
+}
+
+impl OrgTlvCodec for MacPhyConfigStatusTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 1;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8(self.autoneg_supported as u8 | (self.autoneg_enabled as u8) << 1);
+        buf.put_u16(self.advertised);
+        buf.put_u16(self.mau_type);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<MacPhyConfigStatusTLV, LldpError> {
+        check_info_len(info, 5..=5)?;
+
+        Ok(MacPhyConfigStatusTLV::new(
+            info[0] & 0x01 != 0,
+            info[0] & 0x02 != 0,
+            u16::from_be_bytes([info[1], info[2]]),
+            u16::from_be_bytes([info[3], info[4]]),
+        ))
+    }
+}
+
+/// IEEE 802.3at extension of the [PowerViaMdiTLV]
+///
+/// Power values are given in units of 0.1 W.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerViaMdiAt {
+    /// The power type (0: type 2 PSE, 1: type 2 PD, 2: type 1 PSE, 3: type 1 PD)
+    pub power_type: u8,
+    /// The power source, its meaning depends on the power type
+    pub power_source: u8,
+    /// The PD supports powering of both modes simultaneously (IEEE 802.3bt)
+    pub pd_4pid: bool,
+    /// The power priority (0: unknown, 1: critical, 2: high, 3: low)
+    pub priority: u8,
+    /// The power requested by the PD
+    pub requested: u16,
+    /// The power allocated by the PSE
+    pub allocated: u16,
+    /// The IEEE 802.3bt extension
+    pub bt: Option<PowerViaMdiBt>,
+}
+
+/// IEEE 802.3bt extension of the [PowerViaMdiTLV]
+///
+/// Power values are given in units of 0.1 W.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerViaMdiBt {
+    /// The power requested by the PD for mode A
+    pub requested_mode_a: u16,
+    /// The power requested by the PD for mode B
+    pub requested_mode_b: u16,
+    /// The power allocated by the PSE for alternative A
+    pub allocated_alt_a: u16,
+    /// The power allocated by the PSE for alternative B
+    pub allocated_alt_b: u16,
+    /// The power status field
+    pub power_status: u16,
+    /// The system setup field
+    pub system_setup: u8,
+    /// The maximum power available at the PSE
+    pub max_available: u16,
+    /// The autoclass field
+    pub autoclass: u8,
+    /// The power down request (6 bits) and power down time (18 bits)
+    pub power_down: u32,
+}
+
+/// Power via MDI TLV
+///
+/// Advertises the Power over Ethernet capabilities and status of the port.
+///
+/// | Subtype | Information                                                                                  |
+/// | ------- | -------------------------------------------------------------------------------------------- |
+/// |    2    | MDI Power Support (1 byte), PSE Power Pair (1 byte), Power Class (1 byte)                    |
+/// |         | IEEE 802.3at: Type/Source/Priority (1 byte), PD Requested Power (2 bytes), PSE Allocated Power (2 bytes) |
+/// |         | IEEE 802.3bt: 17 further bytes, see [PowerViaMdiBt]                                          |
+///
+/// The MDI power support byte indicates the port class (bit 0, set for a PSE), support of PSE MDI power (bit 1), the
+/// PSE MDI power state (bit 2) and whether the PSE pairs can be controlled (bit 3). The power pairs are 1 for the
+/// signal pairs and 2 for the spare pairs, the power class is transmitted as class + 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerViaMdiTLV {
+    /// The port is a PSE (power sourcing equipment), otherwise a PD (powered device)
+    pub pse: bool,
+    /// PSE MDI power is supported
+    pub supported: bool,
+    /// PSE MDI power is enabled
+    pub enabled: bool,
+    /// The PSE pairs can be controlled
+    pub pairs_controllable: bool,
+    /// The PSE power pairs (1: signal, 2: spare)
+    pub power_pairs: u8,
+    /// The power class + 1
+    pub power_class: u8,
+    /// The IEEE 802.3at extension
+    pub at: Option<PowerViaMdiAt>,
+}
+
+impl PowerViaMdiTLV {
+    /// Constructor
+    pub fn new(
+        pse: bool,
+        supported: bool,
+        enabled: bool,
+        pairs_controllable: bool,
+        power_pairs: u8,
+        power_class: u8,
+    ) -> PowerViaMdiTLV {
+        PowerViaMdiTLV {
+            pse,
+            supported,
+            enabled,
+            pairs_controllable,
+            power_pairs,
+            power_class,
+            at: None,
+        }
+    }
+
+    /// Add the IEEE 802.3at extension
+    pub fn with_at(mut self, at: PowerViaMdiAt) -> PowerViaMdiTLV {
+        self.at = Some(at);
+        self
+    }
+}
+
+impl Display for PowerViaMdiTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PowerViaMdiTLV({}, {}, {}, {}, {}, ",
+            if self.pse { "PSE" } else { "PD" },
+            if self.supported {
+                "supported"
+            } else {
+                "not supported"
+            },
+            if self.enabled { "enabled" } else { "disabled" },
+            if self.pairs_controllable {
+                "pairs controllable"
+            } else {
+                "pairs fixed"
+            },
+            match self.power_pairs {
+                1 => "signal",
+                2 => "spare",
+                _ => "unknown pairs",
+            }
+        )?;
+        match self.power_class {
+            1..=5 => write!(f, "class {}", self.power_class - 1)?,
+            _ => write!(f, "unknown class")?,
+        }
+
+        if let Some(at) = &self.at {
+            write!(
+                f,
+                ", type {} {}, priority {}, requested {}, allocated {}",
+                if at.power_type & 0x02 == 0 { 2 } else { 1 },
+                if at.power_type & 0x01 == 0 {
+                    "PSE"
+                } else {
+                    "PD"
+                },
+                ["unknown", "critical", "high", "low"][at.priority as usize & 0x03],
+                watts(at.requested),
+                watts(at.allocated)
+            )?;
+
+            if let Some(bt) = &at.bt {
+                write!(f, ", maximum available {}", watts(bt.max_available))?;
+            }
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl OrgTlvCodec for PowerViaMdiTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 2;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8(
+            self.pse as u8
+                | (self.supported as u8) << 1
+                | (self.enabled as u8) << 2
+                | (self.pairs_controllable as u8) << 3,
+        );
+        buf.put_u8(self.power_pairs);
+        buf.put_u8(self.power_class);
+
+        if let Some(at) = &self.at {
+            buf.put_u8(
+                (at.power_type & 0x03) << 6
+                    | (at.power_source & 0x03) << 4
+                    | (at.pd_4pid as u8) << 2
+                    | at.priority & 0x03,
+            );
+            buf.put_u16(at.requested);
+            buf.put_u16(at.allocated);
+
+            if let Some(bt) = &at.bt {
+                buf.put_u16(bt.requested_mode_a);
+                buf.put_u16(bt.requested_mode_b);
+                buf.put_u16(bt.allocated_alt_a);
+                buf.put_u16(bt.allocated_alt_b);
+                buf.put_u16(bt.power_status);
+                buf.put_u8(bt.system_setup);
+                buf.put_u16(bt.max_available);
+                buf.put_u8(bt.autoclass);
+                buf.put_slice(&bt.power_down.to_be_bytes()[1..]);
+            }
+        }
+    }
+
+    /// Returns an error unless the information has the length of the basic TLV (3 bytes), the IEEE 802.3at extension
+    /// (8 bytes) or the IEEE 802.3bt extension (25 bytes).
+    fn decode_info(info: &[u8]) -> Result<PowerViaMdiTLV, LldpError> {
+        if ![3, 8, 25].contains(&info.len()) {
+            return Err(bad_info_len(info));
+        }
+
+        let u16_at = |index: usize| u16::from_be_bytes([info[index], info[index + 1]]);
+
+        let mut tlv = PowerViaMdiTLV::new(
+            info[0] & 0x01 != 0,
+            info[0] & 0x02 != 0,
+            info[0] & 0x04 != 0,
+            info[0] & 0x08 != 0,
+            info[1],
+            info[2],
+        );
+
+        if info.len() >= 8 {
+            let bt = if info.len() == 25 {
+                Some(PowerViaMdiBt {
+                    requested_mode_a: u16_at(8),
+                    requested_mode_b: u16_at(10),
+                    allocated_alt_a: u16_at(12),
+                    allocated_alt_b: u16_at(14),
+                    power_status: u16_at(16),
+                    system_setup: info[18],
+                    max_available: u16_at(19),
+                    autoclass: info[21],
+                    power_down: u32::from_be_bytes([0, info[22], info[23], info[24]]),
+                })
+            } else {
+                None
+            };
+
+            tlv = tlv.with_at(PowerViaMdiAt {
+                power_type: info[3] >> 6,
+                power_source: (info[3] >> 4) & 0x03,
+                pd_4pid: info[3] & 0x04 != 0,
+                priority: info[3] & 0x03,
+                requested: u16_at(4),
+                allocated: u16_at(6),
+                bt,
+            });
+        }
+
+        Ok(tlv)
+    }
+}
+
+/// Link Aggregation TLV
+///
+/// Advertises whether the link can be aggregated, whether it currently is aggregated and the identifier of the
+/// aggregated port.
+///
+/// | Subtype | Information                                               |
+/// | ------- | --------------------------------------------------------- |
+/// |    3    | Aggregation Status (1 byte), Aggregated Port ID (4 bytes) |
+///
+/// This TLV is deprecated in favor of the IEEE 802.1
+/// [LinkAggregationTLV](crate::tlv::organizationallyspecific_tlv::ieee8021::LinkAggregationTLV), which has the same
+/// format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinkAggregationTLV {
+    /// The link is capable of being aggregated
+    pub capable: bool,
+    /// The link is currently aggregated
+    pub enabled: bool,
+    /// The interface index of the aggregated port
+    pub port_id: u32,
+}
+
+impl LinkAggregationTLV {
+    /// Constructor
+    pub fn new(capable: bool, enabled: bool, port_id: u32) -> LinkAggregationTLV {
+        LinkAggregationTLV {
+            capable,
+            enabled,
+            port_id,
+        }
+    }
+}
+
+impl Display for LinkAggregationTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LinkAggregationTLV({}, {}, {})",
+            if self.capable {
+                "capable"
+            } else {
+                "not capable"
+            },
+            if self.enabled {
+                "aggregated"
+            } else {
+                "not aggregated"
+            },
+            self.port_id
+        )
+    }
+}
+
+impl OrgTlvCodec for LinkAggregationTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 3;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8(self.capable as u8 | (self.enabled as u8) << 1);
+        buf.put_u32(self.port_id);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<LinkAggregationTLV, LldpError> {
+        check_info_len(info, 5..=5)?;
+
+        Ok(LinkAggregationTLV::new(
+            info[0] & 0x01 != 0,
+            info[0] & 0x02 != 0,
+            u32::from_be_bytes([info[1], info[2], info[3], info[4]]),
+        ))
+    }
+}
+
+/// Maximum Frame Size TLV
+///
+/// Advertises the maximum frame size supported by the MAC and PHY of the port.
+///
+/// | Subtype | Information                   |
+/// | ------- | ----------------------------- |
+/// |    4    | Maximum Frame Size (2 bytes)  |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaximumFrameSizeTLV {
+    /// The maximum frame size in bytes
+    pub size: u16,
+}
+
+impl MaximumFrameSizeTLV {
+    /// Constructor
+    pub fn new(size: u16) -> MaximumFrameSizeTLV {
+        MaximumFrameSizeTLV { size }
+    }
+}
+
+impl Display for MaximumFrameSizeTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MaximumFrameSizeTLV({})", self.size)
+    }
+}
+
+impl OrgTlvCodec for MaximumFrameSizeTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 4;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.size);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<MaximumFrameSizeTLV, LldpError> {
+        check_info_len(info, 2..=2)?;
+
+        Ok(MaximumFrameSizeTLV::new(u16::from_be_bytes([
+            info[0], info[1],
+        ])))
+    }
+}
+
+/// Energy-Efficient Ethernet TLV
+///
+/// Advertises the wake times (Tw) of the port in microseconds, see IEEE 802.3 clause 78.
+///
+/// | Subtype | Information                                                                        |
+/// | ------- | ---------------------------------------------------------------------------------- |
+/// |    5    | Transmit Tw, Receive Tw, Fallback Tw, Echo Transmit Tw, Echo Receive Tw (2 bytes each) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EeeTLV {
+    /// The wake time the transmitting link partner is able to support
+    pub transmit_tw: u16,
+    /// The wake time requested by the receiving link partner
+    pub receive_tw: u16,
+    /// The fallback wake time of the receiving link partner
+    pub fallback_tw: u16,
+    /// The transmit wake time echoed from the remote link partner
+    pub echo_transmit_tw: u16,
+    /// The receive wake time echoed from the remote link partner
+    pub echo_receive_tw: u16,
+}
+
+impl EeeTLV {
+    /// Constructor
+    pub fn new(
+        transmit_tw: u16,
+        receive_tw: u16,
+        fallback_tw: u16,
+        echo_transmit_tw: u16,
+        echo_receive_tw: u16,
+    ) -> EeeTLV {
+        EeeTLV {
+            transmit_tw,
+            receive_tw,
+            fallback_tw,
+            echo_transmit_tw,
+            echo_receive_tw,
+        }
+    }
+}
+
+impl Display for EeeTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EeeTLV({}, {}, {}, {}, {})",
+            self.transmit_tw,
+            self.receive_tw,
+            self.fallback_tw,
+            self.echo_transmit_tw,
+            self.echo_receive_tw
+        )
+    }
+}
+
+impl OrgTlvCodec for EeeTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 5;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.transmit_tw);
+        buf.put_u16(self.receive_tw);
+        buf.put_u16(self.fallback_tw);
+        buf.put_u16(self.echo_transmit_tw);
+        buf.put_u16(self.echo_receive_tw);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<EeeTLV, LldpError> {
+        check_info_len(info, 10..=10)?;
+
+        let u16_at = |index: usize| u16::from_be_bytes([info[index], info[index + 1]]);
+
+        Ok(EeeTLV::new(
+            u16_at(0),
+            u16_at(2),
+            u16_at(4),
+            u16_at(6),
+            u16_at(8),
+        ))
+    }
+}
+
+/// IEEE 802.3 Organizationally Specific TLVs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ieee8023Tlv {
+    MacPhyConfigStatus(MacPhyConfigStatusTLV),
+    PowerViaMdi(PowerViaMdiTLV),
+    LinkAggregation(LinkAggregationTLV),
+    MaximumFrameSize(MaximumFrameSizeTLV),
+    Eee(EeeTLV),
+}
+
+org_tlv_dispatch! {
+    Ieee8023Tlv {
+        MacPhyConfigStatus(MacPhyConfigStatusTLV),
+        PowerViaMdi(PowerViaMdiTLV),
+        LinkAggregation(LinkAggregationTLV),
+        MaximumFrameSize(MaximumFrameSizeTLV),
+        Eee(EeeTLV),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::tests::roundtrip;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+    use crate::tlv::TlvType;
+
+    #[test]
+    fn test_mac_phy_config_status() {
+        let tlv =
+            roundtrip::<MacPhyConfigStatusTLV>(b"\xFE\x09\x00\x12\x0F\x01\x03\x00\x01\x00\x10");
+        assert_eq!(tlv.advertised(), ["1000BASE-T full"]);
+        assert_eq!(tlv.mau_type_name(), Some("100BaseTXFD"));
+        assert_eq!(
+            tlv.to_string(),
+            "MacPhyConfigStatusTLV(supported, enabled, \"1000BASE-T full\", 100BaseTXFD)"
+        );
+
+        let tlv = MacPhyConfigStatusTLV::new(false, false, 0x8000, 500);
+        assert_eq!(tlv.mau_type_name(), None);
+        assert_eq!(
+            tlv.to_string(),
+            "MacPhyConfigStatusTLV(not supported, disabled, \"other\", 500)"
+        );
+    }
+
+    #[test]
+    fn test_power_via_mdi() {
+        let tlv = roundtrip::<PowerViaMdiTLV>(b"\xFE\x07\x00\x12\x0F\x02\x07\x01\x01");
+        assert_eq!(tlv, PowerViaMdiTLV::new(true, true, true, false, 1, 1));
+        assert_eq!(
+            tlv.to_string(),
+            "PowerViaMdiTLV(PSE, supported, enabled, pairs fixed, signal, class 0)"
+        );
+    }
+
+    #[test]
+    fn test_power_via_mdi_at() {
+        let tlv = roundtrip::<PowerViaMdiTLV>(
+            b"\xFE\x0C\x00\x12\x0F\x02\x0F\x01\x05\x52\x00\xFF\x00\xFF",
+        );
+        match &tlv.at {
+            Some(at) => {
+                assert_eq!(at.power_type, 1);
+                assert_eq!(at.power_source, 1);
+                assert_eq!(at.priority, 2);
+                assert_eq!(at.requested, 255);
+                assert_eq!(at.bt, None);
+            }
+            None => panic!("expected 802.3at Power via MDI TLV, got {:?}", tlv),
+        }
+        assert_eq!(
+            tlv.to_string(),
+            "PowerViaMdiTLV(PSE, supported, enabled, pairs controllable, signal, class 4, type 2 PD, priority high, \
+             requested 25.5 W, allocated 25.5 W)"
+        );
+    }
+
+    #[test]
+    fn test_power_via_mdi_bt() {
+        let tlv = roundtrip::<PowerViaMdiTLV>(
+            b"\xFE\x1D\x00\x12\x0F\x02\x0F\x01\x05\x12\x02\x58\x02\x58\x01\x2C\x01\x2C\x01\x2C\x01\x2C\x00\x41\x02\x03\x84\x00\x00\x00\x00",
+        );
+        match &tlv.at {
+            Some(PowerViaMdiAt { bt: Some(bt), .. }) => {
+                assert_eq!(bt.requested_mode_a, 300);
+                assert_eq!(bt.power_status, 0x0041);
+                assert_eq!(bt.system_setup, 2);
+                assert_eq!(bt.max_available, 900);
+            }
+            at => panic!("expected 802.3bt Power via MDI TLV, got {:?}", at),
+        }
+        assert!(tlv.to_string().ends_with(", maximum available 90.0 W)"));
+    }
+
+    #[test]
+    fn test_link_aggregation() {
+        let tlv = roundtrip::<LinkAggregationTLV>(b"\xFE\x09\x00\x12\x0F\x03\x03\x00\x00\x00\x0C");
+        assert_eq!(tlv, LinkAggregationTLV::new(true, true, 12));
+        assert_eq!(
+            tlv.to_string(),
+            "LinkAggregationTLV(capable, aggregated, 12)"
+        );
+    }
+
+    #[test]
+    fn test_maximum_frame_size() {
+        let tlv = roundtrip::<MaximumFrameSizeTLV>(b"\xFE\x06\x00\x12\x0F\x04\x05\xEE");
+        assert_eq!(tlv, MaximumFrameSizeTLV::new(1518));
+        assert_eq!(tlv.to_string(), "MaximumFrameSizeTLV(1518)");
+    }
+
+    #[test]
+    fn test_eee() {
+        let tlv = roundtrip::<EeeTLV>(
+            b"\xFE\x0E\x00\x12\x0F\x05\x00\x11\x00\x11\x00\x11\x00\x10\x00\x10",
+        );
+        assert_eq!(tlv, EeeTLV::new(17, 17, 17, 16, 16));
+        assert_eq!(tlv.to_string(), "EeeTLV(17, 17, 17, 16, 16)");
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        let bad_length = |length| LldpError::BadLength {
+            tlv_type: TlvType::OrganizationallySpecific,
+            length,
+        };
+
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 1, vec![0x03, 0x00]);
+        assert_eq!(Ieee8023Tlv::decode(&tlv), Some(Err(bad_length(6))));
+
+        // Neither a basic, an 802.3at nor an 802.3bt Power via MDI TLV
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 2, vec![0x0F; 5]);
+        assert_eq!(Ieee8023Tlv::decode(&tlv), Some(Err(bad_length(9))));
+
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 2, vec![0x0F; 26]);
+        assert_eq!(Ieee8023Tlv::decode(&tlv), Some(Err(bad_length(30))));
+
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 9, vec![]);
+        assert_eq!(Ieee8023Tlv::decode(&tlv), None);
+    }
+}
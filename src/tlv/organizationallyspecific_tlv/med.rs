@@ -0,0 +1,1062 @@
+//! LLDP-MED Organizationally Specific TLVs
+//!
+//! TLVs defined by ANSI/TIA-1057 (LLDP for Media Endpoint Devices), transmitted with the OUI 00-12-BB.
+//!
+//! ```
+//! use lldp_rs::tlv::organizationallyspecific_tlv::med::{ApplicationType, NetworkPolicyTLV};
+//! use lldp_rs::tlv::organizationallyspecific_tlv::OrgTlvCodec;
+//!
+//! let tlv = NetworkPolicyTLV::new(ApplicationType::Voice as u8, true, 100, 5, 46);
+//! assert_eq!(tlv.to_tlv().value, b"\x01\x40\xC9\x6E");
+//! assert_eq!(
+//!     tlv.to_string(),
+//!     "NetworkPolicyTLV(Voice, defined, tagged, vlan 100, priority 5, dscp 46)"
+//! );
+//! ```
+
+use crate::error::LldpError;
+use crate::tlv::organizationallyspecific_tlv::{bad_info_len, check_info_len, OrgTlvCodec};
+use crate::tlv::TlvType;
+use bytes::BufMut;
+use std::fmt::Display;
+
+/// OUI of the TIA (LLDP-MED)
+pub const OUI: [u8; 3] = [0x00, 0x12, 0xBB];
+
+/// Maximum length of the inventory strings in bytes
+pub const MAX_INVENTORY_LEN: usize = 32;
+
+/// Decode a string of organizationally defined information
+fn decode_string(bytes: &[u8]) -> Result<String, LldpError> {
+    String::from_utf8(bytes.to_vec())
+        .map_err(|_| LldpError::InvalidUtf8(TlvType::OrganizationallySpecific))
+}
+
+/// Format a power value given in units of 0.1 W
+fn watts(deciwatts: u16) -> String {
+    format!("{}.{} W", deciwatts / 10, deciwatts % 10)
+}
+
+/// Names of the LLDP-MED capability bits, starting with the least significant bit
+const CAPABILITIES: [&str; 6] = [
+    "Capabilities",
+    "Network Policy",
+    "Location Identification",
+    "Extended Power via MDI-PSE",
+    "Extended Power via MDI-PD",
+    "Inventory",
+];
+
+/// LLDP-MED device types, see [MedCapabilitiesTLV]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceType {
+    NotDefined = 0,
+    EndpointClassI = 1,
+    EndpointClassII = 2,
+    EndpointClassIII = 3,
+    NetworkConnectivity = 4,
+}
+
+impl DeviceType {
+    /// Get the name of the device type as used by ANSI/TIA-1057
+    pub fn name(device_type: u8) -> Option<&'static str> {
+        match device_type {
+            0 => Some("Type Not Defined"),
+            1 => Some("Endpoint Class I"),
+            2 => Some("Endpoint Class II"),
+            3 => Some("Endpoint Class III"),
+            4 => Some("Network Connectivity"),
+            _ => None,
+        }
+    }
+}
+
+/// LLDP-MED Capabilities TLV
+///
+/// Advertises the LLDP-MED TLVs supported by the device and its device type. It must be the first LLDP-MED TLV of an
+/// LLDPDU.
+///
+/// | Subtype | Information                                          |
+/// | ------- | ---------------------------------------------------- |
+/// |    1    | Capabilities (2 bytes), Device Type (1 byte)         |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MedCapabilitiesTLV {
+    /// Bitmap of the supported LLDP-MED TLVs, see [MedCapabilitiesTLV::capabilities]
+    pub capabilities: u16,
+    /// The device type, see [DeviceType]
+    pub device_type: u8,
+}
+
+impl MedCapabilitiesTLV {
+    /// Constructor
+    pub fn new(capabilities: u16, device_type: u8) -> MedCapabilitiesTLV {
+        MedCapabilitiesTLV {
+            capabilities,
+            device_type,
+        }
+    }
+
+    /// Get the names of the supported capabilities, e.g. `Network Policy`
+    pub fn capabilities(&self) -> Vec<&'static str> {
+        CAPABILITIES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| self.capabilities & (1 << bit) != 0)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+}
+
+impl Display for MedCapabilitiesTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MedCapabilitiesTLV(\"{}\", ",
+            self.capabilities().join(", ")
+        )?;
+
+        match DeviceType::name(self.device_type) {
+            Some(name) => write!(f, "{})", name),
+            None => write!(f, "{})", self.device_type),
+        }
+    }
+}
+
+impl OrgTlvCodec for MedCapabilitiesTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 1;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.capabilities);
+        buf.put_u8(self.device_type);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<MedCapabilitiesTLV, LldpError> {
+        check_info_len(info, 3..=3)?;
+
+        Ok(MedCapabilitiesTLV::new(
+            u16::from_be_bytes([info[0], info[1]]),
+            info[2],
+        ))
+    }
+}
+
+/// Application types of the [NetworkPolicyTLV]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplicationType {
+    Voice = 1,
+    VoiceSignaling = 2,
+    GuestVoice = 3,
+    GuestVoiceSignaling = 4,
+    SoftphoneVoice = 5,
+    VideoConferencing = 6,
+    StreamingVideo = 7,
+    VideoSignaling = 8,
+}
+
+impl ApplicationType {
+    /// Get the name of the application type as used by ANSI/TIA-1057
+    pub fn name(application: u8) -> Option<&'static str> {
+        match application {
+            1 => Some("Voice"),
+            2 => Some("Voice Signaling"),
+            3 => Some("Guest Voice"),
+            4 => Some("Guest Voice Signaling"),
+            5 => Some("Softphone Voice"),
+            6 => Some("Video Conferencing"),
+            7 => Some("Streaming Video"),
+            8 => Some("Video Signaling"),
+            _ => None,
+        }
+    }
+}
+
+/// Network Policy TLV
+///
+/// Advertises the VLAN, layer 2 priority and DSCP value to use for an application.
+///
+/// | Subtype | Information                                                                          |
+/// | ------- | ------------------------------------------------------------------------------------ |
+/// |    2    | Application Type (1 byte), U/T/X flags (3 bits), VLAN ID (12 bits), L2 Priority (3 bits), DSCP (6 bits) |
+///
+/// The U flag indicates an unknown policy, i.e. the device needs a policy that is not defined yet. The T flag
+/// indicates that the application uses tagged frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetworkPolicyTLV {
+    /// The application type, see [ApplicationType]
+    pub application: u8,
+    /// The policy is not known (U flag)
+    pub unknown: bool,
+    /// The application uses tagged frames (T flag)
+    pub tagged: bool,
+    /// The VLAN identifier (12 bits), 0 for priority tagged frames
+    pub vlan_id: u16,
+    /// The layer 2 priority (3 bits)
+    pub priority: u8,
+    /// The DSCP value (6 bits)
+    pub dscp: u8,
+}
+
+impl NetworkPolicyTLV {
+    /// Create a defined network policy
+    pub fn new(
+        application: u8,
+        tagged: bool,
+        vlan_id: u16,
+        priority: u8,
+        dscp: u8,
+    ) -> NetworkPolicyTLV {
+        NetworkPolicyTLV {
+            application,
+            unknown: false,
+            tagged,
+            vlan_id,
+            priority,
+            dscp,
+        }
+    }
+
+    /// Create an unknown network policy for an application
+    pub fn unknown(application: u8) -> NetworkPolicyTLV {
+        NetworkPolicyTLV {
+            application,
+            unknown: true,
+            tagged: false,
+            vlan_id: 0,
+            priority: 0,
+            dscp: 0,
+        }
+    }
+}
+
+impl Display for NetworkPolicyTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NetworkPolicyTLV(")?;
+        match ApplicationType::name(self.application) {
+            Some(name) => write!(f, "{}", name)?,
+            None => write!(f, "{}", self.application)?,
+        }
+
+        write!(
+            f,
+            ", {}, {}, vlan {}, priority {}, dscp {})",
+            if self.unknown { "unknown" } else { "defined" },
+            if self.tagged { "tagged" } else { "untagged" },
+            self.vlan_id,
+            self.priority,
+            self.dscp
+        )
+    }
+}
+
+impl OrgTlvCodec for NetworkPolicyTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 2;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        let policy = (self.unknown as u32) << 23
+            | (self.tagged as u32) << 22
+            | (self.vlan_id as u32 & 0x0FFF) << 9
+            | (self.priority as u32 & 0x07) << 6
+            | self.dscp as u32 & 0x3F;
+
+        buf.put_u8(self.application);
+        buf.put_slice(&policy.to_be_bytes()[1..]);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<NetworkPolicyTLV, LldpError> {
+        check_info_len(info, 4..=4)?;
+
+        let policy = u32::from_be_bytes([0, info[1], info[2], info[3]]);
+
+        Ok(NetworkPolicyTLV {
+            application: info[0],
+            unknown: policy & (1 << 23) != 0,
+            tagged: policy & (1 << 22) != 0,
+            vlan_id: ((policy >> 9) & 0x0FFF) as u16,
+            priority: ((policy >> 6) & 0x07) as u8,
+            dscp: (policy & 0x3F) as u8,
+        })
+    }
+}
+
+/// Coordinate-based location configuration information (RFC 3825)
+///
+/// Latitude and longitude are transmitted as 34 bit fixed point numbers with 25 fractional bits, the altitude as a 30
+/// bit fixed point number with 8 fractional bits. The raw values are kept so encoding is lossless, the methods convert
+/// them to degrees and meters or floors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoordinateLci {
+    /// Number of valid bits of the latitude (6 bits)
+    pub latitude_resolution: u8,
+    /// The raw latitude (34 bits)
+    pub latitude: i64,
+    /// Number of valid bits of the longitude (6 bits)
+    pub longitude_resolution: u8,
+    /// The raw longitude (34 bits)
+    pub longitude: i64,
+    /// The altitude type (1: meters, 2: floors)
+    pub altitude_type: u8,
+    /// Number of valid bits of the altitude (6 bits)
+    pub altitude_resolution: u8,
+    /// The raw altitude (30 bits)
+    pub altitude: i32,
+    /// The geodetic datum (1: WGS84, 2: NAD83/NAVD88, 3: NAD83/MLLW)
+    pub datum: u8,
+}
+
+impl CoordinateLci {
+    /// Length of the encoded coordinates in bytes
+    pub const LEN: usize = 16;
+
+    /// Create a location from latitude and longitude in degrees and the altitude in meters (WGS84 datum)
+    pub fn new(latitude: f64, longitude: f64, altitude: f64) -> CoordinateLci {
+        CoordinateLci {
+            latitude_resolution: 34,
+            latitude: (latitude * (1 << 25) as f64).round() as i64,
+            longitude_resolution: 34,
+            longitude: (longitude * (1 << 25) as f64).round() as i64,
+            altitude_type: 1,
+            altitude_resolution: 30,
+            altitude: (altitude * (1 << 8) as f64).round() as i32,
+            datum: 1,
+        }
+    }
+
+    /// Get the latitude in degrees, positive values are north of the equator
+    pub fn latitude_degrees(&self) -> f64 {
+        self.latitude as f64 / (1 << 25) as f64
+    }
+
+    /// Get the longitude in degrees, positive values are east of the prime meridian
+    pub fn longitude_degrees(&self) -> f64 {
+        self.longitude as f64 / (1 << 25) as f64
+    }
+
+    /// Get the altitude in meters or floors, see [CoordinateLci::altitude_type]
+    pub fn altitude_value(&self) -> f64 {
+        self.altitude as f64 / (1 << 8) as f64
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) {
+        let bits = (self.latitude_resolution as u128 & 0x3F) << 122
+            | (self.latitude as u128 & 0x3_FFFF_FFFF) << 88
+            | (self.longitude_resolution as u128 & 0x3F) << 82
+            | (self.longitude as u128 & 0x3_FFFF_FFFF) << 48
+            | (self.altitude_type as u128 & 0x0F) << 44
+            | (self.altitude_resolution as u128 & 0x3F) << 38
+            | (self.altitude as u128 & 0x3FFF_FFFF) << 8
+            | self.datum as u128;
+
+        buf.put_u128(bits);
+    }
+
+    fn decode(bytes: &[u8]) -> CoordinateLci {
+        let mut raw = [0; 16];
+        raw.copy_from_slice(bytes);
+        let bits = u128::from_be_bytes(raw);
+
+        CoordinateLci {
+            latitude_resolution: (bits >> 122) as u8 & 0x3F,
+            // Sign extend the 34 bit values
+            latitude: (((bits >> 88) as i64) << 30) >> 30,
+            longitude_resolution: (bits >> 82) as u8 & 0x3F,
+            longitude: (((bits >> 48) as i64) << 30) >> 30,
+            altitude_type: (bits >> 44) as u8 & 0x0F,
+            altitude_resolution: (bits >> 38) as u8 & 0x3F,
+            altitude: (((bits >> 8) as i32) << 2) >> 2,
+            datum: bits as u8,
+        }
+    }
+}
+
+impl Display for CoordinateLci {
+    /// Write the coordinates, e.g. `48.137100, 11.575400, 519.00 m, WGS84`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.6}, {:.6}, {:.2} {}, ",
+            self.latitude_degrees(),
+            self.longitude_degrees(),
+            self.altitude_value(),
+            match self.altitude_type {
+                1 => "m",
+                2 => "floors",
+                _ => "(unknown unit)",
+            }
+        )?;
+
+        match self.datum {
+            1 => write!(f, "WGS84"),
+            2 => write!(f, "NAD83/NAVD88"),
+            3 => write!(f, "NAD83/MLLW"),
+            datum => write!(f, "datum {}", datum),
+        }
+    }
+}
+
+/// Civic address element, see [CivicAddress]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CivicAddressElement {
+    /// The CAtype, see [CivicAddressElement::type_name]
+    pub ca_type: u8,
+    /// The value of the element
+    pub value: String,
+}
+
+impl CivicAddressElement {
+    /// Constructor
+    pub fn new(ca_type: u8, value: String) -> CivicAddressElement {
+        CivicAddressElement { ca_type, value }
+    }
+
+    /// Get the name of the CAtype as defined by RFC 4776, e.g. `city` for CAtype 3
+    pub fn type_name(&self) -> Option<&'static str> {
+        match self.ca_type {
+            0 => Some("language"),
+            1 => Some("state"),
+            2 => Some("county"),
+            3 => Some("city"),
+            4 => Some("city division"),
+            5 => Some("neighborhood"),
+            6 => Some("street"),
+            16 => Some("leading street direction"),
+            17 => Some("trailing street suffix"),
+            18 => Some("street suffix"),
+            19 => Some("house number"),
+            20 => Some("house number suffix"),
+            21 => Some("landmark"),
+            22 => Some("additional location"),
+            23 => Some("name"),
+            24 => Some("postal code"),
+            25 => Some("building"),
+            26 => Some("unit"),
+            27 => Some("floor"),
+            28 => Some("room"),
+            29 => Some("place type"),
+            30 => Some("postal community name"),
+            31 => Some("post office box"),
+            32 => Some("additional code"),
+            33 => Some("seat"),
+            34 => Some("road"),
+            35 => Some("road section"),
+            36 => Some("road branch"),
+            37 => Some("road sub-branch"),
+            38 => Some("road pre-modifier"),
+            39 => Some("road post-modifier"),
+            128 => Some("script"),
+            _ => None,
+        }
+    }
+}
+
+impl Display for CivicAddressElement {
+    /// Write the element, e.g. `city "Munich"`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.type_name() {
+            Some(name) => write!(f, "{} \"{}\"", name, self.value),
+            None => write!(f, "CAtype {} \"{}\"", self.ca_type, self.value),
+        }
+    }
+}
+
+/// Civic address location configuration information (RFC 4776)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CivicAddress {
+    /// The location described (0: DHCP server, 1: network element closest to the client, 2: client)
+    pub what: u8,
+    /// The two letter ISO 3166 country code
+    pub country: String,
+    /// The address elements
+    pub elements: Vec<CivicAddressElement>,
+}
+
+impl CivicAddress {
+    /// Create a civic address of the client
+    pub fn new(country: String, elements: Vec<CivicAddressElement>) -> CivicAddress {
+        CivicAddress {
+            what: 2,
+            country,
+            elements,
+        }
+    }
+
+    /// Create a civic address of the client, checking its length.
+    ///
+    /// Returns an error if the country code is not two bytes long, or the address including all elements is longer
+    /// than the 255 bytes the LCI length field can hold.
+    pub fn try_new(
+        country: String,
+        elements: Vec<CivicAddressElement>,
+    ) -> Result<CivicAddress, LldpError> {
+        let address = CivicAddress::new(country, elements);
+        if address.country.len() != 2 || address.len() > u8::MAX as usize {
+            // Format and LCI length precede the address in the information
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 6 + address.len(),
+            });
+        }
+
+        Ok(address)
+    }
+
+    fn len(&self) -> usize {
+        1 + self.country.len()
+            + self
+                .elements
+                .iter()
+                .map(|element| 2 + element.value.len())
+                .sum::<usize>()
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) {
+        buf.put_u8(self.len() as u8);
+        buf.put_u8(self.what);
+        buf.put_slice(self.country.as_bytes());
+        for element in &self.elements {
+            buf.put_u8(element.ca_type);
+            buf.put_u8(element.value.len() as u8);
+            buf.put_slice(element.value.as_bytes());
+        }
+    }
+
+    /// Decode the civic address, `info` is the whole information of the Location Identification TLV.
+    fn decode(info: &[u8]) -> Result<CivicAddress, LldpError> {
+        // Format, LCI length, what and country code
+        check_info_len(info, 5..=usize::MAX)?;
+        if info[1] as usize != info.len() - 2 {
+            return Err(bad_info_len(info));
+        }
+
+        let mut elements = vec![];
+        let mut rest = &info[5..];
+        while !rest.is_empty() {
+            if rest.len() < 2 || rest.len() < 2 + rest[1] as usize {
+                return Err(bad_info_len(info));
+            }
+            let (element, tail) = rest.split_at(2 + rest[1] as usize);
+            elements.push(CivicAddressElement::new(
+                element[0],
+                decode_string(&element[2..])?,
+            ));
+            rest = tail;
+        }
+
+        Ok(CivicAddress {
+            what: info[2],
+            country: decode_string(&info[3..5])?,
+            elements,
+        })
+    }
+}
+
+impl Display for CivicAddress {
+    /// Write the country code and the elements, e.g. `DE, city "Munich"`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.country)?;
+        for element in &self.elements {
+            write!(f, ", {}", element)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Location data of the [LocationIdTLV]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Location {
+    /// Coordinate-based LCI (format 1)
+    Coordinate(CoordinateLci),
+    /// Civic address LCI (format 2)
+    Civic(CivicAddress),
+    /// Emergency call service ELIN, a numeric string of 10 to 25 digits (format 3)
+    Elin(String),
+    /// A reserved location data format
+    Unknown {
+        format: u8,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::hex"))]
+        data: Vec<u8>,
+    },
+}
+
+/// Location Identification TLV
+///
+/// Advertises the physical location of the device as coordinates, a civic address or an emergency call service
+/// location identification number (ELIN).
+///
+/// | Subtype | Information                                                   |
+/// | ------- | ------------------------------------------------------------- |
+/// |    3    | Location Data Format (1 byte), Location ID (1 - 256 bytes)    |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocationIdTLV {
+    /// The location
+    pub location: Location,
+}
+
+impl LocationIdTLV {
+    /// Constructor
+    pub fn new(location: Location) -> LocationIdTLV {
+        LocationIdTLV { location }
+    }
+}
+
+impl Display for LocationIdTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Location::Coordinate(lci) => write!(f, "LocationIdTLV(Coordinate({}))", lci),
+            Location::Civic(address) => write!(f, "LocationIdTLV(Civic({}))", address),
+            Location::Elin(elin) => write!(f, "LocationIdTLV(Elin(\"{}\"))", elin),
+            Location::Unknown { format, data } => {
+                let mut hex = String::new();
+                for i in data {
+                    hex.push_str(&format!("{:02X}", i));
+                }
+
+                write!(f, "LocationIdTLV({}, \"{}\")", format, hex)
+            }
+        }
+    }
+}
+
+impl OrgTlvCodec for LocationIdTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 3;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        match &self.location {
+            Location::Coordinate(lci) => {
+                buf.put_u8(1);
+                lci.encode(buf);
+            }
+            Location::Civic(address) => {
+                buf.put_u8(2);
+                address.encode(buf);
+            }
+            Location::Elin(elin) => {
+                buf.put_u8(3);
+                buf.put_slice(elin.as_bytes());
+            }
+            Location::Unknown { format, data } => {
+                buf.put_u8(*format);
+                buf.put_slice(data);
+            }
+        }
+    }
+
+    /// Returns an error if the location does not match the length defined by its format, or an ELIN is not numeric.
+    fn decode_info(info: &[u8]) -> Result<LocationIdTLV, LldpError> {
+        check_info_len(info, 1..=usize::MAX)?;
+
+        let location = match info[0] {
+            1 => {
+                check_info_len(info, 1 + CoordinateLci::LEN..=1 + CoordinateLci::LEN)?;
+                Location::Coordinate(CoordinateLci::decode(&info[1..]))
+            }
+            2 => Location::Civic(CivicAddress::decode(info)?),
+            3 => {
+                check_info_len(info, 11..=26)?;
+                if !info[1..].iter().all(u8::is_ascii_digit) {
+                    return Err(LldpError::InvalidUtf8(TlvType::OrganizationallySpecific));
+                }
+                Location::Elin(decode_string(&info[1..])?)
+            }
+            format => Location::Unknown {
+                format,
+                data: info[1..].to_vec(),
+            },
+        };
+
+        Ok(LocationIdTLV::new(location))
+    }
+}
+
+/// Extended Power-via-MDI TLV
+///
+/// Advertises the power requirements or the available power of a PoE device.
+///
+/// | Subtype | Information                                                                  |
+/// | ------- | ---------------------------------------------------------------------------- |
+/// |    4    | Power Type (2 bits), Power Source (2 bits), Priority (4 bits), Power Value (2 bytes) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtendedPowerViaMdiTLV {
+    /// The power type (0: PSE, 1: PD)
+    pub power_type: u8,
+    /// The power source, its meaning depends on the power type
+    pub power_source: u8,
+    /// The power priority (0: unknown, 1: critical, 2: high, 3: low)
+    pub priority: u8,
+    /// The power required by a PD or available at a PSE in units of 0.1 W
+    pub power: u16,
+}
+
+impl ExtendedPowerViaMdiTLV {
+    /// Constructor
+    pub fn new(
+        power_type: u8,
+        power_source: u8,
+        priority: u8,
+        power: u16,
+    ) -> ExtendedPowerViaMdiTLV {
+        ExtendedPowerViaMdiTLV {
+            power_type,
+            power_source,
+            priority,
+            power,
+        }
+    }
+}
+
+impl Display for ExtendedPowerViaMdiTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let source = match (self.power_type, self.power_source) {
+            (0, 1) => "primary power source",
+            (0, 2) => "backup power source",
+            (1, 1) => "PSE",
+            (1, 2) => "local",
+            (1, 3) => "PSE and local",
+            _ => "unknown source",
+        };
+
+        write!(
+            f,
+            "ExtendedPowerViaMdiTLV({}, {}, priority {}, {})",
+            match self.power_type {
+                0 => "PSE",
+                1 => "PD",
+                _ => "reserved",
+            },
+            source,
+            match self.priority {
+                1 => "critical",
+                2 => "high",
+                3 => "low",
+                _ => "unknown",
+            },
+            watts(self.power)
+        )
+    }
+}
+
+impl OrgTlvCodec for ExtendedPowerViaMdiTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 4;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u8(
+            (self.power_type & 0x03) << 6 | (self.power_source & 0x03) << 4 | self.priority & 0x0F,
+        );
+        buf.put_u16(self.power);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<ExtendedPowerViaMdiTLV, LldpError> {
+        check_info_len(info, 3..=3)?;
+
+        Ok(ExtendedPowerViaMdiTLV::new(
+            info[0] >> 6,
+            (info[0] >> 4) & 0x03,
+            info[0] & 0x0F,
+            u16::from_be_bytes([info[1], info[2]]),
+        ))
+    }
+}
+
+/// Define an inventory TLV, which carries a single string of up to 32 bytes.
+macro_rules! inventory_tlv {
+    ($(#[$doc:meta])* $tlv:ident, $subtype:expr) => {
+        $(#[$doc])*
+        ///
+        /// The string is at most 32 bytes long.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        pub struct $tlv {
+            /// The inventory string
+            pub value: String,
+        }
+
+        impl $tlv {
+            /// Constructor
+            pub fn new(value: String) -> $tlv {
+                $tlv { value }
+            }
+        }
+
+        impl Display for $tlv {
+            /// Write a printable representation of the TLV object.
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}(\"{}\")", stringify!($tlv), self.value)
+            }
+        }
+
+        impl OrgTlvCodec for $tlv {
+            const OUI: [u8; 3] = OUI;
+            const SUBTYPE: u8 = $subtype;
+
+            fn encode_info(&self, buf: &mut impl BufMut) {
+                buf.put_slice(self.value.as_bytes());
+            }
+
+            fn decode_info(info: &[u8]) -> Result<$tlv, LldpError> {
+                check_info_len(info, 0..=MAX_INVENTORY_LEN)?;
+
+                Ok($tlv::new(decode_string(info)?))
+            }
+        }
+    };
+}
+
+inventory_tlv! {
+    /// Inventory - Hardware Revision TLV
+    HardwareRevisionTLV, 5
+}
+
+inventory_tlv! {
+    /// Inventory - Firmware Revision TLV
+    FirmwareRevisionTLV, 6
+}
+
+inventory_tlv! {
+    /// Inventory - Software Revision TLV
+    SoftwareRevisionTLV, 7
+}
+
+inventory_tlv! {
+    /// Inventory - Serial Number TLV
+    SerialNumberTLV, 8
+}
+
+inventory_tlv! {
+    /// Inventory - Manufacturer Name TLV
+    ManufacturerNameTLV, 9
+}
+
+inventory_tlv! {
+    /// Inventory - Model Name TLV
+    ModelNameTLV, 10
+}
+
+inventory_tlv! {
+    /// Inventory - Asset ID TLV
+    AssetIdTLV, 11
+}
+
+/// LLDP-MED Organizationally Specific TLVs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MedTlv {
+    Capabilities(MedCapabilitiesTLV),
+    NetworkPolicy(NetworkPolicyTLV),
+    LocationId(LocationIdTLV),
+    ExtendedPowerViaMdi(ExtendedPowerViaMdiTLV),
+    HardwareRevision(HardwareRevisionTLV),
+    FirmwareRevision(FirmwareRevisionTLV),
+    SoftwareRevision(SoftwareRevisionTLV),
+    SerialNumber(SerialNumberTLV),
+    ManufacturerName(ManufacturerNameTLV),
+    ModelName(ModelNameTLV),
+    AssetId(AssetIdTLV),
+}
+
+org_tlv_dispatch! {
+    MedTlv {
+        Capabilities(MedCapabilitiesTLV),
+        NetworkPolicy(NetworkPolicyTLV),
+        LocationId(LocationIdTLV),
+        ExtendedPowerViaMdi(ExtendedPowerViaMdiTLV),
+        HardwareRevision(HardwareRevisionTLV),
+        FirmwareRevision(FirmwareRevisionTLV),
+        SoftwareRevision(SoftwareRevisionTLV),
+        SerialNumber(SerialNumberTLV),
+        ManufacturerName(ManufacturerNameTLV),
+        ModelName(ModelNameTLV),
+        AssetId(AssetIdTLV),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::tests::roundtrip;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+    use crate::tlv::TlvCodec;
+
+    #[test]
+    fn test_capabilities() {
+        let tlv = roundtrip::<MedCapabilitiesTLV>(b"\xFE\x07\x00\x12\xBB\x01\x00\x33\x03");
+        assert_eq!(
+            tlv.to_string(),
+            "MedCapabilitiesTLV(\"Capabilities, Network Policy, Extended Power via MDI-PD, Inventory\", Endpoint \
+             Class III)"
+        );
+    }
+
+    #[test]
+    fn test_network_policy() {
+        let tlv = roundtrip::<NetworkPolicyTLV>(b"\xFE\x08\x00\x12\xBB\x02\x01\x40\xC9\x6E");
+        assert_eq!(tlv, NetworkPolicyTLV::new(1, true, 100, 5, 46));
+
+        let tlv = roundtrip::<NetworkPolicyTLV>(b"\xFE\x08\x00\x12\xBB\x02\x02\x80\x00\x00");
+        assert_eq!(tlv, NetworkPolicyTLV::unknown(2));
+        assert_eq!(
+            tlv.to_string(),
+            "NetworkPolicyTLV(Voice Signaling, unknown, untagged, vlan 0, priority 0, dscp 0)"
+        );
+    }
+
+    #[test]
+    fn test_coordinate_location() {
+        let lci = CoordinateLci::new(48.1371, -11.5754, 519.25);
+        let tlv = LocationIdTLV::new(Location::Coordinate(lci.clone())).to_tlv();
+        assert_eq!(tlv.value.len(), 17);
+        assert_eq!(tlv.value[1] >> 2, 34);
+
+        let decoded = roundtrip::<LocationIdTLV>(&tlv.bytes());
+        match &decoded.location {
+            Location::Coordinate(decoded) => {
+                assert_eq!(decoded, &lci);
+                assert!((decoded.latitude_degrees() - 48.1371).abs() < 1e-6);
+                assert!((decoded.longitude_degrees() + 11.5754).abs() < 1e-6);
+                assert_eq!(decoded.altitude_value(), 519.25);
+            }
+            location => panic!("expected coordinate location, got {:?}", location),
+        }
+        assert_eq!(
+            decoded.to_string(),
+            "LocationIdTLV(Coordinate(48.137100, -11.575400, 519.25 m, WGS84))"
+        );
+    }
+
+    #[test]
+    fn test_civic_location() {
+        let tlv = roundtrip::<LocationIdTLV>(
+            b"\xFE\x1D\x00\x12\xBB\x03\x02\x17\x02DE\x01\x07Bavaria\x03\x06Munich\x13\x011",
+        );
+        match &tlv.location {
+            Location::Civic(address) => {
+                assert_eq!(address.country, "DE");
+                assert_eq!(address.elements[1].type_name(), Some("city"));
+                assert_eq!(address.elements[2].value, "1");
+            }
+            location => panic!("expected civic location, got {:?}", location),
+        }
+        assert_eq!(
+            tlv.to_string(),
+            "LocationIdTLV(Civic(DE, state \"Bavaria\", city \"Munich\", house number \"1\"))"
+        );
+    }
+
+    #[test]
+    fn test_civic_address_try_new() {
+        let address = CivicAddress::try_new(
+            String::from("DE"),
+            vec![CivicAddressElement::new(3, "x".repeat(250))],
+        )
+        .unwrap();
+        assert_eq!(address.len(), 255);
+        assert_eq!(
+            LocationIdTLV::new(Location::Civic(address))
+                .to_tlv()
+                .value
+                .len(),
+            257
+        );
+
+        assert_eq!(
+            CivicAddress::try_new(
+                String::from("DE"),
+                vec![CivicAddressElement::new(3, "x".repeat(256))],
+            )
+            .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 267
+            }
+        );
+        assert_eq!(
+            CivicAddress::try_new(
+                String::from("DE"),
+                vec![
+                    CivicAddressElement::new(3, "x".repeat(200)),
+                    CivicAddressElement::new(6, "x".repeat(200)),
+                ],
+            )
+            .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 413
+            }
+        );
+        assert_eq!(
+            CivicAddress::try_new(String::from("DEU"), vec![]).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 10
+            }
+        );
+    }
+
+    #[test]
+    fn test_elin_location() {
+        let tlv = roundtrip::<LocationIdTLV>(b"\xFE\x0F\x00\x12\xBB\x03\x031234567890");
+        assert_eq!(tlv.to_string(), "LocationIdTLV(Elin(\"1234567890\"))");
+    }
+
+    #[test]
+    fn test_extended_power_via_mdi() {
+        let tlv = roundtrip::<ExtendedPowerViaMdiTLV>(b"\xFE\x07\x00\x12\xBB\x04\x53\x00\x82");
+        assert_eq!(tlv, ExtendedPowerViaMdiTLV::new(1, 1, 3, 130));
+        assert_eq!(
+            tlv.to_string(),
+            "ExtendedPowerViaMdiTLV(PD, PSE, priority low, 13.0 W)"
+        );
+    }
+
+    #[test]
+    fn test_inventory() {
+        let tlv = roundtrip::<HardwareRevisionTLV>(b"\xFE\x0A\x00\x12\xBB\x05rev 2B");
+        assert_eq!(tlv, HardwareRevisionTLV::new(String::from("rev 2B")));
+        assert_eq!(tlv.to_string(), "HardwareRevisionTLV(\"rev 2B\")");
+
+        let tlv = roundtrip::<AssetIdTLV>(b"\xFE\x0B\x00\x12\xBB\x0BASSET01");
+        assert_eq!(tlv.to_string(), "AssetIdTLV(\"ASSET01\")");
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        let bad_length = |length| LldpError::BadLength {
+            tlv_type: TlvType::OrganizationallySpecific,
+            length,
+        };
+
+        // LCI length does not match
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 3, b"\x02\x05\x02DE\x03".to_vec());
+        assert_eq!(MedTlv::decode(&tlv), Some(Err(bad_length(10))));
+
+        // Truncated CA element
+        let tlv =
+            OrganizationallySpecificTLV::new(OUI.to_vec(), 3, b"\x02\x06\x02DE\x03\x05M".to_vec());
+        assert_eq!(MedTlv::decode(&tlv), Some(Err(bad_length(12))));
+
+        // ELIN too short
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 3, b"\x03112".to_vec());
+        assert_eq!(MedTlv::decode(&tlv), Some(Err(bad_length(8))));
+
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 8, vec![b'x'; 33]);
+        assert_eq!(MedTlv::decode(&tlv), Some(Err(bad_length(37))));
+
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 12, vec![]);
+        assert_eq!(MedTlv::decode(&tlv), None);
+    }
+}
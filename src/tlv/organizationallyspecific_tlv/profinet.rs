@@ -0,0 +1,822 @@
+//! PROFINET Organizationally Specific TLVs
+//!
+//! TLVs defined by IEC 61158-6-10 for PROFINET IO devices, transmitted with the OUI 00-0E-CF. PROFINET devices also
+//! advertise their NameOfStation in the Chassis ID and Port ID TLVs, see [name_of_station].
+//!
+//! ```
+//! use lldp_rs::tlv::organizationallyspecific_tlv::profinet::PortStatusTLV;
+//! use lldp_rs::tlv::organizationallyspecific_tlv::OrgTlvCodec;
+//!
+//! let tlv = PortStatusTLV::new(0x0002, 0x0004);
+//! assert_eq!(tlv.to_tlv().value, b"\x00\x02\x00\x04");
+//! assert_eq!(tlv.to_string(), "PortStatusTLV(RTClass2 up, RTClass3 running)");
+//! ```
+
+use crate::error::LldpError;
+use crate::lldpdu::Lldpdu;
+use crate::tlv::chassisid_tlv::{ChassisIdSubType, ChassisIdValue};
+use crate::tlv::mac_address::MacAddress;
+use crate::tlv::organizationallyspecific_tlv::{check_info_len, OrgTlvCodec, MAX_INFO_LEN};
+use crate::tlv::portid_tlv::{PortIdSubtype, PortIdValue};
+use crate::tlv::TlvType;
+use bytes::BufMut;
+use std::fmt::Display;
+
+/// OUI of PROFINET
+pub const OUI: [u8; 3] = [0x00, 0x0E, 0xCF];
+
+/// Format a UUID, e.g. `dea00000-6c97-11d1-8271-00a02442df7d`
+fn uuid(uuid: &[u8; 16]) -> String {
+    let hex: String = uuid.iter().map(|b| format!("{:02x}", b)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+fn decode_uuid(bytes: &[u8]) -> [u8; 16] {
+    let mut uuid = [0; 16];
+    uuid.copy_from_slice(&bytes[..16]);
+    uuid
+}
+
+fn decode_mac(bytes: &[u8]) -> MacAddress {
+    let mut mac = [0; 6];
+    mac.copy_from_slice(&bytes[..6]);
+    MacAddress::new(mac)
+}
+
+fn decode_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+/// Get the PROFINET NameOfStation of the sender of an LLDPDU
+///
+/// PROFINET devices send a locally assigned Port ID of the form `port-001.<NameOfStation>` or
+/// `port-001-00002.<NameOfStation>`, and may send their NameOfStation as locally assigned Chassis ID. Returns `None`
+/// if neither is present.
+///
+/// ```
+/// use lldp_rs::lldpdu::builder::LldpduBuilder;
+/// use lldp_rs::tlv::organizationallyspecific_tlv::profinet::name_of_station;
+///
+/// let lldpdu = LldpduBuilder::new()
+///     .chassis_mac([0x00, 0x0E, 0xCF, 0x01, 0x02, 0x03])
+///     .port_ifname("port-001.plc-1")
+///     .ttl(20)
+///     .build()
+///     .unwrap();
+/// assert_eq!(name_of_station(&lldpdu), Some("plc-1"));
+/// ```
+pub fn name_of_station(lldpdu: &Lldpdu) -> Option<&str> {
+    let from_port = lldpdu
+        .port_id()
+        .and_then(|port| match (&port.subtype, &port.value) {
+            (PortIdSubtype::Local, PortIdValue::Other(id))
+            | (PortIdSubtype::InterfaceName, PortIdValue::Other(id))
+                if id.starts_with("port-") =>
+            {
+                id.split_once('.').map(|(_, name)| name)
+            }
+            _ => None,
+        });
+
+    from_port.or_else(|| {
+        lldpdu
+            .chassis_id()
+            .and_then(|chassis| match (&chassis.subtype, &chassis.value) {
+                (ChassisIdSubType::Local, ChassisIdValue::Other(name)) => Some(name.as_str()),
+                _ => None,
+            })
+    })
+}
+
+/// Measured Delay Values TLV
+///
+/// Advertises the line delays measured by the port, in nanoseconds. A value of 0 means that the delay is not known.
+///
+/// | Subtype | Information                                                                   |
+/// | ------- | ----------------------------------------------------------------------------- |
+/// |    1    | RX Delay Local, RX Delay Remote, TX Delay Local, TX Delay Remote, Cable Delay (4 bytes each) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeasuredDelayTLV {
+    pub rx_delay_local: u32,
+    pub rx_delay_remote: u32,
+    pub tx_delay_local: u32,
+    pub tx_delay_remote: u32,
+    pub cable_delay_local: u32,
+}
+
+impl MeasuredDelayTLV {
+    /// Constructor
+    pub fn new(
+        rx_delay_local: u32,
+        rx_delay_remote: u32,
+        tx_delay_local: u32,
+        tx_delay_remote: u32,
+        cable_delay_local: u32,
+    ) -> MeasuredDelayTLV {
+        MeasuredDelayTLV {
+            rx_delay_local,
+            rx_delay_remote,
+            tx_delay_local,
+            tx_delay_remote,
+            cable_delay_local,
+        }
+    }
+}
+
+impl Display for MeasuredDelayTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MeasuredDelayTLV(rx {}/{} ns, tx {}/{} ns, cable {} ns)",
+            self.rx_delay_local,
+            self.rx_delay_remote,
+            self.tx_delay_local,
+            self.tx_delay_remote,
+            self.cable_delay_local
+        )
+    }
+}
+
+impl OrgTlvCodec for MeasuredDelayTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 1;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u32(self.rx_delay_local);
+        buf.put_u32(self.rx_delay_remote);
+        buf.put_u32(self.tx_delay_local);
+        buf.put_u32(self.tx_delay_remote);
+        buf.put_u32(self.cable_delay_local);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<MeasuredDelayTLV, LldpError> {
+        check_info_len(info, 20..=20)?;
+
+        Ok(MeasuredDelayTLV::new(
+            decode_u32(&info[0..]),
+            decode_u32(&info[4..]),
+            decode_u32(&info[8..]),
+            decode_u32(&info[12..]),
+            decode_u32(&info[16..]),
+        ))
+    }
+}
+
+/// Port Status TLV
+///
+/// Advertises the state of the isochronous real-time classes of the port.
+///
+/// | Subtype | Information                                              |
+/// | ------- | -------------------------------------------------------- |
+/// |    2    | RTClass2 Port Status (2 bytes), RTClass3 Port Status (2 bytes) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PortStatusTLV {
+    /// The RTClass2 port status (0: off, 1: configured, 2: up, 3: running)
+    pub rt_class2: u16,
+    /// The RTClass3 port status, the lower 3 bits hold the state (0: off, 2: up, 4: running), bits 12 to 14 the
+    /// fragmentation, preamble length and optimized flags
+    pub rt_class3: u16,
+}
+
+impl PortStatusTLV {
+    /// Constructor
+    pub fn new(rt_class2: u16, rt_class3: u16) -> PortStatusTLV {
+        PortStatusTLV {
+            rt_class2,
+            rt_class3,
+        }
+    }
+}
+
+impl Display for PortStatusTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PortStatusTLV(RTClass2 ")?;
+        match self.rt_class2 & 0x07 {
+            0 => write!(f, "off")?,
+            1 => write!(f, "configured")?,
+            2 => write!(f, "up")?,
+            3 => write!(f, "running")?,
+            status => write!(f, "{}", status)?,
+        }
+
+        write!(f, ", RTClass3 ")?;
+        match self.rt_class3 & 0x07 {
+            0 => write!(f, "off")?,
+            2 => write!(f, "up")?,
+            4 => write!(f, "running")?,
+            status => write!(f, "{}", status)?,
+        }
+        for (bit, flag) in [
+            (12, "fragmentation"),
+            (13, "short preamble"),
+            (14, "optimized"),
+        ]
+        .iter()
+        {
+            if self.rt_class3 & (1 << bit) != 0 {
+                write!(f, ", {}", flag)?;
+            }
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl OrgTlvCodec for PortStatusTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 2;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.rt_class2);
+        buf.put_u16(self.rt_class3);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<PortStatusTLV, LldpError> {
+        check_info_len(info, 4..=4)?;
+
+        Ok(PortStatusTLV::new(
+            u16::from_be_bytes([info[0], info[1]]),
+            u16::from_be_bytes([info[2], info[3]]),
+        ))
+    }
+}
+
+/// Alias TLV
+///
+/// Advertises the alias name of the port, i.e. `<port name>.<NameOfStation>`. The TLV is not sent by devices
+/// implementing newer versions of IEC 61158-6-10.
+///
+/// | Subtype | Information  |
+/// | ------- | ------------ |
+/// |    3    | Alias        |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AliasTLV {
+    /// The alias name
+    pub alias: String,
+}
+
+impl AliasTLV {
+    /// Constructor
+    pub fn new(alias: String) -> AliasTLV {
+        AliasTLV { alias }
+    }
+
+    /// Create an Alias TLV, checking the length of the alias.
+    ///
+    /// Returns an error if the alias is longer than the 507 bytes of organizationally defined information.
+    pub fn try_new(alias: String) -> Result<AliasTLV, LldpError> {
+        if alias.len() > MAX_INFO_LEN {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 4 + alias.len(),
+            });
+        }
+
+        Ok(AliasTLV::new(alias))
+    }
+}
+
+impl Display for AliasTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AliasTLV(\"{}\")", self.alias)
+    }
+}
+
+impl OrgTlvCodec for AliasTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 3;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_slice(self.alias.as_bytes());
+    }
+
+    fn decode_info(info: &[u8]) -> Result<AliasTLV, LldpError> {
+        let alias = String::from_utf8(info.to_vec())
+            .map_err(|_| LldpError::InvalidUtf8(TlvType::OrganizationallySpecific))?;
+
+        Ok(AliasTLV::new(alias))
+    }
+}
+
+/// MRP Port Status TLV
+///
+/// Advertises the Media Redundancy Protocol domain of the port and the state of its MRRT (media redundancy for
+/// real-time) function.
+///
+/// | Subtype | Information                                           |
+/// | ------- | ----------------------------------------------------- |
+/// |    4    | MRP Domain UUID (16 bytes), MRRT Port Status (2 bytes) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MrpPortStatusTLV {
+    /// The UUID of the MRP domain
+    pub domain_uuid: [u8; 16],
+    /// The MRRT port status (0: off, 1: configured, 2: up)
+    pub mrrt_status: u16,
+}
+
+impl MrpPortStatusTLV {
+    /// Constructor
+    pub fn new(domain_uuid: [u8; 16], mrrt_status: u16) -> MrpPortStatusTLV {
+        MrpPortStatusTLV {
+            domain_uuid,
+            mrrt_status,
+        }
+    }
+}
+
+impl Display for MrpPortStatusTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MrpPortStatusTLV({}, MRRT ", uuid(&self.domain_uuid))?;
+        match self.mrrt_status {
+            0 => write!(f, "off)"),
+            1 => write!(f, "configured)"),
+            2 => write!(f, "up)"),
+            status => write!(f, "{})", status),
+        }
+    }
+}
+
+impl OrgTlvCodec for MrpPortStatusTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 4;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&self.domain_uuid);
+        buf.put_u16(self.mrrt_status);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<MrpPortStatusTLV, LldpError> {
+        check_info_len(info, 18..=18)?;
+
+        Ok(MrpPortStatusTLV::new(
+            decode_uuid(info),
+            u16::from_be_bytes([info[16], info[17]]),
+        ))
+    }
+}
+
+/// Chassis MAC TLV
+///
+/// Advertises the MAC address of the interface of the device, which differs from the MAC address of the port.
+///
+/// | Subtype | Information            |
+/// | ------- | ---------------------- |
+/// |    5    | MAC Address (6 bytes)  |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChassisMacTLV {
+    /// The MAC address of the device
+    pub mac: MacAddress,
+}
+
+impl ChassisMacTLV {
+    /// Constructor
+    pub fn new(mac: MacAddress) -> ChassisMacTLV {
+        ChassisMacTLV { mac }
+    }
+}
+
+impl Display for ChassisMacTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ChassisMacTLV(\"{}\")", self.mac)
+    }
+}
+
+impl OrgTlvCodec for ChassisMacTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 5;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&self.mac.octets());
+    }
+
+    fn decode_info(info: &[u8]) -> Result<ChassisMacTLV, LldpError> {
+        check_info_len(info, 6..=6)?;
+
+        Ok(ChassisMacTLV::new(decode_mac(info)))
+    }
+}
+
+/// PTCP Status TLV
+///
+/// Advertises the state of the precision transparent clock protocol synchronization and the phases of the
+/// isochronous cycle. The bit 31 of each period value indicates that the value is valid, the lower bits hold the
+/// length or the begin of the period in nanoseconds.
+///
+/// | Subtype | Information                                                                                    |
+/// | ------- | ---------------------------------------------------------------------------------------------- |
+/// |    6    | Master Source Address (6 bytes), Subdomain UUID (16 bytes), IRData UUID (16 bytes), Length of Period, Red Period Begin, Orange Period Begin, Green Period Begin (4 bytes each) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PtcpStatusTLV {
+    /// The MAC address of the sync master
+    pub master: MacAddress,
+    /// The UUID of the sync domain
+    pub subdomain_uuid: [u8; 16],
+    /// The UUID of the isochronous real-time configuration
+    pub ir_data_uuid: [u8; 16],
+    /// The length of the isochronous cycle
+    pub length_of_period: u32,
+    /// The begin of the red (isochronous) phase
+    pub red_period_begin: u32,
+    /// The begin of the orange (cyclic real-time) phase
+    pub orange_period_begin: u32,
+    /// The begin of the green (non real-time) phase
+    pub green_period_begin: u32,
+}
+
+impl PtcpStatusTLV {
+    /// Constructor, the periods are not valid
+    pub fn new(
+        master: MacAddress,
+        subdomain_uuid: [u8; 16],
+        ir_data_uuid: [u8; 16],
+    ) -> PtcpStatusTLV {
+        PtcpStatusTLV {
+            master,
+            subdomain_uuid,
+            ir_data_uuid,
+            length_of_period: 0,
+            red_period_begin: 0,
+            orange_period_begin: 0,
+            green_period_begin: 0,
+        }
+    }
+
+    /// Set the period values, the values are marked valid
+    pub fn with_periods(mut self, length: u32, red: u32, orange: u32, green: u32) -> PtcpStatusTLV {
+        self.length_of_period = 1 << 31 | length;
+        self.red_period_begin = 1 << 31 | red;
+        self.orange_period_begin = 1 << 31 | orange;
+        self.green_period_begin = 1 << 31 | green;
+        self
+    }
+}
+
+impl Display for PtcpStatusTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PtcpStatusTLV(\"{}\", {}, {}",
+            self.master,
+            uuid(&self.subdomain_uuid),
+            uuid(&self.ir_data_uuid)
+        )?;
+
+        let periods = [
+            ("period", self.length_of_period),
+            ("red", self.red_period_begin),
+            ("orange", self.orange_period_begin),
+            ("green", self.green_period_begin),
+        ];
+        for (name, value) in periods.iter() {
+            if value & (1 << 31) != 0 {
+                write!(f, ", {} {} ns", name, value & 0x7FFF_FFFF)?;
+            }
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl OrgTlvCodec for PtcpStatusTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 6;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&self.master.octets());
+        buf.put_slice(&self.subdomain_uuid);
+        buf.put_slice(&self.ir_data_uuid);
+        buf.put_u32(self.length_of_period);
+        buf.put_u32(self.red_period_begin);
+        buf.put_u32(self.orange_period_begin);
+        buf.put_u32(self.green_period_begin);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<PtcpStatusTLV, LldpError> {
+        check_info_len(info, 54..=54)?;
+
+        Ok(PtcpStatusTLV {
+            master: decode_mac(info),
+            subdomain_uuid: decode_uuid(&info[6..]),
+            ir_data_uuid: decode_uuid(&info[22..]),
+            length_of_period: decode_u32(&info[38..]),
+            red_period_begin: decode_u32(&info[42..]),
+            orange_period_begin: decode_u32(&info[46..]),
+            green_period_begin: decode_u32(&info[50..]),
+        })
+    }
+}
+
+/// MAU Type Extension TLV
+///
+/// Refines the MAU type of the IEEE 802.3 MAC/PHY Configuration/Status TLV, e.g. for polymer optical fiber.
+///
+/// | Subtype | Information                  |
+/// | ------- | ---------------------------- |
+/// |    7    | MAU Type Extension (2 bytes) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MauTypeExtensionTLV {
+    /// The MAU type extension (0: none, 0x0100: polymer optical fiber)
+    pub extension: u16,
+}
+
+impl MauTypeExtensionTLV {
+    /// Constructor
+    pub fn new(extension: u16) -> MauTypeExtensionTLV {
+        MauTypeExtensionTLV { extension }
+    }
+}
+
+impl Display for MauTypeExtensionTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.extension {
+            0x0000 => write!(f, "MauTypeExtensionTLV(none)"),
+            0x0100 => write!(f, "MauTypeExtensionTLV(POF)"),
+            extension => write!(f, "MauTypeExtensionTLV(0x{:04X})", extension),
+        }
+    }
+}
+
+impl OrgTlvCodec for MauTypeExtensionTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 7;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.extension);
+    }
+
+    fn decode_info(info: &[u8]) -> Result<MauTypeExtensionTLV, LldpError> {
+        check_info_len(info, 2..=2)?;
+
+        Ok(MauTypeExtensionTLV::new(u16::from_be_bytes([
+            info[0], info[1],
+        ])))
+    }
+}
+
+/// MRP Interconnection Port Status TLV
+///
+/// Advertises the MRP interconnection domain, which couples two MRP rings, and the role of the port in it.
+///
+/// | Subtype | Information                                                        |
+/// | ------- | ------------------------------------------------------------------ |
+/// |    8    | Domain ID (2 bytes), Role (2 bytes), Multicast MAC Address (6 bytes) |
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MrpInterconnectionPortStatusTLV {
+    /// The ID of the MRP interconnection domain
+    pub domain_id: u16,
+    /// The role of the port (0: none, 1: client, 2: manager)
+    pub role: u16,
+    /// The multicast MAC address of the interconnection domain
+    pub multicast: MacAddress,
+}
+
+impl MrpInterconnectionPortStatusTLV {
+    /// Constructor
+    pub fn new(
+        domain_id: u16,
+        role: u16,
+        multicast: MacAddress,
+    ) -> MrpInterconnectionPortStatusTLV {
+        MrpInterconnectionPortStatusTLV {
+            domain_id,
+            role,
+            multicast,
+        }
+    }
+}
+
+impl Display for MrpInterconnectionPortStatusTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MrpInterconnectionPortStatusTLV({}, ", self.domain_id)?;
+        match self.role {
+            0 => write!(f, "none")?,
+            1 => write!(f, "client")?,
+            2 => write!(f, "manager")?,
+            role => write!(f, "{}", role)?,
+        }
+
+        write!(f, ", \"{}\")", self.multicast)
+    }
+}
+
+impl OrgTlvCodec for MrpInterconnectionPortStatusTLV {
+    const OUI: [u8; 3] = OUI;
+    const SUBTYPE: u8 = 8;
+
+    fn encode_info(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.domain_id);
+        buf.put_u16(self.role);
+        buf.put_slice(&self.multicast.octets());
+    }
+
+    fn decode_info(info: &[u8]) -> Result<MrpInterconnectionPortStatusTLV, LldpError> {
+        check_info_len(info, 10..=10)?;
+
+        Ok(MrpInterconnectionPortStatusTLV::new(
+            u16::from_be_bytes([info[0], info[1]]),
+            u16::from_be_bytes([info[2], info[3]]),
+            decode_mac(&info[4..]),
+        ))
+    }
+}
+
+/// PROFINET Organizationally Specific TLVs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfinetTlv {
+    MeasuredDelay(MeasuredDelayTLV),
+    PortStatus(PortStatusTLV),
+    Alias(AliasTLV),
+    MrpPortStatus(MrpPortStatusTLV),
+    ChassisMac(ChassisMacTLV),
+    PtcpStatus(PtcpStatusTLV),
+    MauTypeExtension(MauTypeExtensionTLV),
+    MrpInterconnectionPortStatus(MrpInterconnectionPortStatusTLV),
+}
+
+org_tlv_dispatch! {
+    ProfinetTlv {
+        MeasuredDelay(MeasuredDelayTLV),
+        PortStatus(PortStatusTLV),
+        Alias(AliasTLV),
+        MrpPortStatus(MrpPortStatusTLV),
+        ChassisMac(ChassisMacTLV),
+        PtcpStatus(PtcpStatusTLV),
+        MauTypeExtension(MauTypeExtensionTLV),
+        MrpInterconnectionPortStatus(MrpInterconnectionPortStatusTLV),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lldpdu::builder::LldpduBuilder;
+    use crate::tlv::organizationallyspecific_tlv::tests::roundtrip;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+    use crate::tlv::TlvCodec;
+
+    const UUID: [u8; 16] = [
+        0xde, 0xa0, 0x00, 0x00, 0x6c, 0x97, 0x11, 0xd1, 0x82, 0x71, 0x00, 0xa0, 0x24, 0x42, 0xdf,
+        0x7d,
+    ];
+
+    #[test]
+    fn test_measured_delay() {
+        let tlv = roundtrip::<MeasuredDelayTLV>(
+            b"\xFE\x18\x00\x0E\xCF\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x32",
+        );
+        assert_eq!(
+            tlv.to_string(),
+            "MeasuredDelayTLV(rx 0/0 ns, tx 0/0 ns, cable 50 ns)"
+        );
+    }
+
+    #[test]
+    fn test_port_status() {
+        let tlv = roundtrip::<PortStatusTLV>(b"\xFE\x08\x00\x0E\xCF\x02\x00\x00\x30\x04");
+        assert_eq!(
+            tlv.to_string(),
+            "PortStatusTLV(RTClass2 off, RTClass3 running, fragmentation, short preamble)"
+        );
+    }
+
+    #[test]
+    fn test_alias() {
+        let tlv = roundtrip::<AliasTLV>(b"\xFE\x12\x00\x0E\xCF\x03port-001.plc-1");
+        assert_eq!(tlv, AliasTLV::new(String::from("port-001.plc-1")));
+
+        let tlv = AliasTLV::try_new("x".repeat(507)).unwrap();
+        assert_eq!(tlv.to_tlv().bytes().len(), 2 + 511);
+        assert_eq!(
+            AliasTLV::try_new("x".repeat(600)).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 604
+            }
+        );
+    }
+
+    #[test]
+    fn test_mrp_port_status() {
+        let tlv = MrpPortStatusTLV::new(UUID, 2);
+        let decoded = roundtrip::<MrpPortStatusTLV>(&tlv.to_tlv().bytes());
+        assert_eq!(decoded, tlv);
+        assert_eq!(
+            decoded.to_string(),
+            "MrpPortStatusTLV(dea00000-6c97-11d1-8271-00a02442df7d, MRRT up)"
+        );
+    }
+
+    #[test]
+    fn test_chassis_mac() {
+        let tlv = roundtrip::<ChassisMacTLV>(b"\xFE\x0A\x00\x0E\xCF\x05\x00\x0E\xCF\x01\x02\x03");
+        assert_eq!(tlv.to_string(), "ChassisMacTLV(\"00:0e:cf:01:02:03\")");
+    }
+
+    #[test]
+    fn test_ptcp_status() {
+        let tlv = PtcpStatusTLV::new(
+            MacAddress::new([0x00, 0x0E, 0xCF, 0x01, 0x02, 0x03]),
+            UUID,
+            [0; 16],
+        )
+        .with_periods(1_000_000, 0, 250_000, 500_000);
+        let decoded = roundtrip::<PtcpStatusTLV>(&tlv.to_tlv().bytes());
+        assert_eq!(decoded, tlv);
+        assert_eq!(
+            decoded.to_string(),
+            "PtcpStatusTLV(\"00:0e:cf:01:02:03\", dea00000-6c97-11d1-8271-00a02442df7d, \
+             00000000-0000-0000-0000-000000000000, period 1000000 ns, red 0 ns, orange 250000 ns, green 500000 ns)"
+        );
+    }
+
+    #[test]
+    fn test_mau_type_extension() {
+        let tlv = roundtrip::<MauTypeExtensionTLV>(b"\xFE\x06\x00\x0E\xCF\x07\x01\x00");
+        assert_eq!(tlv.to_string(), "MauTypeExtensionTLV(POF)");
+    }
+
+    #[test]
+    fn test_mrp_interconnection_port_status() {
+        let tlv = roundtrip::<MrpInterconnectionPortStatusTLV>(
+            b"\xFE\x0E\x00\x0E\xCF\x08\x00\x01\x00\x02\x01\x15\x4E\x00\x00\x01",
+        );
+        assert_eq!(
+            tlv.to_string(),
+            "MrpInterconnectionPortStatusTLV(1, manager, \"01:15:4e:00:00:01\")"
+        );
+    }
+
+    #[test]
+    fn test_name_of_station() {
+        let lldpdu = LldpduBuilder::new()
+            .chassis_id(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("io-device-7")),
+            )
+            .port_id(
+                PortIdSubtype::Local,
+                PortIdValue::Other(String::from("port-002-00001.io-device-7")),
+            )
+            .ttl(20)
+            .build()
+            .unwrap();
+        assert_eq!(name_of_station(&lldpdu), Some("io-device-7"));
+
+        let lldpdu = LldpduBuilder::new()
+            .chassis_id(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("io-device-7")),
+            )
+            .port_ifname("eth0")
+            .ttl(20)
+            .build()
+            .unwrap();
+        assert_eq!(name_of_station(&lldpdu), Some("io-device-7"));
+
+        let lldpdu = LldpduBuilder::new()
+            .chassis_mac([0x00, 0x0E, 0xCF, 0x01, 0x02, 0x03])
+            .port_ifname("eth0")
+            .ttl(20)
+            .build()
+            .unwrap();
+        assert_eq!(name_of_station(&lldpdu), None);
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 6, vec![0; 53]);
+        assert_eq!(
+            ProfinetTlv::decode(&tlv),
+            Some(Err(LldpError::BadLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 57
+            }))
+        );
+
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 9, vec![]);
+        assert_eq!(ProfinetTlv::decode(&tlv), None);
+    }
+}
@@ -0,0 +1,277 @@
+//! Registry of Organizationally Specific TLV decoders
+//!
+//! Decoders for proprietary TLVs can be registered for an OUI and subtype without changing this crate.
+//! [OrgTlvRegistry::with_builtin] creates a registry that already knows the TLVs defined in this crate.
+//!
+//! ```
+//! use lldp_rs::error::LldpError;
+//! use lldp_rs::tlv::organizationallyspecific_tlv::registry::{OrgTlvRegistry, OrgTlvValue};
+//! use lldp_rs::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+//! use lldp_rs::tlv::TlvType;
+//! use std::any::Any;
+//! use std::fmt;
+//!
+//! #[derive(Debug)]
+//! struct RackTLV(u8);
+//!
+//! impl fmt::Display for RackTLV {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         write!(f, "RackTLV({})", self.0)
+//!     }
+//! }
+//!
+//! impl OrgTlvValue for RackTLV {
+//!     fn write_info(&self, buf: &mut Vec<u8>) {
+//!         buf.push(self.0);
+//!     }
+//!
+//!     fn as_any(&self) -> &dyn Any {
+//!         self
+//!     }
+//! }
+//!
+//! let mut registry = OrgTlvRegistry::with_builtin();
+//! registry.register([0x02, 0x00, 0x00], 1, |info: &[u8]| match info {
+//!     [rack] => Ok(Box::new(RackTLV(*rack)) as Box<dyn OrgTlvValue>),
+//!     _ => Err(LldpError::BadLength {
+//!         tlv_type: TlvType::OrganizationallySpecific,
+//!         length: 4 + info.len(),
+//!     }),
+//! });
+//!
+//! let tlv = OrganizationallySpecificTLV::new(vec![0x02, 0x00, 0x00], 1, vec![42]);
+//! let value = registry.decode(&tlv).unwrap().unwrap();
+//! assert_eq!(value.to_string(), "RackTLV(42)");
+//! assert_eq!(value.as_any().downcast_ref::<RackTLV>().unwrap().0, 42);
+//! assert_eq!(OrganizationallySpecificTLV::from_value([0x02, 0x00, 0x00], 1, &*value).value, tlv.value);
+//! ```
+
+use crate::error::LldpError;
+use crate::tlv::organizationallyspecific_tlv::dcbx::DcbxTlv;
+use crate::tlv::organizationallyspecific_tlv::ieee8021::Ieee8021Tlv;
+use crate::tlv::organizationallyspecific_tlv::ieee8023::Ieee8023Tlv;
+use crate::tlv::organizationallyspecific_tlv::med::MedTlv;
+use crate::tlv::organizationallyspecific_tlv::profinet::ProfinetTlv;
+use crate::tlv::organizationallyspecific_tlv::{OrgTlvCodec, OrganizationallySpecificTLV};
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+
+/// Typed organizationally defined information, see [OrgTlvDecoder]
+pub trait OrgTlvValue: Display + Debug {
+    /// Write the organizationally defined information to `buf`.
+    fn write_info(&self, buf: &mut Vec<u8>);
+
+    /// Return the value as [Any], to downcast it to its concrete type
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: OrgTlvCodec + Display + Debug + 'static> OrgTlvValue for T {
+    fn write_info(&self, buf: &mut Vec<u8>) {
+        self.encode_info(buf);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Decoder of the organizationally defined information of one OUI and subtype
+///
+/// Implemented by closures taking the information and returning the boxed value.
+pub trait OrgTlvDecoder {
+    /// Decode the organizationally defined information.
+    ///
+    /// Returns an error if the information is malformed.
+    fn decode(&self, info: &[u8]) -> Result<Box<dyn OrgTlvValue>, LldpError>;
+}
+
+impl<F> OrgTlvDecoder for F
+where
+    F: Fn(&[u8]) -> Result<Box<dyn OrgTlvValue>, LldpError>,
+{
+    fn decode(&self, info: &[u8]) -> Result<Box<dyn OrgTlvValue>, LldpError> {
+        self(info)
+    }
+}
+
+/// Decoder of a TLV implementing [OrgTlvCodec], see [OrgTlvRegistry::register_codec]
+struct CodecDecoder<T>(PhantomData<T>);
+
+impl<T: OrgTlvCodec + Display + Debug + 'static> OrgTlvDecoder for CodecDecoder<T> {
+    fn decode(&self, info: &[u8]) -> Result<Box<dyn OrgTlvValue>, LldpError> {
+        Ok(Box::new(T::decode_info(info)?))
+    }
+}
+
+/// Registry of Organizationally Specific TLV decoders, keyed by OUI and subtype
+#[derive(Default)]
+pub struct OrgTlvRegistry {
+    decoders: HashMap<([u8; 3], u8), Box<dyn OrgTlvDecoder>>,
+}
+
+impl OrgTlvRegistry {
+    /// Create an empty registry
+    pub fn new() -> OrgTlvRegistry {
+        OrgTlvRegistry::default()
+    }
+
+    /// Create a registry with the decoders of all TLVs defined in this crate
+    ///
+    /// The decoders can be replaced by registering another decoder for the same OUI and subtype.
+    pub fn with_builtin() -> OrgTlvRegistry {
+        let mut registry = OrgTlvRegistry::new();
+        Ieee8021Tlv::register(&mut registry);
+        Ieee8023Tlv::register(&mut registry);
+        MedTlv::register(&mut registry);
+        DcbxTlv::register(&mut registry);
+        ProfinetTlv::register(&mut registry);
+
+        registry
+    }
+
+    /// Register a decoder for an OUI and subtype, replacing the decoder registered before.
+    pub fn register(&mut self, oui: [u8; 3], subtype: u8, decoder: impl OrgTlvDecoder + 'static) {
+        self.decoders.insert((oui, subtype), Box::new(decoder));
+    }
+
+    /// Register a TLV implementing [OrgTlvCodec] for its OUI and subtype.
+    pub fn register_codec<T: OrgTlvCodec + Display + Debug + 'static>(&mut self) {
+        self.register(T::OUI, T::SUBTYPE, CodecDecoder::<T>(PhantomData));
+    }
+
+    /// Remove the decoder of an OUI and subtype, returns `true` if a decoder was registered.
+    pub fn unregister(&mut self, oui: [u8; 3], subtype: u8) -> bool {
+        self.decoders.remove(&(oui, subtype)).is_some()
+    }
+
+    /// Check if a decoder is registered for an OUI and subtype
+    pub fn contains(&self, oui: [u8; 3], subtype: u8) -> bool {
+        self.decoders.contains_key(&(oui, subtype))
+    }
+
+    /// Decode the organizationally defined information of `tlv`.
+    ///
+    /// Returns `None` if no decoder is registered for the OUI and subtype, and an error if the information is
+    /// malformed.
+    pub fn decode(
+        &self,
+        tlv: &OrganizationallySpecificTLV,
+    ) -> Option<Result<Box<dyn OrgTlvValue>, LldpError>> {
+        let decoder = match tlv.oui[..] {
+            [a, b, c] => self.decoders.get(&([a, b, c], tlv.subtype))?,
+            _ => return None,
+        };
+
+        Some(decoder.decode(&tlv.value))
+    }
+}
+
+impl OrganizationallySpecificTLV {
+    /// Create an Organizationally Specific TLV carrying a value decoded by an [OrgTlvDecoder]
+    pub fn from_value(
+        oui: [u8; 3],
+        subtype: u8,
+        value: &dyn OrgTlvValue,
+    ) -> OrganizationallySpecificTLV {
+        let mut info = vec![];
+        value.write_info(&mut info);
+
+        OrganizationallySpecificTLV::new(oui.to_vec(), subtype, info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::ieee8021::{self, PortVlanIdTLV};
+    use crate::tlv::organizationallyspecific_tlv::{dcbx, ieee8023, med, profinet};
+
+    const OUI: [u8; 3] = [0x02, 0x00, 0x00];
+
+    #[derive(Debug, PartialEq)]
+    struct TemperatureTLV(i8);
+
+    impl Display for TemperatureTLV {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "TemperatureTLV({} °C)", self.0)
+        }
+    }
+
+    impl OrgTlvCodec for TemperatureTLV {
+        const OUI: [u8; 3] = OUI;
+        const SUBTYPE: u8 = 7;
+
+        fn encode_info(&self, buf: &mut impl bytes::BufMut) {
+            buf.put_i8(self.0);
+        }
+
+        fn decode_info(info: &[u8]) -> Result<TemperatureTLV, LldpError> {
+            crate::tlv::organizationallyspecific_tlv::check_info_len(info, 1..=1)?;
+
+            Ok(TemperatureTLV(info[0] as i8))
+        }
+    }
+
+    #[test]
+    fn test_register_codec() {
+        let mut registry = OrgTlvRegistry::new();
+        let tlv = TemperatureTLV(-5).to_tlv();
+        assert!(registry.decode(&tlv).is_none());
+
+        registry.register_codec::<TemperatureTLV>();
+        assert!(registry.contains(OUI, 7));
+
+        let value = registry.decode(&tlv).unwrap().unwrap();
+        assert_eq!(value.to_string(), "TemperatureTLV(-5 °C)");
+        assert_eq!(value.as_any().downcast_ref(), Some(&TemperatureTLV(-5)));
+        assert_eq!(
+            OrganizationallySpecificTLV::from_value(OUI, 7, &*value).value,
+            tlv.value
+        );
+
+        let tlv = OrganizationallySpecificTLV::new(OUI.to_vec(), 7, vec![]);
+        assert!(registry.decode(&tlv).unwrap().is_err());
+
+        assert!(registry.unregister(OUI, 7));
+        assert!(!registry.unregister(OUI, 7));
+    }
+
+    #[test]
+    fn test_builtin() {
+        let tlv = PortVlanIdTLV::new(100).to_tlv();
+        assert!(OrgTlvRegistry::new().decode(&tlv).is_none());
+
+        let mut registry = OrgTlvRegistry::with_builtin();
+        let value = registry.decode(&tlv).unwrap().unwrap();
+        assert_eq!(value.to_string(), "PortVlanIdTLV(100)");
+        assert_eq!(
+            value.as_any().downcast_ref(),
+            Some(&PortVlanIdTLV::new(100))
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::from_value(ieee8021::OUI, 1, &*value).value,
+            tlv.value
+        );
+        for (oui, subtype) in [
+            (ieee8021::OUI, 1),
+            (ieee8023::OUI, 1),
+            (med::OUI, 1),
+            (ieee8021::OUI, 9),
+            (dcbx::CEE_OUI, 2),
+            (profinet::OUI, 1),
+        ] {
+            assert!(registry.contains(oui, subtype));
+        }
+
+        // Built-in decoders can be replaced
+        let tlv = OrganizationallySpecificTLV::new(ieee8021::OUI.to_vec(), 1, vec![100]);
+        assert!(registry.decode(&tlv).unwrap().is_err());
+        registry.register(ieee8021::OUI, 1, |info: &[u8]| {
+            Ok(Box::new(TemperatureTLV::decode_info(info)?) as Box<dyn OrgTlvValue>)
+        });
+        let value = registry.decode(&tlv).unwrap().unwrap();
+        assert_eq!(value.to_string(), "TemperatureTLV(100 °C)");
+    }
+}
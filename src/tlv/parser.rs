@@ -0,0 +1,405 @@
+use crate::error::LldpError;
+use crate::tlv::mac_address::{Eui64, MacAddress};
+use crate::tlv::managementaddress_tlv;
+use crate::tlv::{Tlv, TlvHeader, TlvType, TLV_MAX_VALUE_LEN};
+
+use std::convert::TryFrom;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// Parser for the printable representation of TLVs
+///
+/// The parser reads the exact syntax written by the `Display` implementations, e.g. `TtlTLV(120)` or
+/// `ChassisIdTLV(4, "ff:ee:dd:cc:bb:aa")`. Each TLV is converted to its wire format and decoded again, so parsed
+/// TLVs are checked just like received ones.
+///
+/// Strings are not escaped in the printable representation. A quote followed by `,` or `)` is therefore taken as
+/// the end of the string.
+pub(crate) struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Constructor
+    pub(crate) fn new(input: &'a str) -> Parser<'a> {
+        Parser { input, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn error(&self) -> LldpError {
+        LldpError::InvalidSyntax(self.position)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Check that the whole input has been consumed
+    pub(crate) fn end(&mut self) -> Result<(), LldpError> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Consume `token` if the input continues with it
+    pub(crate) fn accept(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume `token`
+    pub(crate) fn expect(&mut self, token: &str) -> Result<(), LldpError> {
+        if self.accept(token) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Consume a name, e.g. `TtlTLV`
+    pub(crate) fn name(&mut self) -> Result<&'a str, LldpError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error());
+        }
+
+        self.position += len;
+        Ok(&rest[..len])
+    }
+
+    /// Consume a decimal number
+    fn number<T: FromStr>(&mut self) -> Result<T, LldpError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let number = rest[..len].parse().map_err(|_| self.error())?;
+
+        self.position += len;
+        Ok(number)
+    }
+
+    /// Consume a quoted string
+    fn string(&mut self) -> Result<&'a str, LldpError> {
+        self.expect("\"")?;
+        let rest = self.rest();
+        for (index, _) in rest.match_indices('"') {
+            let after = rest[index + 1..].trim_start();
+            if after.is_empty() || after.starts_with(',') || after.starts_with(')') {
+                self.position += index + 1;
+                return Ok(&rest[..index]);
+            }
+        }
+
+        Err(self.error())
+    }
+
+    /// Consume a quoted string of hex digits, two per byte
+    fn hex(&mut self) -> Result<Vec<u8>, LldpError> {
+        let position = self.position;
+        decode_hex(self.string()?).ok_or(LldpError::InvalidSyntax(position))
+    }
+
+    /// Consume a quoted network address and return it prefixed with its address family
+    ///
+    /// IP addresses are given in their usual notation, addresses of other families as `family:HEX`.
+    fn address(&mut self) -> Result<Vec<u8>, LldpError> {
+        let position = self.position;
+        let address = self.string()?;
+
+        let bytes = match address.parse::<IpAddr>() {
+            Ok(IpAddr::V4(address)) => Some([&[1], address.octets().as_ref()].concat()),
+            Ok(IpAddr::V6(address)) => Some([&[2], address.octets().as_ref()].concat()),
+            Err(_) => address.split_once(':').and_then(|(family, bytes)| {
+                let family: u8 = family.parse().ok()?;
+                Some([vec![family], decode_hex(bytes)?].concat())
+            }),
+        };
+
+        bytes.ok_or(LldpError::InvalidSyntax(position))
+    }
+
+    /// Consume the ID of a Chassis ID or Port ID TLV
+    ///
+    /// MAC addresses are given in any notation accepted by [MacAddress] or [Eui64], network addresses as IP addresses.
+    fn id(&mut self, tlv_type: TlvType) -> Result<Vec<u8>, LldpError> {
+        let subtype: u8 = self.number()?;
+        self.expect(",")?;
+
+        let (mac, network_address) = match tlv_type {
+            TlvType::ChassisId => (4, 5),
+            _ => (3, 4),
+        };
+
+        let position = self.position;
+        let id = match subtype {
+            _ if subtype == mac => {
+                let mac = self.string()?;
+                match (mac.parse::<MacAddress>(), mac.parse::<Eui64>()) {
+                    (Ok(mac), _) => mac.octets().to_vec(),
+                    (_, Ok(eui)) => eui.octets().to_vec(),
+                    _ => return Err(LldpError::InvalidSyntax(position)),
+                }
+            }
+            _ if subtype == network_address => self.address()?,
+            _ => self.string()?.as_bytes().to_vec(),
+        };
+
+        Ok([&[subtype], id.as_slice()].concat())
+    }
+
+    /// Consume the arguments of a TLV and return the TLV value in wire format
+    fn value(&mut self, tlv_type: TlvType) -> Result<Vec<u8>, LldpError> {
+        let value = match tlv_type {
+            TlvType::ChassisId | TlvType::PortId => self.id(tlv_type)?,
+            TlvType::Ttl => self.number::<u16>()?.to_be_bytes().to_vec(),
+            TlvType::PortDescription | TlvType::SystemName | TlvType::SystemDescription => {
+                self.string()?.as_bytes().to_vec()
+            }
+            TlvType::SystemCapabilities => {
+                let supported: u16 = self.number()?;
+                self.expect(",")?;
+                let enabled: u16 = self.number()?;
+                [supported.to_be_bytes(), enabled.to_be_bytes()].concat()
+            }
+            TlvType::ManagementAddress => {
+                // The interface numbering subtype is not part of the printable representation
+                let address = self.address()?;
+                self.expect(",")?;
+                let interface_number: u32 = self.number()?;
+                self.expect(",")?;
+                let oid = self.hex()?;
+                // The length fields would wrap, the address follows its one byte subtype
+                if address.len() > 1 + managementaddress_tlv::MAX_ADDRESS_LEN
+                    || oid.len() > managementaddress_tlv::MAX_OID_LEN
+                {
+                    return Err(LldpError::BadLength {
+                        tlv_type,
+                        length: address.len() + oid.len() + 6,
+                    });
+                }
+                let mut value = vec![address.len() as u8];
+                value.extend_from_slice(&address);
+                value.push(2);
+                value.extend_from_slice(&interface_number.to_be_bytes());
+                value.push(oid.len() as u8);
+                value.extend_from_slice(&oid);
+                value
+            }
+            TlvType::OrganizationallySpecific => {
+                let position = self.position;
+                let oui = self.hex()?;
+                if oui.len() != 3 {
+                    return Err(LldpError::InvalidSyntax(position));
+                }
+                self.expect(",")?;
+                let subtype: u8 = self.number()?;
+                self.expect(",")?;
+                [oui, vec![subtype], self.hex()?].concat()
+            }
+            TlvType::EndOfLLDPDU | TlvType::Unknown => vec![],
+        };
+
+        Ok(value)
+    }
+
+    /// Consume a TLV
+    pub(crate) fn tlv(&mut self) -> Result<Tlv, LldpError> {
+        self.skip_whitespace();
+        let position = self.position;
+
+        let (type_code, value) = match self.name()? {
+            "EndOfLLDPDUTLV" => (TlvType::EndOfLLDPDU as u8, vec![]),
+            "UnknownTLV" => {
+                self.expect("(")?;
+                let type_code: u8 = self.number()?;
+                self.expect(",")?;
+                let value = self.hex()?;
+                self.expect(")")?;
+                crate::tlv::check_unknown(type_code, &value)?;
+                (type_code, value)
+            }
+            name => {
+                let tlv_type = match name {
+                    "ChassisIdTLV" => TlvType::ChassisId,
+                    "PortIdTLV" => TlvType::PortId,
+                    "TtlTLV" => TlvType::Ttl,
+                    "PortDescriptionTLV" => TlvType::PortDescription,
+                    "SystemNameTLV" => TlvType::SystemName,
+                    "SystemDescriptionTLV" => TlvType::SystemDescription,
+                    "SystemCapabilitiesTLV" => TlvType::SystemCapabilities,
+                    "ManagementAddressTLV" => TlvType::ManagementAddress,
+                    "OrganizationallySpecificTLV" => TlvType::OrganizationallySpecific,
+                    _ => return Err(LldpError::InvalidSyntax(position)),
+                };
+                self.expect("(")?;
+                let value = self.value(tlv_type)?;
+                self.expect(")")?;
+                (tlv_type as u8, value)
+            }
+        };
+
+        if value.len() > TLV_MAX_VALUE_LEN {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::try_from(type_code).unwrap_or(TlvType::Unknown),
+                length: value.len(),
+            });
+        }
+
+        let mut bytes = TlvHeader::new(type_code, value.len()).bytes().to_vec();
+        bytes.extend_from_slice(&value);
+        Tlv::try_from_bytes(&bytes)
+    }
+}
+
+/// Decode a string of hex digits, two per byte
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+impl FromStr for Tlv {
+    type Err = LldpError;
+
+    /// Parse the printable representation of a TLV, e.g. `TtlTLV(120)`.
+    ///
+    /// Returns an error if the string is not a valid representation or the TLV contains errors. Management Address
+    /// TLVs are parsed with the interface numbering subtype [IfIndex], as it is not part of the representation.
+    ///
+    /// [IfIndex]: crate::tlv::managementaddress_tlv::IFNumberingSubtype::IfIndex
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let tlv = parser.tlv()?;
+        parser.end()?;
+
+        Ok(tlv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(s: &str) -> Tlv {
+        let tlv: Tlv = s.parse().unwrap();
+        assert_eq!(tlv.to_string(), s);
+        tlv
+    }
+
+    #[test]
+    fn test_parse() {
+        roundtrip("ChassisIdTLV(4, \"ff:ee:dd:cc:bb:aa\")");
+        roundtrip("ChassisIdTLV(4, \"02:04:df:ff:fe:88:a2:b4\")");
+        roundtrip("ChassisIdTLV(5, \"2001:db8::1\")");
+        roundtrip("ChassisIdTLV(7, \"Pablo's Computer\")");
+        roundtrip("PortIdTLV(3, \"02:04:df:88:a2:b4\")");
+        roundtrip("PortIdTLV(4, \"16:6C6C64702E6578616D706C65\")");
+        roundtrip("PortIdTLV(7, \"Bla bla bla, Mr. Freeman\")");
+        roundtrip("TtlTLV(120)");
+        roundtrip("PortDescriptionTLV(\"Unittest\")");
+        roundtrip("SystemNameTLV(\"\")");
+        roundtrip("SystemDescriptionTLV(\"Say \"Hello\"\")");
+        roundtrip("SystemCapabilitiesTLV(92, 84)");
+        roundtrip("ManagementAddressTLV(\"192.0.2.100\", 5, \"2B0601040182371514\")");
+        roundtrip("ManagementAddressTLV(\"6:0204DF88A2B4\", 1, \"\")");
+        roundtrip("OrganizationallySpecificTLV(\"0080C2\", 1, \"002A\")");
+        roundtrip("UnknownTLV(42, \"BEEF\")");
+        roundtrip(&format!(
+            "ManagementAddressTLV(\"6:{}\", 1, \"\")",
+            "AB".repeat(31)
+        ));
+        assert_eq!(
+            format!("ManagementAddressTLV(\"6:{}\", 1, \"\")", "AB".repeat(300))
+                .parse::<Tlv>()
+                .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::ManagementAddress,
+                length: 307
+            }
+        );
+        assert!(
+            format!("ManagementAddressTLV(\"6:{}\", 1, \"\")", "AB".repeat(32))
+                .parse::<Tlv>()
+                .is_err()
+        );
+        assert!(format!(
+            "ManagementAddressTLV(\"192.0.2.1\", 1, \"{}\")",
+            "01".repeat(129)
+        )
+        .parse::<Tlv>()
+        .is_err());
+        assert!("UnknownTLV(1, \"07\")".parse::<Tlv>().is_err());
+        assert!("UnknownTLV(200, \"\")".parse::<Tlv>().is_err());
+        roundtrip("EndOfLLDPDUTLV");
+
+        let tlv: Tlv = "  TtlTLV( 120 ) ".parse().unwrap();
+        assert_eq!(tlv.bytes(), b"\x06\x02\x00\x78");
+
+        let tlv: Tlv = "ChassisIdTLV(4, \"2:4:DF:88:A2:B4\")".parse().unwrap();
+        assert_eq!(tlv.bytes(), b"\x02\x07\x04\x02\x04\xdf\x88\xa2\xb4");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            "TtlTLV(120".parse::<Tlv>().unwrap_err(),
+            LldpError::InvalidSyntax(10)
+        );
+        assert_eq!(
+            "TtlTLV(70000)".parse::<Tlv>().unwrap_err(),
+            LldpError::InvalidSyntax(7)
+        );
+        assert_eq!(
+            "HalTLV(9000)".parse::<Tlv>().unwrap_err(),
+            LldpError::InvalidSyntax(0)
+        );
+        assert_eq!(
+            "TtlTLV(120) TtlTLV(120)".parse::<Tlv>().unwrap_err(),
+            LldpError::InvalidSyntax(12)
+        );
+        assert_eq!(
+            "ChassisIdTLV(4, \"FF:EE:DD:CC:BB:AAA\")"
+                .parse::<Tlv>()
+                .unwrap_err(),
+            LldpError::InvalidSyntax(15)
+        );
+        assert_eq!(
+            "ChassisIdTLV(8, \"chair\")".parse::<Tlv>().unwrap_err(),
+            LldpError::UnknownSubtype {
+                tlv_type: TlvType::ChassisId,
+                subtype: 8
+            }
+        );
+        assert_eq!(
+            "SystemCapabilitiesTLV(4, 20)".parse::<Tlv>().unwrap_err(),
+            LldpError::CapabilityMismatch {
+                supported: 4,
+                enabled: 20
+            }
+        );
+    }
+}
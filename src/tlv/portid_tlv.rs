@@ -1,11 +1,14 @@
-use crate::tlv::TlvType;
+use crate::error::LldpError;
+use crate::tlv::address_family::AddressFamily;
+use crate::tlv::mac_address::{Eui64, MacAddress};
+use crate::tlv::{check_len, TlvCodec, TlvType, MAX_STRING_LEN};
 use bytes::BufMut;
-
 use std::convert::{TryFrom, TryInto};
 use std::fmt::Display;
 use std::net::IpAddr;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PortIdSubtype {
     InterfaceAlias = 1,
     PortComponent = 2,
@@ -34,9 +37,18 @@ impl TryFrom<u8> for PortIdSubtype {
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PortIdValue {
-    Mac(Vec<u8>),
+    Mac(MacAddress),
+    /// EUI-64 transmitted with the MAC address subtype
+    Eui64(Eui64),
     IpAddress(IpAddr),
+    /// Network address of any family other than IPv4 and IPv6, see [AddressFamily]
+    NetworkAddress {
+        family: u8,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::hex"))]
+        bytes: Vec<u8>,
+    },
     Other(String),
 }
 
@@ -49,6 +61,7 @@ pub enum PortIdValue {
 ///
 /// # TLV Format:
 ///
+/// ```text
 ///         0               1               2               3
 ///        +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
 ///        |             |                 |               |               |
@@ -57,6 +70,7 @@ pub enum PortIdValue {
 ///        +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
 ///
 ///                                                            1 - 255 byte
+/// ```
 ///
 /// # Subtypes
 ///
@@ -93,32 +107,46 @@ pub enum PortIdValue {
 /// To determine the type of network protocol and the appropriate length of the network address transmitted in the
 /// port ID TLV, network addresses are prefixed with an extra byte identifying the address family.
 ///
-/// For this implementation we only consider IPv4 and IPv6.
+/// IPv4 and IPv6 addresses are represented as [IpAddr], addresses of any other family as raw bytes together with
+/// their family number. The common family numbers are named by [AddressFamily].
 ///
 /// | Protocol | Family Number |
 /// | -------- | ------------- |
 /// |   IPv4   |             1 |
 /// |   IPv6   |             2 |
+/// |   802    |             6 |
+/// |   DNS    |            16 |
 ///
+/// ```text
 ///     Examples (Address -> Bytes -> Prefixed Bytes):
 ///         134.96.86.110  ->  b"\x86\x60\x56\x6E"  -> b"\x01\x86\x60\x56\x6E"
 ///
 ///         20db::1        ->  b"\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
 ///                        ->  b"\x02\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
+/// ```
 ///
 /// The full list of registered protocol families is available at:
 /// <https://www.iana.org/assignments/address-family-numbers/address-family-numbers.xhtml>
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "crate::serialization::PortIdFields")
+)]
 pub struct PortIdTLV {
     /// The type of the TLV
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "crate::serialization::tlv_type::<PortIdTLV>")
+    )]
     pub tlv_type: TlvType,
     /// The port ID subtype
     pub subtype: PortIdSubtype,
     /// The port ID.
     ///
     /// The type of the value field depends on the subtype
-    /// * Mac Address -> `PortIdValue::Mac(Vec<u8>)`,
-    /// * Network Address -> `PortIdValue::IpAddress(IpAddr)`,
+    /// * Mac Address -> `PortIdValue::Mac(MacAddress)` or `PortIdValue::Eui64(Eui64)`,
+    /// * Network Address -> `PortIdValue::IpAddress(IpAddr)` or `PortIdValue::NetworkAddress`,
     /// * Otherwise -> `PortIdValue::Other(String)`
     pub value: PortIdValue,
 }
@@ -127,18 +155,17 @@ impl Display for PortIdTLV {
     /// Write a printable representation of the TLV object.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let value = match &self.value {
-            PortIdValue::Mac(mac) => {
-                let mut result = String::new();
-                for (index, i) in mac.iter().enumerate() {
-                    result.push_str(&format!("{:X}", i));
-                    if index != mac.len() - 1 {
-                        result.push_str(&":");
-                    }
+            PortIdValue::Mac(mac) => mac.to_string(),
+            PortIdValue::Eui64(eui) => eui.to_string(),
+            PortIdValue::Other(s) => s.clone(),
+            PortIdValue::IpAddress(addr) => addr.to_string(),
+            PortIdValue::NetworkAddress { family, bytes } => {
+                let mut result = format!("{}:", family);
+                for i in bytes {
+                    result.push_str(&format!("{:02X}", i));
                 }
                 result
             }
-            PortIdValue::Other(s) => s.clone(),
-            PortIdValue::IpAddress(addr) => addr.to_string(),
         };
 
         write!(f, "PortIdTLV({}, \"{}\")", self.subtype as u8, value)
@@ -149,112 +176,144 @@ impl PortIdTLV {
     /// Constructor
     ///
     /// The type of the id argument depends on the subtype
-    /// * Mac Address -> `PortIdValue::Mac(Vec<u8>)`,
-    /// * Network Address -> `PortIdValue::IpAddress(IpAddr)`,
+    /// * Mac Address -> `PortIdValue::Mac(MacAddress)` or `PortIdValue::Eui64(Eui64)`,
+    /// * Network Address -> `PortIdValue::IpAddress(IpAddr)` or `PortIdValue::NetworkAddress`,
     /// * Otherwise -> `PortIdValue::Other(String)`
     pub fn new(subtype: PortIdSubtype, id: PortIdValue) -> PortIdTLV {
         // TODO: Implement
         PortIdTLV {
             tlv_type: TlvType::PortId,
-            subtype: subtype,
+            subtype,
             value: id,
         }
     }
 
-    /// Create a TLV instance from raw bytes.
+    /// Create a Port ID TLV, checking the ID.
     ///
-    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
-    pub fn new_from_bytes(bytes: &[u8]) -> PortIdTLV {
-        let mut type_field = bytes[0] & 0b11111110;
-        type_field = type_field >> 1;
-
-        if type_field != TlvType::PortId as u8 {
-            panic!("Wrong TLV Type for ChassisId_Tlv");
-        }
-
-        let mut length = bytes[1] as usize;
-        if bytes[0] & 1 == 1 {
-            length += 1 << 9;
-        }
-
-        let subtype = match PortIdSubtype::try_from(bytes[2]) {
-            Ok(val) => val,
-            Err(_) => panic!("invalid subtype field"),
-        };
+    /// Returns an error if the ID is empty or longer than 255 bytes, is of a different kind than the subtype requires
+    /// (see [PortIdTLV::new]) or cannot be decoded again, e.g. a network address of an unknown family.
+    pub fn try_new(subtype: PortIdSubtype, id: PortIdValue) -> Result<PortIdTLV, LldpError> {
+        let tlv = check_len(PortIdTLV::new(subtype, id), 2..=MAX_STRING_LEN + 1)?;
 
-        let id = match subtype {
+        let matches = match tlv.subtype {
             PortIdSubtype::MacAddress => {
-                assert_eq!(6, bytes[3..].len());
-                PortIdValue::Mac(bytes[3..].to_vec())
+                matches!(tlv.value, PortIdValue::Mac(_) | PortIdValue::Eui64(_))
             }
-            PortIdSubtype::NetworkAddress => match bytes[3] {
-                1u8 => {
-                    assert_eq!(4, bytes[4..].len());
-                    let addr: [u8; 4] = bytes[4..].try_into().unwrap();
-                    PortIdValue::IpAddress(IpAddr::from(addr))
-                }
-                2u8 => {
-                    assert_eq!(16, bytes[4..].len());
-                    let addr: [u8; 16] = bytes[4..].try_into().unwrap();
-                    PortIdValue::IpAddress(IpAddr::from(addr))
-                }
-                _ => panic!("Expected IP Address specifier"),
-            },
-            _ => match String::from_utf8(bytes[3..].to_vec()) {
-                Ok(value) => PortIdValue::Other(value),
-                Err(_) => panic!("Invlaid value for PortId::Other type "),
-            },
+            PortIdSubtype::NetworkAddress => matches!(
+                tlv.value,
+                PortIdValue::IpAddress(_) | PortIdValue::NetworkAddress { .. }
+            ),
+            _ => matches!(tlv.value, PortIdValue::Other(_)),
         };
+        if !matches {
+            return Err(LldpError::SubtypeMismatch {
+                tlv_type: TlvType::PortId,
+                subtype: tlv.subtype as u8,
+            });
+        }
 
-        PortIdTLV::new(subtype, id)
+        PortIdTLV::try_from_bytes(&tlv.bytes())?;
+        Ok(tlv)
     }
+}
+
+impl TlvCodec for PortIdTLV {
+    const TLV_TYPE: TlvType = TlvType::PortId;
 
-    /// Return the length of the TLV value
-    pub fn len(&self) -> usize {
+    fn len(&self) -> usize {
         1 + match &self.value {
             PortIdValue::Mac(_) => 6,
+            PortIdValue::Eui64(_) => 8,
             PortIdValue::Other(s) => s.len(),
             PortIdValue::IpAddress(IpAddr::V4(_)) => 4 + 1,
             PortIdValue::IpAddress(IpAddr::V6(_)) => 16 + 1,
+            PortIdValue::NetworkAddress { bytes, .. } => 1 + bytes.len(),
         }
     }
 
-    /// Return the byte representation of the TLV.
-    pub fn bytes(&self) -> Vec<u8> {
-        let mut type_field = (self.tlv_type as u8) << 1;
+    fn encode_value(&self, buf: &mut impl BufMut) {
+        buf.put_u8(self.subtype as u8);
 
-        let length_field = self.len();
-        if length_field & (1 << 9) == 1 {
-            type_field |= 1;
+        match &self.value {
+            PortIdValue::Mac(mac) => buf.put_slice(&mac.octets()),
+            PortIdValue::Eui64(eui) => buf.put_slice(&eui.octets()),
+            PortIdValue::Other(value) => buf.put_slice(value.as_bytes()),
+            PortIdValue::IpAddress(IpAddr::V4(address)) => {
+                buf.put_u8(1);
+                buf.put_slice(&address.octets());
+            }
+            PortIdValue::IpAddress(IpAddr::V6(address)) => {
+                buf.put_u8(2);
+                buf.put_slice(&address.octets());
+            }
+            PortIdValue::NetworkAddress { family, bytes } => {
+                buf.put_u8(*family);
+                buf.put_slice(bytes);
+            }
         }
+    }
 
-        let length_field = length_field as u8;
-
-        let mut result: Vec<u8> = Vec::new();
-        result.push(type_field);
-        result.push(length_field);
+    fn decode_value(value: &[u8]) -> Result<PortIdTLV, LldpError> {
+        let length = value.len();
 
-        let subtype_field = self.subtype.clone() as u8;
-        result.push(subtype_field);
+        if length < 2 {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::PortId,
+                length,
+            });
+        }
 
-        let value_field = match &self.value {
-            PortIdValue::Mac(addr) => addr.clone(),
-            PortIdValue::Other(value) => value.as_bytes().to_vec(),
-            PortIdValue::IpAddress(IpAddr::V4(address)) => address.octets().to_vec(),
-            PortIdValue::IpAddress(IpAddr::V6(address)) => address.octets().to_vec(),
+        let subtype = match PortIdSubtype::try_from(value[0]) {
+            Ok(subtype) => subtype,
+            Err(_) => {
+                return Err(LldpError::UnknownSubtype {
+                    tlv_type: TlvType::PortId,
+                    subtype: value[0],
+                })
+            }
         };
 
-        if let PortIdValue::IpAddress(IpAddr::V4(_)) = self.value {
-            result.push(1);
-        }
-
-        if let PortIdValue::IpAddress(IpAddr::V6(_)) = self.value {
-            result.push(2);
-        }
+        let id = &value[1..];
+        let bad_length = LldpError::BadLength {
+            tlv_type: TlvType::PortId,
+            length,
+        };
 
-        result.extend_from_slice(&value_field);
+        let value = match subtype {
+            PortIdSubtype::MacAddress => {
+                if let Ok(mac) = <[u8; 6]>::try_from(id) {
+                    PortIdValue::Mac(MacAddress::new(mac))
+                } else if let Ok(eui) = <[u8; 8]>::try_from(id) {
+                    PortIdValue::Eui64(Eui64::new(eui))
+                } else {
+                    return Err(bad_length);
+                }
+            }
+            PortIdSubtype::NetworkAddress => match id[0] {
+                1u8 => {
+                    let addr: [u8; 4] = id[1..].try_into().map_err(|_| bad_length)?;
+                    PortIdValue::IpAddress(IpAddr::from(addr))
+                }
+                2u8 => {
+                    let addr: [u8; 16] = id[1..].try_into().map_err(|_| bad_length)?;
+                    PortIdValue::IpAddress(IpAddr::from(addr))
+                }
+                family if !AddressFamily::is_valid(family) => {
+                    return Err(LldpError::UnknownAddressFamily(family))
+                }
+                _ if id.len() < 2 => return Err(bad_length),
+                family => PortIdValue::NetworkAddress {
+                    family,
+                    bytes: id[1..].to_vec(),
+                },
+            },
+            _ => match String::from_utf8(id.to_vec()) {
+                Ok(value) => PortIdValue::Other(value),
+                Err(_) => return Err(LldpError::InvalidUtf8(TlvType::PortId)),
+            },
+        };
 
-        result
+        Ok(PortIdTLV::new(subtype, value))
     }
 }
 
@@ -419,6 +478,41 @@ mod tests {
         PortIdTLV::new_from_bytes(b"\x04\x06\x04\x02\xC0\x02\x00\x01".as_ref());
     }
 
+    #[test]
+    fn test_load_network_address() {
+        let tlv = PortIdTLV::new_from_bytes(b"\x04\x09\x04\x10lldp.rs".as_ref());
+        assert_eq!(
+            tlv.value,
+            PortIdValue::NetworkAddress {
+                family: AddressFamily::Dns as u8,
+                bytes: b"lldp.rs".to_vec()
+            }
+        );
+        assert_eq!(tlv.bytes(), b"\x04\x09\x04\x10lldp.rs".to_vec());
+    }
+
+    #[test]
+    fn test_try_load_invalid() {
+        assert_eq!(
+            PortIdTLV::try_from_bytes(b"\x04\x02\x08X".as_ref()).unwrap_err(),
+            LldpError::UnknownSubtype {
+                tlv_type: TlvType::PortId,
+                subtype: 8
+            }
+        );
+        assert_eq!(
+            PortIdTLV::try_from_bytes(b"\x04\x03\x04\x00\x00".as_ref()).unwrap_err(),
+            LldpError::UnknownAddressFamily(0)
+        );
+        assert_eq!(
+            PortIdTLV::try_from_bytes(b"\x04\x0C\x07Abra".as_ref()).unwrap_err(),
+            LldpError::Truncated {
+                needed: 14,
+                available: 7
+            }
+        );
+    }
+
     #[test]
     fn test_display1() {
         let tlv = PortIdTLV::new(
@@ -436,7 +530,7 @@ mod tests {
     fn test_chassisid_display2() {
         let tlv = PortIdTLV::new(
             PortIdSubtype::MacAddress,
-            PortIdValue::Mac(vec![0x66, 0x6F, 0x6F, 0x62, 0x61, 0x72]),
+            PortIdValue::Mac(MacAddress::new([0x66, 0x6F, 0x6F, 0x62, 0x61, 0x72])),
         );
 
         assert_eq!(
@@ -454,4 +548,50 @@ mod tests {
 
         assert_eq!(format!("{}", tlv), "PortIdTLV(4, \"127.0.0.1\")")
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(PortIdTLV::try_new(
+            PortIdSubtype::InterfaceName,
+            PortIdValue::Other(String::from("eth0"))
+        )
+        .is_ok());
+        assert_eq!(
+            PortIdTLV::try_new(PortIdSubtype::Local, PortIdValue::Other("x".repeat(256)))
+                .unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::PortId,
+                length: 257
+            }
+        );
+
+        // The ID must be of the kind required by the subtype
+        assert_eq!(
+            PortIdTLV::try_new(
+                PortIdSubtype::MacAddress,
+                PortIdValue::Other(String::from("abcdef"))
+            )
+            .unwrap_err(),
+            LldpError::SubtypeMismatch {
+                tlv_type: TlvType::PortId,
+                subtype: 3
+            }
+        );
+        assert_eq!(
+            PortIdTLV::try_new(
+                PortIdSubtype::NetworkAddress,
+                PortIdValue::Other(String::from("eth0"))
+            )
+            .unwrap_err(),
+            LldpError::SubtypeMismatch {
+                tlv_type: TlvType::PortId,
+                subtype: 4
+            }
+        );
+        assert!(PortIdTLV::try_new(
+            PortIdSubtype::MacAddress,
+            PortIdValue::Mac(MacAddress::new([0x02, 0, 0, 0, 0, 1]))
+        )
+        .is_ok());
+    }
 }
@@ -1,15 +1,26 @@
-use crate::tlv::TlvType;
-use std::{convert::TryFrom, fmt::Display};
+use crate::error::LldpError;
+use crate::tlv::{TlvCodec, TlvType};
+use bytes::BufMut;
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::iter::FromIterator;
+use std::ops::{BitOr, BitOrAssign};
+use std::str::FromStr;
 
 /// Capability bit values
 ///
-/// This enum can be used to construct a capability bitmap in a descriptive way.
-///
-/// To create a capability bitmap the enum values can be ORed with each other, e.g. for a WLAN router the
+/// The enum values can be ORed with each other to construct a [CapabilitySet], e.g. for a WLAN router the
 /// capabilities might look like this:
 ///
-///     caps = Capability.WLAN_AP | Capability.ROUTER
-#[derive(Debug, Clone)]
+/// ```
+/// use lldp_rs::tlv::systemcapabilities_tlv::SystemCapability;
+///
+/// let caps = SystemCapability::WlanAP | SystemCapability::Router;
+/// assert_eq!(caps.bits(), 0x0018);
+/// assert_eq!(caps.to_string(), "WLAN Access Point, Router");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SystemCapability {
     Other = 1,
     Repeater = 2,
@@ -24,30 +35,237 @@ pub enum SystemCapability {
     TwoPortMacRelay = 1024,
 }
 
+impl SystemCapability {
+    /// All defined capabilities in the order of their bits
+    pub const ALL: [SystemCapability; 11] = [
+        SystemCapability::Other,
+        SystemCapability::Repeater,
+        SystemCapability::Bridge,
+        SystemCapability::WlanAP,
+        SystemCapability::Router,
+        SystemCapability::Telephone,
+        SystemCapability::DocsisDevice,
+        SystemCapability::StationOnly,
+        SystemCapability::CVlanComponent,
+        SystemCapability::SVlanComponent,
+        SystemCapability::TwoPortMacRelay,
+    ];
+
+    /// Get the human readable name of the capability as used by IEEE 802.1AB
+    pub fn name(&self) -> &'static str {
+        match self {
+            SystemCapability::Other => "Other",
+            SystemCapability::Repeater => "Repeater",
+            SystemCapability::Bridge => "Bridge",
+            SystemCapability::WlanAP => "WLAN Access Point",
+            SystemCapability::Router => "Router",
+            SystemCapability::Telephone => "Telephone",
+            SystemCapability::DocsisDevice => "DOCSIS Cable Device",
+            SystemCapability::StationOnly => "Station Only",
+            SystemCapability::CVlanComponent => "C-VLAN Component",
+            SystemCapability::SVlanComponent => "S-VLAN Component",
+            SystemCapability::TwoPortMacRelay => "Two-Port MAC Relay",
+        }
+    }
+}
+
+impl Display for SystemCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for SystemCapability {
+    type Err = LldpError;
+
+    /// Parse a capability from its name.
+    ///
+    /// Case, spaces and dashes are ignored and the variant names are accepted as well, i.e. `WLAN Access Point`,
+    /// `wlan-access-point` and `WlanAP` all name [SystemCapability::WlanAP].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalize = |name: &str| -> String {
+            name.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect()
+        };
+        let name = normalize(s);
+
+        SystemCapability::ALL
+            .iter()
+            .find(|capability| {
+                normalize(capability.name()) == name
+                    || normalize(&format!("{:?}", capability)) == name
+            })
+            .copied()
+            .ok_or_else(|| LldpError::UnknownCapability(s.to_string()))
+    }
+}
+
 impl TryFrom<u16> for SystemCapability {
     type Error = ();
 
     fn try_from(v: u16) -> Result<Self, Self::Error> {
-        match v {
-            x if x == SystemCapability::Other as u16 => Ok(SystemCapability::Other),
-            x if x == SystemCapability::Repeater as u16 => Ok(SystemCapability::Repeater),
-            x if x == SystemCapability::Bridge as u16 => Ok(SystemCapability::Bridge),
-            x if x == SystemCapability::WlanAP as u16 => Ok(SystemCapability::WlanAP),
-            x if x == SystemCapability::Router as u16 => Ok(SystemCapability::Router),
-            x if x == SystemCapability::Telephone as u16 => Ok(SystemCapability::Telephone),
-            x if x == SystemCapability::DocsisDevice as u16 => Ok(SystemCapability::DocsisDevice),
-            x if x == SystemCapability::StationOnly as u16 => Ok(SystemCapability::StationOnly),
-            x if x == SystemCapability::CVlanComponent as u16 => {
-                Ok(SystemCapability::CVlanComponent)
-            }
-            x if x == SystemCapability::SVlanComponent as u16 => {
-                Ok(SystemCapability::SVlanComponent)
-            }
-            x if x == SystemCapability::TwoPortMacRelay as u16 => {
-                Ok(SystemCapability::TwoPortMacRelay)
+        SystemCapability::ALL
+            .iter()
+            .find(|&&capability| capability as u16 == v)
+            .copied()
+            .ok_or(())
+    }
+}
+
+impl BitOr for SystemCapability {
+    type Output = CapabilitySet;
+
+    fn bitor(self, rhs: SystemCapability) -> CapabilitySet {
+        CapabilitySet::from(self) | rhs
+    }
+}
+
+/// Set of capabilities
+///
+/// A capability set wraps a capability bitmap as carried in the System Capabilities TLV. Sets are built by ORing
+/// [SystemCapability] values or other sets, and iterate over the capabilities they contain. Reserved bits are kept
+/// in [CapabilitySet::bits] but are not part of the iteration.
+///
+/// The printable representation lists the capability names, e.g. `Bridge, Router`, and can be parsed again:
+///
+/// ```
+/// use lldp_rs::tlv::systemcapabilities_tlv::{CapabilitySet, SystemCapability};
+///
+/// let caps: CapabilitySet = "Bridge, Router".parse().unwrap();
+/// assert_eq!(caps, SystemCapability::Bridge | SystemCapability::Router);
+/// assert!(caps.contains(SystemCapability::Router));
+/// assert_eq!(caps.iter().collect::<Vec<_>>(), [SystemCapability::Bridge, SystemCapability::Router]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CapabilitySet(u16);
+
+impl CapabilitySet {
+    /// Create an empty set
+    pub fn new() -> CapabilitySet {
+        CapabilitySet(0)
+    }
+
+    /// Create a set from a capability bitmap
+    pub fn from_bits(bits: u16) -> CapabilitySet {
+        CapabilitySet(bits)
+    }
+
+    /// Get the capability bitmap of the set
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Check if the set contains no capabilities and no reserved bits
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Check if the set contains all of the given capabilities
+    pub fn contains(&self, capabilities: impl Into<CapabilitySet>) -> bool {
+        let capabilities = capabilities.into();
+        self.0 & capabilities.0 == capabilities.0
+    }
+
+    /// Add a capability to the set
+    pub fn insert(&mut self, capability: SystemCapability) {
+        self.0 |= capability as u16;
+    }
+
+    /// Remove a capability from the set
+    pub fn remove(&mut self, capability: SystemCapability) {
+        self.0 &= !(capability as u16);
+    }
+
+    /// Get the reserved bits set in the bitmap
+    pub fn reserved(&self) -> u16 {
+        let defined = SystemCapability::ALL
+            .iter()
+            .fold(0, |bits, &capability| bits | capability as u16);
+        self.0 & !defined
+    }
+
+    /// Iterate over the capabilities in the set, in the order of their bits
+    pub fn iter(&self) -> impl Iterator<Item = SystemCapability> {
+        let bits = self.0;
+        SystemCapability::ALL
+            .iter()
+            .copied()
+            .filter(move |&capability| bits & capability as u16 != 0)
+    }
+}
+
+impl From<SystemCapability> for CapabilitySet {
+    fn from(capability: SystemCapability) -> Self {
+        CapabilitySet(capability as u16)
+    }
+}
+
+impl From<u16> for CapabilitySet {
+    fn from(bits: u16) -> Self {
+        CapabilitySet(bits)
+    }
+}
+
+impl From<CapabilitySet> for u16 {
+    fn from(capabilities: CapabilitySet) -> Self {
+        capabilities.0
+    }
+}
+
+impl<T: Into<CapabilitySet>> BitOr<T> for CapabilitySet {
+    type Output = CapabilitySet;
+
+    fn bitor(self, rhs: T) -> CapabilitySet {
+        CapabilitySet(self.0 | rhs.into().0)
+    }
+}
+
+impl<T: Into<CapabilitySet>> BitOrAssign<T> for CapabilitySet {
+    fn bitor_assign(&mut self, rhs: T) {
+        self.0 |= rhs.into().0;
+    }
+}
+
+impl FromIterator<SystemCapability> for CapabilitySet {
+    fn from_iter<I: IntoIterator<Item = SystemCapability>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(CapabilitySet::new(), |set, capability| set | capability)
+    }
+}
+
+impl Display for CapabilitySet {
+    /// Write the comma separated names of the capabilities, reserved bits are written as a hex bitmap
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names: Vec<String> = self
+            .iter()
+            .map(|capability| capability.to_string())
+            .collect();
+        if self.reserved() != 0 {
+            names.push(format!("{:#06x}", self.reserved()));
+        }
+        write!(f, "{}", names.join(", "))
+    }
+}
+
+impl FromStr for CapabilitySet {
+    type Err = LldpError;
+
+    /// Parse a comma separated list of capability names or hex bitmaps, see [SystemCapability::from_str].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = CapabilitySet::new();
+        for name in s.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            match name.strip_prefix("0x") {
+                Some(hex) => {
+                    set |= u16::from_str_radix(hex, 16)
+                        .map_err(|_| LldpError::UnknownCapability(name.to_string()))?
+                }
+                None => set |= name.parse::<SystemCapability>()?,
             }
-            _ => Err(()),
         }
+
+        Ok(set)
     }
 }
 
@@ -61,6 +279,7 @@ impl TryFrom<u16> for SystemCapability {
 ///
 /// # TLV Format:
 ///
+/// ```text
 ///         0                   1                   2                   3                   4
 ///         0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7
 ///        +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
@@ -73,6 +292,7 @@ impl TryFrom<u16> for SystemCapability {
 ///                                        |                                                               |
 ///                                        |                                                               |
 ///                                        |<--------------------------  Value  -------------------------->|
+/// ```
 ///
 /// # Capabilities:
 ///
@@ -89,142 +309,123 @@ impl TryFrom<u16> for SystemCapability {
 /// |   5   |     Telephone       | i.e. a VoIP phone                                            |
 /// |   6   | DOCSIS cable device | i.e. a cable modem                                           |
 /// |   7   |    Station Only     | e.g. a PC, should not be set in conjunction with other bits  |
-/// | 8-15  |      reserved       |                                                              |
+/// |   8   |  C-VLAN Component   |                                                              |
+/// |   9   |  S-VLAN Component   |                                                              |
+/// |  10   | Two-Port MAC Relay  |                                                              |
+/// | 11-15 |      reserved       |                                                              |
 ///
 /// If the system capabilities field does not indicate the existence of a capability that the enabled capabilities
-/// field indicates is enabled, the TLV will be interpreted as containing an error and a ValueError is raised.
+/// field indicates is enabled, the TLV will be interpreted as containing an error and decoding fails. Station Only
+/// combined with other capabilities is reported by [crate::lldpdu::Lldpdu::validation_warnings].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "crate::serialization::Capabilities",
+        into = "crate::serialization::Capabilities"
+    )
+)]
 pub struct SystemCapabilitiesTLV {
     /// The type of the TLV
     pub tlv_type: TlvType,
-    /// Supported and enabled capabilities
-    pub value: u32,
+    /// Supported capabilities
+    pub supported: CapabilitySet,
+    /// Enabled capabilities
+    pub enabled: CapabilitySet,
 }
 
 impl Display for SystemCapabilitiesTLV {
     /// Write a printable representation of the TLV object.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let supports = (self.value & 0xFFFF0000) >> 16;
-        let enabled = self.value & 0x0000FFFF;
-        println!("{} {}", supports, enabled);
-        write!(f, "SystemCapabilitiesTLV({}, {})", supports, enabled)
+        write!(
+            f,
+            "SystemCapabilitiesTLV({}, {})",
+            self.supported.bits(),
+            self.enabled.bits()
+        )
     }
 }
 
 impl SystemCapabilitiesTLV {
     /// Constructor
     ///
+    /// ```text
     ///    Parameters:
     ///        supported (u16): Bitmap of supported capabilities
     ///        enabled (u16): Bitmap of enabled capabilities
+    /// ```
+    ///
+    /// Panics if capabilities are enabled that are not supported, use [SystemCapabilitiesTLV::try_new] to handle this
+    /// case.
     pub fn new(supported: u16, enabled: u16) -> SystemCapabilitiesTLV {
-        let mut mask = 1;
-
-        for _ in 0..16 {
-            if (supported & mask == 0) && (enabled & mask != 0) {
-                panic!("Invalid Settings");
-            }
-            mask <<= 1;
-        }
-
-        let mut value: u32 = (supported as u32) << 16;
-        value |= enabled as u32;
-
-        SystemCapabilitiesTLV {
-            tlv_type: TlvType::SystemCapabilities,
-            value: value,
-        }
+        SystemCapabilitiesTLV::try_new(supported, enabled)
+            .unwrap_or_else(|_| panic!("Invalid Settings"))
     }
 
-    /// Create a TLV instance from raw bytes.
+    /// Create a System Capabilities TLV from sets of capabilities.
     ///
-    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
-    pub fn new_from_bytes(bytes: &[u8]) -> SystemCapabilitiesTLV {
-        let mut type_field = bytes[0] & 0b11111110;
-        type_field = type_field >> 1;
-
-        if type_field != TlvType::SystemCapabilities as u8 {
-            panic!("Wrong TLV Type for SystemCapabilities");
-        }
-
-        let mut length = bytes[1] as usize;
-        if bytes[0] & 1 == 1 {
-            length += 1 << 9;
+    /// Returns an error if capabilities are enabled that are not supported.
+    pub fn try_new(
+        supported: impl Into<CapabilitySet>,
+        enabled: impl Into<CapabilitySet>,
+    ) -> Result<SystemCapabilitiesTLV, LldpError> {
+        let supported = supported.into();
+        let enabled = enabled.into();
+        if !supported.contains(enabled) {
+            return Err(LldpError::CapabilityMismatch {
+                supported: supported.bits(),
+                enabled: enabled.bits(),
+            });
         }
 
-        assert_eq!(length, 4, "length for SystemCapabilitiesTlv should be 4");
-
-        let supported = (((bytes[2] as u16) << 8) | bytes[3] as u16) as u32;
-        let enabled = (((bytes[4] as u16) << 8) | bytes[5] as u16) as u32;
-
-        let mut mask = 1;
-        for _ in 0..16 {
-            if (supported & mask == 0) && (enabled & mask != 0) {
-                panic!("Invalid Settings");
-            }
-            mask <<= 1;
-        }
-
-        let value = (supported << 16) as u32 | enabled as u32;
-
-        SystemCapabilitiesTLV {
+        Ok(SystemCapabilitiesTLV {
             tlv_type: TlvType::SystemCapabilities,
-            value: value,
-        }
+            supported,
+            enabled,
+        })
     }
 
-    /// Check if the system supports a given set of capabilities.
+    /// Get the set of supported capabilities
     ///
-    /// Multiple capabilities should be ORed together.
-    fn supports(&self, capabilities: u16) -> bool {
-        let mut capabilities_mask = 1;
-        let mut mask: u32 = 1 << 16;
-
-        for _ in 0..16 {
-            if (capabilities & capabilities_mask != 0) && (self.value & mask == 0) {
-                return false;
-            }
-            mask <<= 1;
-            capabilities_mask <<= 1;
-        }
-        true
+    /// Use [CapabilitySet::contains] to check if the system supports given capabilities.
+    pub fn supported(&self) -> CapabilitySet {
+        self.supported
     }
 
-    /// Check if the system has a given capability enabled.
+    /// Get the set of enabled capabilities
     ///
-    /// Multiple capabilities should be ORed together.
-    fn enabled(&self, capabilities: u16) -> bool {
-        let mut capabilities_mask = 1;
-        let mut mask: u32 = 1;
-
-        for _ in 0..16 {
-            if (capabilities & capabilities_mask != 0) && (self.value & mask == 0) {
-                return false;
-            }
-            mask <<= 1;
-            capabilities_mask <<= 1;
-        }
-        true
+    /// Use [CapabilitySet::contains] to check if given capabilities are enabled.
+    pub fn enabled(&self) -> CapabilitySet {
+        self.enabled
     }
+}
+
+impl TlvCodec for SystemCapabilitiesTLV {
+    const TLV_TYPE: TlvType = TlvType::SystemCapabilities;
 
-    /// Return the length of the TLV value
-    pub fn len(&self) -> usize {
-        return 4usize;
+    fn len(&self) -> usize {
+        4
     }
 
-    /// Return the byte representation of the TLV.
-    pub fn bytes(&self) -> Vec<u8> {
-        let mask: u32 = 0xFF;
-        let mut result: Vec<u8> = Vec::new();
-        result.push(7 << 1);
-        result.push(4);
+    fn encode_value(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.supported.bits());
+        buf.put_u16(self.enabled.bits());
+    }
 
-        result.push(((self.value & (mask << 24)) >> 24) as u8);
-        result.push(((self.value & (mask << 16)) >> 16) as u8);
-        result.push(((self.value & (mask << 8)) >> 8) as u8);
-        result.push((self.value & mask) as u8);
+    /// Returns an error if capabilities are enabled that are not supported.
+    fn decode_value(value: &[u8]) -> Result<SystemCapabilitiesTLV, LldpError> {
+        if value.len() != 4 {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::SystemCapabilities,
+                length: value.len(),
+            });
+        }
 
-        result
+        SystemCapabilitiesTLV::try_new(
+            u16::from_be_bytes([value[0], value[1]]),
+            u16::from_be_bytes([value[2], value[3]]),
+        )
     }
 }
 
@@ -258,7 +459,8 @@ mod tests {
     #[test]
     fn test_value() {
         let tlv = set_up();
-        assert_eq!(tlv.value, 0x005c0054);
+        assert_eq!(tlv.supported.bits(), 0x005c);
+        assert_eq!(tlv.enabled.bits(), 0x0054);
     }
 
     #[test]
@@ -273,13 +475,13 @@ mod tests {
         assert_eq!(tlv.tlv_type as u8, TlvType::SystemCapabilities as u8);
         assert_eq!(tlv.len() as u8, 4);
         assert_eq!(
-            (tlv.value & 0xFFFF0000) >> 16,
-            20,
+            tlv.supported(),
+            SystemCapability::Bridge | SystemCapability::Router,
             "Expected only BRIDGE and ROUTER capabilities to be supported."
         );
         assert_eq!(
-            tlv.value & 0xFFFF,
-            4,
+            tlv.enabled(),
+            SystemCapability::Bridge.into(),
             "Expected only BRIDGE capability to be enabled."
         );
     }
@@ -287,60 +489,117 @@ mod tests {
     #[test]
     fn test_supports() {
         let tlv = set_up();
-        assert!(tlv.supports(
-            SystemCapability::WlanAP as u16
-                | SystemCapability::Bridge as u16
-                | SystemCapability::Router as u16
-                | SystemCapability::DocsisDevice as u16
+        let supported = tlv.supported();
+        assert!(supported.contains(
+            SystemCapability::WlanAP
+                | SystemCapability::Bridge
+                | SystemCapability::Router
+                | SystemCapability::DocsisDevice
         ));
-        for cap in [SystemCapability::WlanAP as u16
-            | SystemCapability::Bridge as u16
-            | SystemCapability::Router as u16
-            | SystemCapability::DocsisDevice as u16]
-        {
-            assert!(tlv.supports(cap));
-        }
-        for cap in [SystemCapability::Other as u16
-            | SystemCapability::Repeater as u16
-            | SystemCapability::Telephone as u16
-            | SystemCapability::StationOnly as u16
-            | SystemCapability::CVlanComponent as u16
-            | SystemCapability::SVlanComponent as u16
-            | SystemCapability::TwoPortMacRelay as u16]
-        {
-            assert!(!tlv.supports(cap));
+        for cap in [
+            SystemCapability::Other,
+            SystemCapability::Repeater,
+            SystemCapability::Telephone,
+            SystemCapability::StationOnly,
+            SystemCapability::CVlanComponent,
+            SystemCapability::SVlanComponent,
+            SystemCapability::TwoPortMacRelay,
+        ] {
+            assert!(!supported.contains(cap));
         }
         // Reserved bits should not be used
-        assert!(!tlv.supports(0xF800));
+        assert!(!supported.contains(0xF800));
     }
 
     #[test]
     fn test_enabled() {
         let tlv = set_up();
-        assert!(tlv.supports(
-            SystemCapability::Bridge as u16
-                | SystemCapability::Router as u16
-                | SystemCapability::DocsisDevice as u16
+        let enabled = tlv.enabled();
+        assert!(enabled.contains(
+            SystemCapability::Bridge | SystemCapability::Router | SystemCapability::DocsisDevice
         ));
-        for cap in [SystemCapability::Bridge as u16
-            | SystemCapability::Router as u16
-            | SystemCapability::DocsisDevice as u16]
-        {
-            assert!(tlv.supports(cap));
-        }
-        for cap in [SystemCapability::Other as u16
-            | SystemCapability::Repeater as u16
-            | SystemCapability::WlanAP as u16
-            | SystemCapability::Telephone as u16
-            | SystemCapability::StationOnly as u16
-            | SystemCapability::CVlanComponent as u16
-            | SystemCapability::SVlanComponent as u16
-            | SystemCapability::TwoPortMacRelay as u16]
-        {
-            assert!(!tlv.supports(cap));
+        for cap in [
+            SystemCapability::Other,
+            SystemCapability::Repeater,
+            SystemCapability::WlanAP,
+            SystemCapability::Telephone,
+            SystemCapability::StationOnly,
+            SystemCapability::CVlanComponent,
+            SystemCapability::SVlanComponent,
+            SystemCapability::TwoPortMacRelay,
+        ] {
+            assert!(!enabled.contains(cap));
         }
         // Reserved bits should not be used
-        assert!(!tlv.supports(0xF800));
+        assert!(!enabled.contains(0xF800));
+    }
+
+    #[test]
+    fn test_sets() {
+        let tlv = set_up();
+        assert_eq!(
+            tlv.supported(),
+            SystemCapability::Bridge
+                | SystemCapability::WlanAP
+                | SystemCapability::Router
+                | SystemCapability::DocsisDevice
+        );
+        assert_eq!(
+            tlv.enabled().iter().collect::<Vec<_>>(),
+            [
+                SystemCapability::Bridge,
+                SystemCapability::Router,
+                SystemCapability::DocsisDevice
+            ]
+        );
+        assert!(tlv.supported().contains(tlv.enabled()));
+        assert!(!tlv.enabled().contains(SystemCapability::WlanAP));
+    }
+
+    #[test]
+    fn test_capability_set() {
+        let mut caps = SystemCapability::Bridge | SystemCapability::Router;
+        assert_eq!(caps.bits(), 0x0014);
+        assert_eq!(caps.to_string(), "Bridge, Router");
+
+        caps |= SystemCapability::TwoPortMacRelay;
+        caps.remove(SystemCapability::Bridge);
+        assert_eq!(caps.to_string(), "Router, Two-Port MAC Relay");
+
+        let caps = CapabilitySet::from_bits(0x0881);
+        assert_eq!(caps.reserved(), 0x0800);
+        assert_eq!(caps.to_string(), "Other, Station Only, 0x0800");
+        assert_eq!(caps.to_string().parse(), Ok(caps));
+
+        assert_eq!(CapabilitySet::new().to_string(), "");
+        assert!("".parse::<CapabilitySet>().unwrap().is_empty());
+        assert_eq!(
+            vec![SystemCapability::Telephone, SystemCapability::Repeater]
+                .into_iter()
+                .collect::<CapabilitySet>()
+                .bits(),
+            0x0022
+        );
+    }
+
+    #[test]
+    fn test_parse_names() {
+        assert_eq!(
+            "WLAN Access Point".parse::<SystemCapability>(),
+            Ok(SystemCapability::WlanAP)
+        );
+        assert_eq!(
+            "wlanap".parse::<SystemCapability>(),
+            Ok(SystemCapability::WlanAP)
+        );
+        assert_eq!(
+            "c-vlan component".parse::<SystemCapability>(),
+            Ok(SystemCapability::CVlanComponent)
+        );
+        assert_eq!(
+            "Bridge, Toaster".parse::<CapabilitySet>(),
+            Err(LldpError::UnknownCapability(String::from("Toaster")))
+        );
     }
 
     #[test]
@@ -352,12 +611,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_new() {
+        let tlv = SystemCapabilitiesTLV::try_new(
+            SystemCapability::Bridge | SystemCapability::Router,
+            SystemCapability::Bridge,
+        )
+        .unwrap();
+        assert_eq!(tlv.bytes(), b"\x0e\x04\x00\x14\x00\x04");
+
+        assert_eq!(
+            SystemCapabilitiesTLV::try_new(SystemCapability::StationOnly, SystemCapability::WlanAP)
+                .unwrap_err(),
+            LldpError::CapabilityMismatch {
+                supported: 0x0080,
+                enabled: 0x0008
+            }
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_load_capability_mismatch() {
         SystemCapabilitiesTLV::new_from_bytes(b"\x0e\x04\x00\x00\x00\x14".as_ref());
     }
 
+    #[test]
+    fn test_try_load_invalid() {
+        assert_eq!(
+            SystemCapabilitiesTLV::try_from_bytes(b"\x0e\x04\x00\x00\x00\x14".as_ref())
+                .unwrap_err(),
+            LldpError::CapabilityMismatch {
+                supported: 0,
+                enabled: 0x14
+            }
+        );
+        assert_eq!(
+            SystemCapabilitiesTLV::try_from_bytes(b"\x0e\x02\x00\x14".as_ref()).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::SystemCapabilities,
+                length: 2
+            }
+        );
+    }
+
     #[test]
     fn test_display() {
         let tlv = set_up();
@@ -1,6 +1,8 @@
 use std::fmt::Display;
 
-use crate::tlv::TlvType;
+use crate::error::LldpError;
+use crate::tlv::{check_len, TlvCodec, TlvType, MAX_STRING_LEN};
+use bytes::BufMut;
 
 /// System Description TLV
 ///
@@ -11,6 +13,7 @@ use crate::tlv::TlvType;
 ///
 /// # TLV Format:
 ///
+/// ```text
 ///      0                   1                   2
 ///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
@@ -20,9 +23,19 @@ use crate::tlv::TlvType;
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
 ///
 ///                                             0 - 255 byte
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct SystemDescriptionTLV {
     /// The type of the TLV
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip,
+            default = "crate::serialization::tlv_type::<SystemDescriptionTLV>"
+        )
+    )]
     pub tlv_type: TlvType,
     /// The system description
     pub value: String,
@@ -46,61 +59,35 @@ impl SystemDescriptionTLV {
         }
     }
 
-    /// Create a TLV instance from raw bytes.
+    /// Create a System Description TLV, checking the length of the description.
     ///
-    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
-    pub fn new_from_bytes(bytes: &[u8]) -> SystemDescriptionTLV {
-        let mut type_field = bytes[0] & 0b11111110;
-        type_field = type_field >> 1;
-
-        if type_field != TlvType::SystemDescription as u8 {
-            panic!("Wrong TLV Type for SystemDescription");
-        }
-
-        let mut length = bytes[1] as usize;
-        if bytes[0] & 1 == 1 {
-            length += 1 << 9;
-        }
- 
-        let vec = bytes[2..].to_vec();
-
-        let value = match String::from_utf8(vec) {
-            Ok(value) => value,
-            Err(e) => panic!("could not parse value for PortDescription"),
-        };
-
-        assert_eq!(length, value.len(), "Length field is incorrect");
-
-        SystemDescriptionTLV {
-            tlv_type: TlvType::PortDescription,
-            value: value,
-        }
+    /// Returns an error if the description is longer than 255 bytes.
+    pub fn try_new(description: String) -> Result<SystemDescriptionTLV, LldpError> {
+        check_len(SystemDescriptionTLV::new(description), 0..=MAX_STRING_LEN)
     }
+}
 
-    /// Return the length of the TLV value
-    pub fn len(&self) -> usize {
+impl TlvCodec for SystemDescriptionTLV {
+    const TLV_TYPE: TlvType = TlvType::SystemDescription;
+
+    fn len(&self) -> usize {
         self.value.len()
     }
 
-    /// Return the byte representation of the TLV.
-    pub fn bytes(&self) -> Vec<u8> {
-        let mut type_field = (self.tlv_type as u8) << 1;
-
-        let length_field = self.len();
-        if length_field & (1 << 9) == 1 {
-            type_field |= 1;
-        }
-
-        let length_field = length_field as u8;
-
-        let mut result: Vec<u8> = Vec::new();
-        result.push(type_field);
-        result.push(length_field);
+    fn encode_value(&self, buf: &mut impl BufMut) {
+        buf.put_slice(self.value.as_bytes());
+    }
 
-        let value_bytes: Vec<u8> = self.value.as_bytes().to_vec();
-        result.extend_from_slice(&value_bytes);
+    fn decode_value(value: &[u8]) -> Result<SystemDescriptionTLV, LldpError> {
+        let value = match String::from_utf8(value.to_vec()) {
+            Ok(value) => value,
+            Err(_) => return Err(LldpError::InvalidUtf8(TlvType::SystemDescription)),
+        };
 
-        result
+        Ok(SystemDescriptionTLV {
+            tlv_type: TlvType::SystemDescription,
+            value,
+        })
     }
 }
 
@@ -145,9 +132,57 @@ mod tests {
         assert_eq!(tlv.value, String::from("YetAnotherUnittest"));
     }
 
+    #[test]
+    fn test_long_value_roundtrip() {
+        let description = "0123456789".repeat(30);
+        let tlv = SystemDescriptionTLV::new(description.clone());
+        let bytes = tlv.bytes();
+        assert_eq!(&bytes[..2], b"\x0D\x2C");
+
+        let tlv = SystemDescriptionTLV::new_from_bytes(&bytes);
+        assert_eq!(tlv.value, description);
+        assert_eq!(tlv.bytes(), bytes);
+    }
+
+    #[test]
+    fn test_try_load_invalid() {
+        assert_eq!(
+            SystemDescriptionTLV::try_from_bytes(b"\x0C\x05Unit".as_ref()).unwrap_err(),
+            LldpError::Truncated {
+                needed: 7,
+                available: 6
+            }
+        );
+        assert_eq!(
+            SystemDescriptionTLV::try_from_bytes(b"\x0C\x02\xc3\x28".as_ref()).unwrap_err(),
+            LldpError::InvalidUtf8(TlvType::SystemDescription)
+        );
+    }
+
     #[test]
     fn test_display() {
         let (tlv, _) = set_up();
         assert_eq!(format!("{}", tlv), "SystemDescriptionTLV(\"Unittest\")");
     }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            SystemDescriptionTLV::try_new(String::new()).unwrap().len(),
+            0
+        );
+        assert_eq!(
+            SystemDescriptionTLV::try_new("x".repeat(255))
+                .unwrap()
+                .len(),
+            255
+        );
+        assert_eq!(
+            SystemDescriptionTLV::try_new("x".repeat(256)).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::SystemDescription,
+                length: 256
+            }
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use std::fmt::Display;
 
-use crate::tlv::TlvType;
+use crate::error::LldpError;
+use crate::tlv::{check_len, TlvCodec, TlvType, MAX_STRING_LEN};
+use bytes::BufMut;
 
 /// System Name TLV
 ///
@@ -11,6 +13,7 @@ use crate::tlv::TlvType;
 ///
 /// # TLV Format:
 ///
+/// ```text
 ///      0                   1                   2
 ///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
@@ -20,10 +23,17 @@ use crate::tlv::TlvType;
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
 ///
 ///                                                     0 - 255 byte
+/// ```
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct SystemNameTLV {
     /// The type of the TLV
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "crate::serialization::tlv_type::<SystemNameTLV>")
+    )]
     pub tlv_type: TlvType,
     /// The system name
     pub value: String,
@@ -46,63 +56,35 @@ impl SystemNameTLV {
         }
     }
 
-    /// Create a TLV instance from raw bytes.
+    /// Create a System Name TLV, checking the length of the name.
     ///
-    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
-    pub fn new_from_bytes(bytes: &[u8]) -> SystemNameTLV {
-        let mut type_field = bytes[0] & 0b11111110;
-        type_field = type_field >> 1;
+    /// Returns an error if the name is longer than 255 bytes.
+    pub fn try_new(name: String) -> Result<SystemNameTLV, LldpError> {
+        check_len(SystemNameTLV::new(name), 0..=MAX_STRING_LEN)
+    }
+}
 
-        if type_field != TlvType::SystemName as u8 {
-            panic!("Wrong TLV Type for SystemName");
-        }
+impl TlvCodec for SystemNameTLV {
+    const TLV_TYPE: TlvType = TlvType::SystemName;
 
-        let mut length = bytes[1] as usize;
-        if bytes[0] & 1 == 1 {
-            length += 1 << 9;
-        }
-
-        assert!(length < 512, "length overflow");
+    fn len(&self) -> usize {
+        self.value.len()
+    }
 
-        let vec = bytes[2..].to_vec();
+    fn encode_value(&self, buf: &mut impl BufMut) {
+        buf.put_slice(self.value.as_bytes());
+    }
 
-        let value = match String::from_utf8(vec) {
+    fn decode_value(value: &[u8]) -> Result<SystemNameTLV, LldpError> {
+        let value = match String::from_utf8(value.to_vec()) {
             Ok(value) => value,
-            Err(e) => panic!("could not parse value for PortDescription"),
+            Err(_) => return Err(LldpError::InvalidUtf8(TlvType::SystemName)),
         };
 
-        assert_eq!(length, value.len(), "Length field is incorrect");
-
-        SystemNameTLV {
+        Ok(SystemNameTLV {
             tlv_type: TlvType::SystemName,
-            value: value,
-        }
-    }
-
-    /// Return the length of the TLV value
-    pub fn len(&self) -> usize {
-        self.value.len()
-    }
-
-    /// Return the byte representation of the TLV.
-    pub fn bytes(&self) -> Vec<u8> {
-        let mut type_field = (self.tlv_type as u8) << 1;
-
-        let length_field = self.len();
-        if length_field & (1 << 9) == 1 {
-            type_field |= 1;
-        }
-
-        let length_field = length_field as u8;
-
-        let mut result: Vec<u8> = Vec::new();
-        result.push(type_field);
-        result.push(length_field);
-
-        let value_bytes: Vec<u8> = self.value.as_bytes().to_vec();
-        result.extend_from_slice(&value_bytes);
-
-        result
+            value,
+        })
     }
 }
 
@@ -147,9 +129,37 @@ mod tests {
         assert_eq!(tlv.value, String::from("AnotherUnittestAgain"));
     }
 
+    #[test]
+    fn test_try_load_invalid() {
+        assert_eq!(
+            SystemNameTLV::try_from_bytes(b"\x0A\x05Unit".as_ref()).unwrap_err(),
+            LldpError::Truncated {
+                needed: 7,
+                available: 6
+            }
+        );
+        assert_eq!(
+            SystemNameTLV::try_from_bytes(b"\x0A\x02\xc3\x28".as_ref()).unwrap_err(),
+            LldpError::InvalidUtf8(TlvType::SystemName)
+        );
+    }
+
     #[test]
     fn test_display() {
         let (tlv, _) = set_up();
         assert_eq!(format!("{}", tlv), "SystemNameTLV(\"Unittest\")");
     }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(SystemNameTLV::try_new(String::new()).unwrap().len(), 0);
+        assert_eq!(SystemNameTLV::try_new("x".repeat(255)).unwrap().len(), 255);
+        assert_eq!(
+            SystemNameTLV::try_new("x".repeat(256)).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::SystemName,
+                length: 256
+            }
+        );
+    }
 }
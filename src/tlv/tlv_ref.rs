@@ -0,0 +1,228 @@
+use crate::error::LldpError;
+use crate::tlv::{Tlv, TlvHeader, TlvType};
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+/// Borrowed TLV
+///
+/// A view of a single packed TLV inside a byte buffer. Creating a `TlvRef` only reads the TLV header, the value is
+/// neither copied nor interpreted until one of the accessors is called.
+///
+/// The typed accessors return `None` if the TLV is not of the matching type or its value is too short to contain
+/// the requested field. Use [TlvRef::to_tlv] for a fully validated, owned [Tlv].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TlvRef<'a> {
+    header: TlvHeader,
+    bytes: &'a [u8],
+}
+
+impl Display for TlvRef<'_> {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to_tlv() {
+            Ok(tlv) => tlv.fmt(f),
+            Err(_) => {
+                let mut hex = String::new();
+                for i in self.value() {
+                    hex.push_str(&format!("{:02X}", i));
+                }
+
+                write!(f, "InvalidTLV({}, \"{}\")", self.type_code(), hex)
+            }
+        }
+    }
+}
+
+impl<'a> TlvRef<'a> {
+    /// Create a view of the TLV at the start of `bytes`.
+    ///
+    /// Bytes following the TLV are ignored. Returns an error if `bytes` is shorter than the TLV.
+    pub fn new(bytes: &'a [u8]) -> Result<TlvRef<'a>, LldpError> {
+        let header = TlvHeader::parse(bytes)?;
+        header.value(bytes)?;
+
+        Ok(TlvRef {
+            header,
+            bytes: &bytes[..header.size()],
+        })
+    }
+
+    /// Get the type of the TLV.
+    ///
+    /// TLVs of a reserved type are reported as [TlvType::Unknown].
+    pub fn tlv_type(&self) -> TlvType {
+        TlvType::try_from(self.header.tlv_type).unwrap_or(TlvType::Unknown)
+    }
+
+    /// Get the raw type of the TLV as transmitted in the TLV header.
+    pub fn type_code(&self) -> u8 {
+        self.header.tlv_type
+    }
+
+    /// Return the length of the TLV value
+    pub fn len(&self) -> usize {
+        self.header.length
+    }
+
+    /// Check if the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the TLV value, i.e. everything after the header
+    pub fn value(&self) -> &'a [u8] {
+        &self.bytes[TlvHeader::LEN..]
+    }
+
+    /// Return the byte representation of the TLV, including its header
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Create an owned [Tlv] from the view.
+    ///
+    /// Returns an error if the TLV contains errors.
+    pub fn to_tlv(&self) -> Result<Tlv, LldpError> {
+        Tlv::try_from_bytes(self.bytes)
+    }
+
+    /// Get the subtype of a Chassis ID or Port ID TLV
+    pub fn subtype(&self) -> Option<u8> {
+        match self.tlv_type() {
+            TlvType::ChassisId | TlvType::PortId => self.value().first().copied(),
+            _ => None,
+        }
+    }
+
+    /// Get the raw ID (without subtype) of a Chassis ID or Port ID TLV
+    pub fn id(&self) -> Option<&'a [u8]> {
+        match self.tlv_type() {
+            TlvType::ChassisId | TlvType::PortId => self.value().get(1..),
+            _ => None,
+        }
+    }
+
+    /// Get the TTL of a TTL TLV
+    pub fn ttl(&self) -> Option<u16> {
+        match (self.tlv_type(), self.value()) {
+            (TlvType::Ttl, [high, low]) => Some(u16::from_be_bytes([*high, *low])),
+            _ => None,
+        }
+    }
+
+    /// Get the string of a Port Description, System Name or System Description TLV
+    pub fn as_str(&self) -> Option<&'a str> {
+        match self.tlv_type() {
+            TlvType::PortDescription | TlvType::SystemName | TlvType::SystemDescription => {
+                std::str::from_utf8(self.value()).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the supported and enabled capability bitmaps of a System Capabilities TLV
+    pub fn capabilities(&self) -> Option<(u16, u16)> {
+        match (self.tlv_type(), self.value()) {
+            (TlvType::SystemCapabilities, [s0, s1, e0, e1]) => Some((
+                u16::from_be_bytes([*s0, *s1]),
+                u16::from_be_bytes([*e0, *e1]),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Get the OUI of an Organizationally Specific TLV
+    pub fn oui(&self) -> Option<[u8; 3]> {
+        match (self.tlv_type(), self.value()) {
+            (TlvType::OrganizationallySpecific, [a, b, c, _, ..]) => Some([*a, *b, *c]),
+            _ => None,
+        }
+    }
+
+    /// Get the organizationally defined subtype of an Organizationally Specific TLV
+    pub fn org_subtype(&self) -> Option<u8> {
+        match (self.tlv_type(), self.value()) {
+            (TlvType::OrganizationallySpecific, [_, _, _, subtype, ..]) => Some(*subtype),
+            _ => None,
+        }
+    }
+
+    /// Get the organizationally defined information of an Organizationally Specific TLV
+    pub fn org_value(&self) -> Option<&'a [u8]> {
+        match self.tlv_type() {
+            TlvType::OrganizationallySpecific => self.value().get(4..),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header() {
+        let tlv = TlvRef::new(b"\x0A\x08Unittest\x00\x00".as_ref()).unwrap();
+        assert_eq!(tlv.tlv_type(), TlvType::SystemName);
+        assert_eq!(tlv.type_code(), 5);
+        assert_eq!(tlv.len(), 8);
+        assert_eq!(tlv.value(), b"Unittest");
+        assert_eq!(tlv.bytes(), b"\x0A\x08Unittest");
+    }
+
+    #[test]
+    fn test_truncated() {
+        assert_eq!(
+            TlvRef::new(b"\x0A\x08Unit".as_ref()),
+            Err(LldpError::Truncated {
+                needed: 10,
+                available: 6
+            })
+        );
+    }
+
+    #[test]
+    fn test_accessors() {
+        let tlv = TlvRef::new(b"\x02\x07\x04\xc8\xbc\xc8\x94\x92\xca".as_ref()).unwrap();
+        assert_eq!(tlv.subtype(), Some(4));
+        assert_eq!(tlv.id(), Some(b"\xc8\xbc\xc8\x94\x92\xca".as_ref()));
+        assert_eq!(tlv.ttl(), None);
+
+        let tlv = TlvRef::new(b"\x06\x02\x00\x78".as_ref()).unwrap();
+        assert_eq!(tlv.ttl(), Some(120));
+        assert_eq!(tlv.as_str(), None);
+
+        let tlv = TlvRef::new(b"\x0C\x05Hello".as_ref()).unwrap();
+        assert_eq!(tlv.as_str(), Some("Hello"));
+
+        let tlv = TlvRef::new(b"\x0e\x04\x00\x14\x00\x04".as_ref()).unwrap();
+        assert_eq!(tlv.capabilities(), Some((0x14, 0x04)));
+
+        let tlv = TlvRef::new(b"\xFE\x06\xAA\xBB\xCC\x1A42".as_ref()).unwrap();
+        assert_eq!(tlv.oui(), Some([0xAA, 0xBB, 0xCC]));
+        assert_eq!(tlv.org_subtype(), Some(0x1A));
+        assert_eq!(tlv.org_value(), Some(b"42".as_ref()));
+    }
+
+    #[test]
+    fn test_to_tlv() {
+        let tlv = TlvRef::new(b"\x06\x02\x00\x78".as_ref()).unwrap();
+        match tlv.to_tlv() {
+            Ok(Tlv::Ttl(ttl)) => assert_eq!(ttl.value, 120),
+            tlv => panic!("expected TTL, got {:?}", tlv),
+        }
+
+        let tlv = TlvRef::new(b"\x06\x01\x00".as_ref()).unwrap();
+        assert!(tlv.to_tlv().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let tlv = TlvRef::new(b"\x06\x02\x00\x78".as_ref()).unwrap();
+        assert_eq!(format!("{}", tlv), "TtlTLV(120)");
+
+        let tlv = TlvRef::new(b"\x06\x01\x00".as_ref()).unwrap();
+        assert_eq!(format!("{}", tlv), "InvalidTLV(3, \"00\")");
+    }
+}
@@ -1,5 +1,6 @@
-use crate::tlv::TlvType;
-use bytes::{Buf, BufMut};
+use crate::error::LldpError;
+use crate::tlv::{TlvCodec, TlvType};
+use bytes::BufMut;
 use std::fmt::Display;
 
 /// Time To Live TLV
@@ -12,6 +13,7 @@ use std::fmt::Display;
 ///
 /// # TLV Format:
 ///
+/// ```text
 ///      0                   1                   2                   3
 ///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
@@ -19,9 +21,16 @@ use std::fmt::Display;
 ///     |      3      |      Length     |               TTL             |
 ///     |             |                 |                               |
 ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct TtlTLV {
     /// The type of the TLV
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "crate::serialization::tlv_type::<TtlTLV>")
+    )]
     pub tlv_type: TlvType,
     /// The TTL in seconds
     pub value: u16,
@@ -44,54 +53,28 @@ impl TtlTLV {
             value: ttl,
         }
     }
+}
 
-    /// Create a TLV instance from raw bytes.
-    ///
-    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
-    pub fn new_from_bytes(bytes: &[u8]) -> TtlTLV {
-        let mut type_field = bytes[0] & 0b11111110;
-        type_field = type_field >> 1;
-
-        if type_field != TlvType::Ttl as u8 {
-            panic!("Wrong TLV Type for TTL");
-        }
-
-        let mut length = bytes[1] as usize;
-        if bytes[0] & 1 == 1 {
-            length += 1 << 9;
-        }
-
-        assert_eq!(length, 2, "length should be 2 for TTL");
-
-        let value = ((bytes[2] as u16) << 8) | bytes[3] as u16;
-
-        TtlTLV::new(value)
-    }
+impl TlvCodec for TtlTLV {
+    const TLV_TYPE: TlvType = TlvType::Ttl;
 
-    /// Return the length of the TLV value
-    pub fn len(&self) -> usize {
+    fn len(&self) -> usize {
         2
     }
 
-    /// Return the byte representation of the TLV.
-    pub fn bytes(&self) -> Vec<u8> {
-        let mut type_field = (self.tlv_type as u8) << 1;
+    fn encode_value(&self, buf: &mut impl BufMut) {
+        buf.put_u16(self.value);
+    }
 
-        let length_field = self.len();
-        if length_field & (1 << 9) == 1 {
-            type_field |= 1;
+    fn decode_value(value: &[u8]) -> Result<TtlTLV, LldpError> {
+        if value.len() != 2 {
+            return Err(LldpError::BadLength {
+                tlv_type: TlvType::Ttl,
+                length: value.len(),
+            });
         }
 
-        let length_field = length_field as u8;
-
-        let mut result: Vec<u8> = Vec::new();
-        result.push(type_field);
-        result.push(length_field);
-
-        result.push(((self.value & 0xFF00) >> 8) as u8);
-        result.push((self.value & 0x00FF) as u8);
-
-        result
+        Ok(TtlTLV::new(u16::from_be_bytes([value[0], value[1]])))
     }
 }
 
@@ -148,6 +131,24 @@ mod tests {
         TtlTLV::new_from_bytes(b"\x06\x01\x00\x78".as_ref());
     }
 
+    #[test]
+    fn test_try_load_invalid_length() {
+        assert_eq!(
+            TtlTLV::try_from_bytes(b"\x06\x03\x00\x78\x00".as_ref()).unwrap_err(),
+            LldpError::BadLength {
+                tlv_type: TlvType::Ttl,
+                length: 3
+            }
+        );
+        assert_eq!(
+            TtlTLV::try_from_bytes(b"\x06\x02\x00".as_ref()).unwrap_err(),
+            LldpError::Truncated {
+                needed: 4,
+                available: 3
+            }
+        );
+    }
+
     #[test]
     fn test_display() {
         let (tlv, _) = set_up();